@@ -1,15 +1,39 @@
 use super::ts::TsFlavor;
 
-pub const TS_NUM_STRINGS: &'static [&'static str] = &["ts-num", "TS-NUM"];
+pub const TS_NUM_STRINGS: &[&str] = &["ts-num", "TS-NUM"];
 
-pub const TS_FULL_STRINGS: &'static [&'static str] = &["ts-full", "TS-FULL"];
+pub const TS_FULL_STRINGS: &[&str] = &["ts-full", "TS-FULL"];
 
-pub const TS_FLAVOR_STRINGS: &'static [&'static str] = &["ts-num", "TS-NUM", "ts-full", "TS-FULL"];
+pub const TS_FLAVOR_STRINGS: &[&str] = &["ts-num", "TS-NUM", "ts-full", "TS-FULL"];
 
-pub const DEFAULT_TS_FLAVOR: (TsFlavor, &'static str) = (TsFlavor::TsNum, "TS-NUM");
+pub const DEFAULT_TS_FLAVOR: (TsFlavor, &str) = (TsFlavor::TsNum, "TS-NUM");
 
 const_str!(OPTIONS_GEN_CONFIG => "gen-config");
 
+const_str!(OPTION_TSCONFIG => "tsconfig");
+
+const_str!(OPTION_TSCONFIG_HELP =>
+"Path to the project's tsconfig.json. Used to resolve imports with `baseUrl` and `paths`, and `/// <reference types>` with `typeRoots` [default: Nearest tsconfig.json in the root module's directory or its ancestors, ignored if it cannot be read]"
+);
+
+const_str!(OPTION_MODULE => "module");
+
+const_str!(OPTION_MODULE_HELP =>
+"Specifier of an ambient module (i.e. `declare module \"fs\" { }`) declared by the input files to bind instead of the root TS module"
+);
+
+const_str!(OPTION_ROOT_BINDING => "root-binding");
+
+const_str!(OPTION_ROOT_BINDING_HELP =>
+"How the generated JS binds the TS implementation: `require` (of the require path), `global` (the global named by `export as namespace X`) or `global:X` (the global `X`) [default: require]"
+);
+
+const_str!(OPTION_BIND_GLOBALS => "bind-globals");
+
+const_str!(OPTION_BIND_GLOBALS_HELP =>
+"Bind the global declarations of the input files (i.e. of scripts and `declare global { }`) instead of the exports of the root module. The JS implementation is read from `globalThis`. Optionally only binds the given comma-separated names"
+);
+
 const_str!(OPTIONS_GEN_CONFIG_HELP =>
 "Path to codegen configuration JSON file. Only affects [GEN] options"
 );
@@ -36,6 +60,29 @@ const_str!(OPTION_OPAQUE_INTERFACES => "opaque-interfaces");
 // Needs to be keep in sync with `GenConfig.wrap_top_level_vars` serde name
 const_str!(OPTION_WRAP_TOP_LEVEL_VARS => "wrap-top-level-vars");
 
+// Needs to be kept in sync with `GenConfig.default_export_name` serde name
+const_str!(OPTION_DEFAULT_EXPORT_NAME => "default-export-name");
+
+pub const DEFAULT_EXPORT_NAME_STRINGS: &[&str] = &["default", "declaration"];
+
+// Needs to be kept in sync with `GenConfig.optional_params` serde name
+const_str!(OPTION_OPTIONAL_PARAMS => "optional-params");
+
+pub const OPTIONAL_PARAMS_STRINGS: &[&str] = &["overloads", "option"];
+
+// Needs to be kept in sync with `GenConfig.overloads` serde name
+const_str!(OPTION_OVERLOADS => "overloads");
+
+pub const OVERLOADS_STRINGS: &[&str] = &["suffixed", "dispatch"];
+
+// Needs to be kept in sync with `GenConfig.string_dicts` serde name
+const_str!(OPTION_STRING_DICTS => "string-dicts");
+
+pub const STRING_DICTS_STRINGS: &[&str] = &["immutable", "mutable"];
+
+// Needs to be kept in sync with `GenConfig.lazy_dicts` serde name
+const_str!(OPTION_LAZY_DICTS => "lazy-dicts");
+
 //
 // ============
 // Help strings
@@ -86,3 +133,80 @@ Used by:
 [default: true]
 "
 );
+
+gen_help_str!(OPTION_DEFAULT_EXPORT_NAME_HELP =>
+"Pyret name of default exports"
+);
+
+gen_help_str!(OPTION_DEFAULT_EXPORT_NAME_HELP_LONG =>
+"Pyret name of default exports
+    * default: export as `default`
+    * declaration: export as the identifier of the exported declaration
+        (anonymous declarations are exported as `default`)
+Used by:
+    * TS-NUM
+[default: default]
+"
+);
+
+gen_help_str!(OPTION_OPTIONAL_PARAMS_HELP =>
+"Binding of optional trailing parameters of exported functions"
+);
+
+gen_help_str!(OPTION_OPTIONAL_PARAMS_HELP_LONG =>
+"Binding of optional trailing parameters of exported functions
+    * overloads: export a function per arity
+        (i.e. `foo-1` and `foo` for `function foo(x: number, y?: number)`)
+    * option: optional parameters are `Option` arguments
+Used by:
+    * TS-FULL
+    * TS-NUM
+[default: overloads]
+"
+);
+
+gen_help_str!(OPTION_OVERLOADS_HELP =>
+"Binding of functions declared with several signatures"
+);
+
+gen_help_str!(OPTION_OVERLOADS_HELP_LONG =>
+"Binding of functions declared with several signatures
+    * suffixed: export a function per signature suffixed by its position
+        (i.e. `foo-1` and `foo-2` for two declarations of `foo`)
+    * dispatch: export a single function dispatching on the number of arguments
+        (falls back to `suffixed` if signatures accept the same number of arguments)
+Suffixes of optional parameter arities (see `--optional-params`) follow the position
+    (i.e. `foo-1-1` is the first signature of `foo` taking 1 argument)
+Used by:
+    * TS-FULL
+    * TS-NUM
+[default: suffixed]
+"
+);
+
+gen_help_str!(OPTION_STRING_DICTS_HELP =>
+"Pyret type of objects used as dictionaries (i.e. `{ [key: string]: number }`)"
+);
+
+gen_help_str!(OPTION_STRING_DICTS_HELP_LONG =>
+"Pyret type of objects used as dictionaries (i.e. `{ [key: string]: number }`)
+    * immutable: `StringDict`
+    * mutable: `MutableStringDict`
+Used by:
+    * TS-FULL
+[default: immutable]
+"
+);
+
+gen_help_str!(OPTION_LAZY_DICTS_HELP =>
+"Read Pyret string dicts passed to TS lazily"
+);
+
+gen_help_str!(OPTION_LAZY_DICTS_HELP_LONG =>
+"Read Pyret string dicts passed to TS lazily (converting values on access)
+Otherwise, string dicts are copied into TS objects
+Used by:
+    * TS-FULL
+[default: false]
+"
+);
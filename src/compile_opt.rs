@@ -1,14 +1,20 @@
 use std::path::PathBuf;
 
-use crate::generate::{EmitConfig, GenConfig};
+use crate::generate::{EmitConfig, GenConfig, GlobalSelection, RootBinding};
 use crate::ts::TsFlavor;
 
 pub struct CompileOpt<'a> {
     pub input_path: PathBuf,
     // TODO: Should this be a PathBuf?
     pub require_path: String,
+    pub root_binding: RootBinding,
+
+    /// Bind global declarations (read from `globalThis`) instead of the exports of the root module
+    pub bind_globals: Option<GlobalSelection>,
     pub file_stem: Option<&'a str>,
     pub output_dir: PathBuf,
+    pub tsconfig_path: Option<PathBuf>,
+    pub root_module: Option<&'a str>,
 
     pub ts_flavor: TsFlavor,
     pub gen_config: GenConfig,
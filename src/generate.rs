@@ -12,6 +12,7 @@ mod js_pp;
 mod structures;
 mod ts_flavor_compat;
 mod ts_flavor_detector;
+mod tsconfig;
 mod type_construction;
 mod type_structs;
 mod typify_graph;
@@ -24,7 +25,13 @@ use swc_common::{
 };
 
 pub use self::config::EmitConfig;
+pub use self::config::DefaultExportName;
 pub use self::config::GenConfig;
+pub use self::config::GlobalSelection;
+pub use self::config::OptionalParams;
+pub use self::config::Overloads;
+pub use self::config::StringDicts;
+pub use self::config::RootBinding;
 
 use crate::compile_opt;
 use crate::ts::TsFlavor;
@@ -34,7 +41,38 @@ pub fn gen(options: compile_opt::CompileOpt) {
         let cm: Arc<SourceMap> = Default::default();
         let handler = Handler::with_tty_emitter(ColorConfig::Auto, true, false, Some(cm.clone()));
 
-        let cache = match bind_init::init(cm.clone(), handler, options.input_path.clone()) {
+        let compiler_options = match options.tsconfig_path {
+            Some(ref path) => match tsconfig::CompilerOptions::load(path) {
+                Ok(o) => o,
+
+                Err(e) => {
+                    eprintln!("tsconfig error: {:?}", e);
+                    std::process::exit(1);
+                }
+            },
+
+            // A discovered tsconfig.json may belong to an unrelated enclosing project
+            None => match tsconfig::CompilerOptions::discover(&options.input_path)
+                .map(|path| tsconfig::CompilerOptions::load(&path))
+            {
+                Some(Ok(o)) => o,
+
+                Some(Err(e)) => {
+                    eprintln!("tsconfig warning: ignoring {:?}", e);
+                    tsconfig::CompilerOptions::default()
+                }
+
+                None => tsconfig::CompilerOptions::default(),
+            },
+        };
+
+        let cache = match bind_init::init(
+            cm.clone(),
+            handler,
+            &compiler_options,
+            options.input_path.clone(),
+            options.root_module,
+        ) {
             Ok(c) => c,
 
             Err(e) => {
@@ -1,54 +1,258 @@
 use std::convert::TryFrom;
-use std::path::{Path, PathBuf};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
 
+use serde_json::Value;
+use swc_atoms::JsWord;
 use swc_common::{BytePos, Span, SyntaxContext};
 use swc_ecma_ast::*;
 
 use super::error::*;
 use super::structures::CanonPath;
+use super::tsconfig::CompilerOptions;
 
-// TODO: Fix dependency resolution to match Node (and Typescript Node import option)
-//    Helpful sources: https://www.typescriptlang.org/docs/handbook/module-resolution.html#node
+const DTS_EXTENSION: &str = ".d.ts";
+pub(super) const NODE_MODULES: &str = "node_modules";
+const TYPES_SCOPE: &str = "@types";
+
+/// Maps an import specifier to a '.d.ts' file using the Typescript "node" module resolution
+///   strategy.
+///   Helpful sources: https://www.typescriptlang.org/docs/handbook/module-resolution.html#node
+///
+/// Non-relative specifiers are first mapped through the `tsconfig.json` `paths` and `baseUrl`
+///   options before searching `node_modules`
+///   (`typeRoots` only apply to `/// <reference types="..." />`, see `locate_reference_types()`)
+///
+/// Returns None if the dependency could not be found
 pub fn locate_dependency(
+    compiler_options: &CompilerOptions,
     original: &Path,
     dependency: &Path,
 ) -> Result<Option<CanonPath>, BindGenError> {
-    if dependency.is_relative() {
-        let mut current_path = original.to_owned();
-        current_path.pop();
-        let mut current_path = current_path.join(dependency);
-        prepare_path(&mut current_path);
-
-        CanonPath::try_from(current_path.clone())
-            .map_err(|e| BindGenError {
-                module_path: current_path,
+    let current_dir = original.parent().unwrap_or(Path::new("/"));
+
+    let located = if is_path_specifier(dependency) {
+        let current_path = current_dir.join(dependency);
+        resolve_as_file(&current_path).or_else(|| resolve_as_directory(&current_path))
+    } else {
+        resolve_path_mapping(compiler_options, dependency)
+            .or_else(|| resolve_node_module(current_dir, dependency))
+    };
+
+    canonicalize_located(located)
+}
+
+/// Maps a `/// <reference path="..." />` directive to a '.d.ts' file
+///   The path is always relative to the referencing file
+///
+/// Returns None if the file could not be found
+pub fn locate_reference_path(
+    original: &Path,
+    reference: &Path,
+) -> Result<Option<CanonPath>, BindGenError> {
+    let current_dir = original.parent().unwrap_or(Path::new("/"));
+
+    canonicalize_located(resolve_as_file(&current_dir.join(reference)))
+}
+
+/// Maps a `/// <reference types="..." />` directive to a '.d.ts' file
+///   Searches the `typeRoots` (by default, every `node_modules/@types` directory walking up
+///   from the referencing file) before falling back to `node_modules`
+///
+/// Returns None if the package could not be found
+pub fn locate_reference_types(
+    compiler_options: &CompilerOptions,
+    original: &Path,
+    reference: &Path,
+) -> Result<Option<CanonPath>, BindGenError> {
+    let current_dir = original.parent().unwrap_or(Path::new("/"));
+
+    let located = match compiler_options.type_roots {
+        Some(..) => resolve_type_roots(compiler_options, reference),
+        None => resolve_types_package(current_dir, reference),
+    }
+    .or_else(|| resolve_node_module(current_dir, reference));
+
+    canonicalize_located(located)
+}
+
+fn canonicalize_located(located: Option<PathBuf>) -> Result<Option<CanonPath>, BindGenError> {
+    located
+        .map(|located| {
+            CanonPath::try_from(located.clone()).map_err(|e| BindGenError {
+                module_path: located,
                 kind: e.into(),
                 span: Span::new(BytePos(0), BytePos(0), SyntaxContext::empty()),
             })
-            .map(|path| Some(path))
+        })
+        .transpose()
+}
+
+/// Relative and absolute specifiers are resolved directly against the file system
+pub fn is_path_specifier(dependency: &Path) -> bool {
+    matches!(
+        dependency.components().next(),
+        Some(Component::CurDir) | Some(Component::ParentDir) | Some(Component::RootDir)
+    )
+}
+
+/// Walk up the directory tree starting at `start_dir`, looking for the dependency in each
+///   `node_modules` and `node_modules/@types` directory
+fn resolve_node_module(start_dir: &Path, dependency: &Path) -> Option<PathBuf> {
+    let types_dependency = types_package_path(dependency);
+
+    for dir in start_dir.ancestors() {
+        let node_modules = dir.join(NODE_MODULES);
+        if !node_modules.is_dir() {
+            continue;
+        }
+
+        let package_path = node_modules.join(dependency);
+        if let Some(located) =
+            resolve_as_file(&package_path).or_else(|| resolve_as_directory(&package_path))
+        {
+            return Some(located);
+        }
+
+        let types_path = node_modules.join(TYPES_SCOPE).join(&types_dependency);
+        if let Some(located) =
+            resolve_as_file(&types_path).or_else(|| resolve_as_directory(&types_path))
+        {
+            return Some(located);
+        }
+    }
+
+    None
+}
+
+/// Walk up the directory tree starting at `start_dir`, looking for the package in each
+///   `node_modules/@types` directory
+fn resolve_types_package(start_dir: &Path, dependency: &Path) -> Option<PathBuf> {
+    let types_dependency = types_package_path(dependency);
+
+    start_dir
+        .ancestors()
+        .map(|dir| {
+            dir.join(NODE_MODULES)
+                .join(TYPES_SCOPE)
+                .join(&types_dependency)
+        })
+        .filter_map(|candidate| {
+            resolve_as_file(&candidate).or_else(|| resolve_as_directory(&candidate))
+        })
+        .next()
+}
+
+/// Try the `paths` substitutions and then `baseUrl`
+fn resolve_path_mapping(compiler_options: &CompilerOptions, dependency: &Path) -> Option<PathBuf> {
+    let path_candidates = compiler_options.path_candidates(&dependency.to_string_lossy());
+
+    path_candidates
+        .into_iter()
+        .chain(
+            compiler_options
+                .base_url
+                .as_ref()
+                .map(|base_url| base_url.join(dependency)),
+        )
+        .filter_map(|candidate| {
+            resolve_as_file(&candidate).or_else(|| resolve_as_directory(&candidate))
+        })
+        .next()
+}
+
+/// Look for the package directly inside each of the `typeRoots`
+fn resolve_type_roots(compiler_options: &CompilerOptions, dependency: &Path) -> Option<PathBuf> {
+    let type_roots = compiler_options.type_roots.as_ref()?;
+    let types_dependency = types_package_path(dependency);
+
+    type_roots
+        .iter()
+        .map(|type_root| type_root.join(&types_dependency))
+        .filter_map(|candidate| {
+            resolve_as_file(&candidate).or_else(|| resolve_as_directory(&candidate))
+        })
+        .next()
+}
+
+/// Path of a package within `@types`
+///   Scoped packages are mangled (i.e. `@scope/pkg` => `scope__pkg`)
+fn types_package_path(dependency: &Path) -> PathBuf {
+    let mut components = dependency.components();
+
+    match components.next() {
+        Some(Component::Normal(scope)) if scope.to_string_lossy().starts_with('@') => {
+            let scope = scope.to_string_lossy();
+            let mut path = match components.next() {
+                Some(Component::Normal(package)) => {
+                    PathBuf::from(format!("{}__{}", &scope[1..], package.to_string_lossy()))
+                }
+
+                _ => PathBuf::from(&scope[1..]),
+            };
+            path.push(components.as_path());
+
+            path
+        }
+
+        _ => dependency.to_owned(),
+    }
+}
+
+/// Try the dependency as a '.d.ts' file
+///   `foo`       => `foo.d.ts`
+///   `foo.js`    => `foo.d.ts`
+///   `foo.d.ts`  => `foo.d.ts`
+fn resolve_as_file(path: &Path) -> Option<PathBuf> {
+    let path_str = path.to_string_lossy();
+
+    let candidate = if path_str.ends_with(DTS_EXTENSION) {
+        path.to_owned()
+    } else if let Some(stem) = path_str.strip_suffix(".js") {
+        PathBuf::from(format!("{}{}", stem, DTS_EXTENSION))
+    } else {
+        PathBuf::from(format!("{}{}", path_str, DTS_EXTENSION))
+    };
+
+    if candidate.is_file() {
+        Some(candidate)
     } else {
-        todo!("Other module resolution");
+        None
     }
 }
 
-fn prepare_path(path: &mut PathBuf) {
-    if path.file_name().is_none() {
-        panic!("Module path must contain a file");
+/// Try the dependency as a package directory
+///   Uses the `types` (or `typings`) field in `package.json` if it exists
+///   Otherwise, falls back to `index.d.ts`
+fn resolve_as_directory(path: &Path) -> Option<PathBuf> {
+    if !path.is_dir() {
+        return None;
     }
 
-    match path.extension() {
-        Some(os_str_ext) => {
-            if os_str_ext != "d.ts" {
-                let mut ext = os_str_ext.to_os_string();
-                ext.push(".d.ts");
-                path.set_extension(ext);
-            }
-        }
+    let typings = fs::read_to_string(path.join("package.json"))
+        .ok()
+        .and_then(|contents| serde_json::from_str::<Value>(&contents).ok())
+        .and_then(|package| {
+            package
+                .get("types")
+                .or(package.get("typings"))
+                .and_then(|typings| typings.as_str())
+                .map(|typings| path.join(typings))
+        });
 
-        None => {
-            path.set_extension("d.ts");
+    if let Some(typings) = typings {
+        let located = if typings.is_dir() {
+            resolve_as_file(&typings.join("index"))
+        } else {
+            resolve_as_file(&typings)
+        };
+
+        if located.is_some() {
+            return located;
         }
     }
+
+    resolve_as_file(&path.join("index"))
 }
 
 pub fn get_decl_ident(decl: &Decl) -> &Ident {
@@ -62,3 +266,235 @@ pub fn get_decl_ident(decl: &Decl) -> &Ident {
         Decl::TsModule(..) => panic!("get_decl_ident() does not work on TsModule"),
     }
 }
+
+/// Declarations of the type rooted in a module as `key` (in declaration order)
+///   Several declarations of the same name are merged (see `Type::merge()`)
+///
+/// Default exported classes and interfaces are rooted under both their identifier
+///   and the default export key
+pub fn rooted_type_decls(module: &Module, key: &JsWord) -> Vec<Decl> {
+    module
+        .body
+        .iter()
+        .filter_map(|item| match item {
+            ModuleItem::Stmt(Stmt::Decl(ref decl))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { ref decl, .. })) => {
+                match decl {
+                    Decl::Class(..)
+                    | Decl::TsInterface(..)
+                    | Decl::TsTypeAlias(..)
+                    | Decl::TsEnum(..)
+                        if get_decl_ident(decl).sym == *key =>
+                    {
+                        Some(decl.clone())
+                    }
+
+                    _ => None,
+                }
+            }
+
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                ref decl,
+                ..
+            })) => {
+                let is_default = *key == default_export_key();
+
+                match decl {
+                    DefaultDecl::Class(ClassExpr {
+                        ref ident,
+                        ref class,
+                    }) if is_default || ident.as_ref().map(|i| &i.sym) == Some(key) => {
+                        Some(Decl::Class(ClassDecl {
+                            ident: ident.clone().unwrap_or(Ident::new(key.clone(), class.span)),
+                            declare: false,
+                            class: class.clone(),
+                        }))
+                    }
+
+                    DefaultDecl::TsInterfaceDecl(ref decl) if is_default || decl.id.sym == *key => {
+                        Some(Decl::TsInterface(decl.clone()))
+                    }
+
+                    _ => None,
+                }
+            }
+
+            _ => None,
+        })
+        .collect()
+}
+
+/// Declarations of the value rooted in a module as `key` (in declaration order)
+///   Functions declared several times are overloaded (see `Type::overload()`)
+///
+/// Each declaration is paired with the name it declares, which is the local name of values
+///   exported under another name (i.e. `export { foo as bar }`, `export default foo` and `export = foo`)
+///
+/// Aliases exported in a cycle (i.e. `export { a as b, b as a }`) declare no value
+pub fn rooted_value_decls(
+    module: &Module,
+    key: &JsWord,
+) -> Result<Vec<(JsWord, Decl)>, TypeError> {
+    collect_value_decls(module, key, &mut Vec::new())
+}
+
+/// `visiting` holds the keys whose aliases are being followed (see `rooted_value_decls()`)
+fn collect_value_decls(
+    module: &Module,
+    key: &JsWord,
+    visiting: &mut Vec<JsWord>,
+) -> Result<Vec<(JsWord, Decl)>, TypeError> {
+    if visiting.contains(key) {
+        return Err(TypeError::UnknownValue(key.to_string()));
+    }
+    visiting.push(key.clone());
+
+    let mut decls = Vec::new();
+
+    for item in module.body.iter() {
+        match item {
+            ModuleItem::Stmt(Stmt::Decl(ref decl))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { ref decl, .. })) => {
+                let declared = match decl {
+                    Decl::Var(ref var) => {
+                        var.decls.iter().any(|declarator| match declarator.name {
+                            Pat::Ident(ref ident) => ident.sym == *key,
+                            _ => false,
+                        })
+                    }
+
+                    Decl::Fn(..) | Decl::Class(..) | Decl::TsEnum(..) => {
+                        get_decl_ident(decl).sym == *key
+                    }
+
+                    _ => false,
+                };
+
+                if declared {
+                    decls.push((key.clone(), decl.clone()));
+                }
+            }
+
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                ref decl,
+                ..
+            })) => {
+                let is_default = *key == default_export_key();
+
+                match decl {
+                    DefaultDecl::Fn(FnExpr {
+                        ref ident,
+                        ref function,
+                    }) if is_default || ident.as_ref().map(|i| &i.sym) == Some(key) => {
+                        let decl = Decl::Fn(FnDecl {
+                            ident: ident
+                                .clone()
+                                .unwrap_or(Ident::new(key.clone(), function.span)),
+                            declare: false,
+                            function: function.clone(),
+                        });
+                        decls.push((key.clone(), decl));
+                    }
+
+                    DefaultDecl::Class(ClassExpr {
+                        ref ident,
+                        ref class,
+                    }) if is_default || ident.as_ref().map(|i| &i.sym) == Some(key) => {
+                        let decl = Decl::Class(ClassDecl {
+                            ident: ident.clone().unwrap_or(Ident::new(key.clone(), class.span)),
+                            declare: false,
+                            class: class.clone(),
+                        });
+                        decls.push((key.clone(), decl));
+                    }
+
+                    _ => (),
+                }
+            }
+
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(ref export))
+                if *key == default_export_key() =>
+            {
+                if let Some(ident) = default_export_ident(export) {
+                    decls.extend(collect_value_decls(module, &ident.sym, visiting)?);
+                }
+            }
+
+            ModuleItem::ModuleDecl(ModuleDecl::TsExportAssignment(ref export))
+                if *key == default_export_key() =>
+            {
+                if let Some(ident) = export_assignment_ident(export) {
+                    decls.extend(collect_value_decls(module, &ident.sym, visiting)?);
+                }
+            }
+
+            ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+                ref specifiers,
+                src: None,
+                ..
+            })) => {
+                for specifier in specifiers.iter() {
+                    if let ExportSpecifier::Named(ExportNamedSpecifier {
+                        ref orig,
+                        exported: Some(ref exported),
+                        ..
+                    }) = specifier
+                    {
+                        if exported.sym == *key && orig.sym != *key {
+                            decls.extend(collect_value_decls(module, &orig.sym, visiting)?);
+                        }
+                    }
+                }
+            }
+
+            _ => (),
+        }
+    }
+
+    visiting.pop();
+    Ok(decls)
+}
+
+/// Export key of a module's default export
+///   (i.e. `export default foo` and `import foo from "..."`)
+pub fn default_export_key() -> JsWord {
+    JsWord::from("default")
+}
+
+/// Identifier exported by `export default foo`
+///   Other default export expressions are unsupported
+pub fn default_export_ident(export: &ExportDefaultExpr) -> Option<&Ident> {
+    match *export.expr {
+        Expr::Ident(ref ident) => Some(ident),
+
+        _ => None,
+    }
+}
+
+/// Specifier of `import x = require("specifier")`
+///   Aliases of namespaces (i.e. `import x = A.B`) are unsupported
+pub fn import_equals_specifier(decl: &TsImportEqualsDecl) -> Option<&Str> {
+    match decl.module_ref {
+        TsModuleRef::TsExternalModuleRef(TsExternalModuleRef { ref expr, .. }) => Some(expr),
+
+        TsModuleRef::TsEntityName(..) => None,
+    }
+}
+
+/// Identifier assigned by `export = foo`
+///   Other export assignment expressions are unsupported
+pub fn export_assignment_ident(export: &TsExportAssignment) -> Option<&Ident> {
+    match *export.expr {
+        Expr::Ident(ref ident) => Some(ident),
+
+        _ => None,
+    }
+}
+
+/// Top-level declarations of a script module (i.e. its global declarations)
+pub fn script_decls(module: &Module) -> impl Iterator<Item = &Decl> {
+    module.body.iter().filter_map(|item| match item {
+        ModuleItem::Stmt(Stmt::Decl(ref decl)) => Some(decl),
+        _ => None,
+    })
+}
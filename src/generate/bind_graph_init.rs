@@ -1,10 +1,10 @@
 use std::collections::{HashMap, HashSet};
 
 use swc_atoms::JsWord;
-use swc_common::Span;
 use swc_ecma_ast::*;
 
-use super::bind_init::{ModuleData, ParsedModuleCache as ModuleCache};
+use super::bind_common;
+use super::bind_init::{ModuleData, ModuleKind, ParsedModuleCache as ModuleCache};
 use super::error::*;
 use super::structures::{CanonPath, ItemState};
 
@@ -26,6 +26,12 @@ pub struct ModuleNode {
     pub path: CanonPath,
     pub rooted_export_types: HashSet<JsWord>,
     pub rooted_export_values: HashSet<JsWord>,
+
+    /// Namespaces declared in this module (see `CanonPath::nested()`)
+    pub rooted_export_namespaces: HashSet<JsWord>,
+
+    /// Modules exported as namespaces (i.e. `export * as ns from "./m"`)
+    pub rooted_export_modules: HashMap<JsWord, CanonPath>,
 }
 
 impl ModuleNode {
@@ -36,11 +42,25 @@ impl ModuleNode {
     pub fn is_rooted_value(&self, key: &JsWord) -> bool {
         self.rooted_export_values.contains(key)
     }
+
+    pub fn is_rooted_namespace(&self, key: &JsWord) -> bool {
+        self.rooted_export_namespaces.contains(key) || self.rooted_export_modules.contains_key(key)
+    }
+
+    /// Module of the namespace rooted in this module as `key`
+    pub fn namespace_module(&self, key: &JsWord) -> Option<CanonPath> {
+        if self.rooted_export_namespaces.contains(key) {
+            return Some(self.path.nested(key.clone()));
+        }
+
+        self.rooted_export_modules.get(key).cloned()
+    }
 }
 
 pub enum Import {
     NamedType { source: CanonPath, src_key: JsWord },
     NamedValue { source: CanonPath, src_key: JsWord },
+    NamedNamespace { source: CanonPath, src_key: JsWord },
     Named { source: CanonPath, src_key: JsWord },
 }
 
@@ -56,6 +76,11 @@ pub enum Export {
         src_key: JsWord,
         export_key: JsWord,
     },
+    NamedNamespace {
+        source: CanonPath,
+        src_key: JsWord,
+        export_key: JsWord,
+    },
     Named {
         source: CanonPath,
         src_key: JsWord,
@@ -71,6 +96,7 @@ impl Export {
         match self {
             Export::NamedType { ref source, .. } => source,
             Export::NamedValue { ref source, .. } => source,
+            Export::NamedNamespace { ref source, .. } => source,
             Export::Named { ref source, .. } => source,
             Export::All { ref source, .. } => source,
         }
@@ -91,6 +117,54 @@ impl ModuleGraph {
     fn module_instantiated(&self, p: &CanonPath) -> bool {
         self.nodes.contains_key(p)
     }
+
+    /// Module of the namespace exported from `source` as `key`
+    ///
+    /// Assumes the graph is reduced (see `graph_reduce::reduce()`)
+    pub fn exported_namespace(&self, source: &CanonPath, key: &JsWord) -> Option<CanonPath> {
+        let node = self.nodes.get(source)?;
+        if let Some(namespace) = node.namespace_module(key) {
+            return Some(namespace);
+        }
+
+        self.export_edges
+            .get(source)?
+            .iter()
+            .filter_map(|edge| match edge {
+                Export::NamedNamespace {
+                    ref source,
+                    ref src_key,
+                    ref export_key,
+                } if export_key == key => self.nodes.get(source)?.namespace_module(src_key),
+
+                _ => None,
+            })
+            .next()
+    }
+
+    /// Module and key of the value exported from `source` as `key`
+    ///   (i.e. the declaration of a re-exported value)
+    ///
+    /// Assumes the graph is reduced (see `graph_reduce::reduce()`)
+    pub fn exported_value(&self, source: &CanonPath, key: &JsWord) -> Option<(CanonPath, JsWord)> {
+        if self.nodes.get(source)?.is_rooted_value(key) {
+            return Some((source.clone(), key.clone()));
+        }
+
+        self.export_edges
+            .get(source)?
+            .iter()
+            .filter_map(|edge| match edge {
+                Export::NamedValue {
+                    ref source,
+                    ref src_key,
+                    ref export_key,
+                } if export_key == key => Some((source.clone(), src_key.clone())),
+
+                _ => None,
+            })
+            .next()
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -98,18 +172,29 @@ pub enum ScopeKind {
     Value,
     Type,
     ValueType,
+    Namespace,
+
+    /// Imported items may be any kind of item
+    All,
 }
 
 struct NodeInitSession<'a> {
     path: &'a CanonPath,
+    kind: ModuleKind,
     dependency_map: &'a HashMap<String, CanonPath>,
     import_edges: Vec<Import>,
     export_edges: Vec<Export>,
     rooted_values: HashSet<JsWord>,
     rooted_types: HashSet<JsWord>,
+    rooted_namespaces: HashSet<JsWord>,
+    rooted_modules: HashMap<JsWord, CanonPath>,
 
     value_scope: HashMap<JsWord, ItemState>,
     type_scope: HashMap<JsWord, ItemState>,
+    namespace_scope: HashMap<JsWord, ItemState>,
+
+    /// Module-valued items (i.e. `import * as M from "./m"`)
+    module_scope: HashMap<JsWord, CanonPath>,
 }
 
 impl<'a> NodeInitSession<'a> {
@@ -120,22 +205,46 @@ impl<'a> NodeInitSession<'a> {
     ) -> Result<(), BindGenError> {
         let mut session = NodeInitSession {
             path: &module_data.path,
+            kind: module_data.kind,
             dependency_map: &module_data.dependencies,
             import_edges: Vec::new(),
             export_edges: Vec::new(),
             rooted_values: HashSet::new(),
             rooted_types: HashSet::new(),
+            rooted_namespaces: HashSet::new(),
+            rooted_modules: HashMap::new(),
 
             value_scope: HashMap::new(),
             type_scope: HashMap::new(),
+            namespace_scope: HashMap::new(),
+            module_scope: HashMap::new(),
         };
 
         for item in module_data.module_ast.body.iter() {
             session.process_module_item(item)?;
         }
 
+        // Global declarations are shadowed by module declarations
+        for global_module in cache.globals() {
+            for decl in bind_common::script_decls(&global_module.module_ast) {
+                let (symbols, scope_kind) = decl_symbols(decl);
+
+                for symbol in symbols.into_iter() {
+                    let item = ItemState::Imported {
+                        source: global_module.path.clone(),
+                        src_key: symbol.clone(),
+                        as_key: symbol.clone(),
+                    };
+
+                    session.scope_item(symbol, item, scope_kind);
+                }
+            }
+        }
+
         let rooted_export_types = session.rooted_types;
         let rooted_export_values = session.rooted_values;
+        let rooted_export_namespaces = session.rooted_namespaces;
+        let rooted_export_modules = session.rooted_modules;
         let import_edges = session.import_edges;
         let export_edges = session.export_edges;
 
@@ -143,6 +252,8 @@ impl<'a> NodeInitSession<'a> {
             path: module_data.path.clone(),
             rooted_export_types,
             rooted_export_values,
+            rooted_export_namespaces,
+            rooted_export_modules,
         };
 
         g.nodes.insert(module_data.path.clone(), module_node);
@@ -156,38 +267,24 @@ impl<'a> NodeInitSession<'a> {
     }
 
     fn scope_item(&mut self, name: JsWord, state: ItemState, kind: ScopeKind) {
-        use std::collections::hash_map::Entry;
-
-        match kind {
-            ScopeKind::Value => match self.value_scope.entry(name) {
-                Entry::Vacant(vacant) => {
-                    vacant.insert(state);
-                }
-                Entry::Occupied(ref mut occupied) => (),
-            },
+        let (value, typ, namespace) = match kind {
+            ScopeKind::Value => (true, false, false),
+            ScopeKind::Type => (false, true, false),
+            ScopeKind::ValueType => (true, true, false),
+            ScopeKind::Namespace => (false, false, true),
+            ScopeKind::All => (true, true, true),
+        };
 
-            ScopeKind::Type => match self.type_scope.entry(name) {
-                Entry::Vacant(vacant) => {
-                    vacant.insert(state);
-                }
-                Entry::Occupied(ref mut occupied) => (),
-            },
+        if value {
+            scope_insert(&mut self.value_scope, name.clone(), state.clone());
+        }
 
-            ScopeKind::ValueType => {
-                match self.type_scope.entry(name.clone()) {
-                    Entry::Vacant(vacant) => {
-                        vacant.insert(state.clone());
-                    }
-                    Entry::Occupied(ref mut occupied) => (),
-                }
+        if typ {
+            scope_insert(&mut self.type_scope, name.clone(), state.clone());
+        }
 
-                match self.value_scope.entry(name) {
-                    Entry::Vacant(vacant) => {
-                        vacant.insert(state);
-                    }
-                    Entry::Occupied(ref mut occupied) => (),
-                }
-            }
+        if namespace {
+            scope_insert(&mut self.namespace_scope, name, state);
         }
     }
 
@@ -201,7 +298,7 @@ impl<'a> NodeInitSession<'a> {
 
     fn process_stmt(&mut self, stmt: &Stmt) -> Result<(), BindGenError> {
         if let Stmt::Decl(ref decl) = stmt {
-            self.process_decl(decl, false)?;
+            self.process_decl(decl, self.kind.is_export_context())?;
         }
 
         Ok(())
@@ -232,22 +329,58 @@ impl<'a> NodeInitSession<'a> {
                 Ok(())
             }
 
-            ModuleDecl::ExportDefaultDecl(..) => unreachable!("Caught in bind init"),
+            ModuleDecl::ExportDefaultDecl(ExportDefaultDecl { ref decl, .. }) => {
+                self.process_default_decl(decl);
+                Ok(())
+            }
+
+            ModuleDecl::ExportDefaultExpr(ref export) => {
+                let ident = bind_common::default_export_ident(export).expect("Caught in bind init");
+                self.export_local(ident.sym.clone(), bind_common::default_export_key());
+                Ok(())
+            }
+
+            ModuleDecl::TsImportEquals(ref import) => {
+                let src =
+                    bind_common::import_equals_specifier(import).expect("Caught in bind init");
+                let src_canon_path: &CanonPath = get_dep_src!(self, src);
+                let local = import.id.sym.clone();
 
-            ModuleDecl::ExportDefaultExpr(..) => unreachable!("Caught in bind init"),
+                self.module_scope
+                    .entry(local.clone())
+                    .or_insert(src_canon_path.clone());
 
-            ModuleDecl::TsImportEquals(..) => unreachable!("Caught in bind init"),
+                // The module value is the default export of the module under CommonJS interop
+                //   No import edge b/c only modules with `export =` have a default export
+                let item = ItemState::Imported {
+                    source: src_canon_path.clone(),
+                    src_key: bind_common::default_export_key(),
+                    as_key: local.clone(),
+                };
+                self.scope_item(local.clone(), item, ScopeKind::ValueType);
 
-            ModuleDecl::TsExportAssignment(..) => unreachable!("Caught in bind init"),
+                if import.is_export {
+                    self.export_local(local.clone(), local);
+                }
 
-            ModuleDecl::TsNamespaceExport(..) => unreachable!("Caught in bind init"),
+                Ok(())
+            }
+
+            ModuleDecl::TsExportAssignment(ref export) => {
+                let ident =
+                    bind_common::export_assignment_ident(export).expect("Caught in bind init");
+                self.process_export_assignment(ident.sym.clone());
+                Ok(())
+            }
+
+            // UMD global names only affect how the root module is bound (see `RootBinding`)
+            ModuleDecl::TsNamespaceExport(..) => Ok(()),
         }
     }
 
     fn prune_export_specifiers<'c, T>(
         &self,
         specifiers: T,
-        exp_span: &Span,
     ) -> Result<Vec<&'c ExportSpecifier>, BindGenError>
     where
         T: Iterator<Item = &'c ExportSpecifier>,
@@ -255,29 +388,11 @@ impl<'a> NodeInitSession<'a> {
         let mut buff = Vec::new();
         for spec in specifiers {
             match spec {
-                ExportSpecifier::Named(..) => {
+                ExportSpecifier::Named(..)
+                | ExportSpecifier::Default(..)
+                | ExportSpecifier::Namespace(..) => {
                     buff.push(spec);
                 }
-
-                ExportSpecifier::Namespace(ExportNamespaceSpecifier { ref span, .. }) => {
-                    return Err(BindGenError {
-                        module_path: self.path.as_path().to_owned(),
-                        kind: BindGenErrorKind::UnsupportedFeature(
-                            UnsupportedFeature::NamespaceExport,
-                        ),
-                        span: span.clone(),
-                    });
-                }
-
-                ExportSpecifier::Default(..) => {
-                    return Err(BindGenError {
-                        module_path: self.path.as_path().to_owned(),
-                        kind: BindGenErrorKind::UnsupportedFeature(
-                            UnsupportedFeature::DefaultExport,
-                        ),
-                        span: exp_span.clone(),
-                    });
-                }
             }
         }
 
@@ -285,7 +400,7 @@ impl<'a> NodeInitSession<'a> {
     }
 
     fn process_named_export(&mut self, exp: &NamedExport) -> Result<(), BindGenError> {
-        let specifiers = self.prune_export_specifiers(exp.specifiers.iter(), &exp.span)?;
+        let specifiers = self.prune_export_specifiers(exp.specifiers.iter())?;
 
         match exp.src {
             Some(ref src) => {
@@ -312,7 +427,22 @@ impl<'a> NodeInitSession<'a> {
                             });
                         }
 
-                        _ => unreachable!("Invalid specifier should be pruned"),
+                        // `export foo from "..."`
+                        ExportSpecifier::Default(ExportDefaultSpecifier { ref exported }) => {
+                            self.export_edges.push(Export::Named {
+                                source: src_canon_path.clone(),
+                                src_key: bind_common::default_export_key(),
+                                export_key: exported.sym.clone(),
+                            });
+                        }
+
+                        // `export * as ns from "..."`
+                        ExportSpecifier::Namespace(ExportNamespaceSpecifier {
+                            ref name, ..
+                        }) => {
+                            self.rooted_modules
+                                .insert(name.sym.clone(), src_canon_path.clone());
+                        }
                     }
                 }
 
@@ -333,51 +463,7 @@ impl<'a> NodeInitSession<'a> {
                                 .map(|x| x.sym.clone())
                                 .unwrap_or(orig_key.clone());
 
-                            // Handle the named export if it refers to a rooted item or imported
-                            //   item by adding an edge if it is an imported item
-                            //   or by marking the item as rooted (under its export key)
-
-                            // Handle value
-                            if let Some(ref state) = self.value_scope.get(&orig_key) {
-                                match state {
-                                    ItemState::Imported {
-                                        ref source,
-                                        ref src_key,
-                                        ref as_key,
-                                    } => {
-                                        self.export_edges.push(Export::Named {
-                                            source: source.clone(),
-                                            src_key: src_key.clone(),
-                                            export_key: as_key.clone(),
-                                        });
-                                    }
-
-                                    ItemState::Rooted => {
-                                        self.rooted_values.insert(export_key.clone());
-                                    }
-                                }
-                            }
-
-                            // Handle value
-                            if let Some(ref state) = self.type_scope.get(&orig_key) {
-                                match state {
-                                    ItemState::Imported {
-                                        ref source,
-                                        ref src_key,
-                                        ref as_key,
-                                    } => {
-                                        self.export_edges.push(Export::Named {
-                                            source: source.clone(),
-                                            src_key: src_key.clone(),
-                                            export_key: as_key.clone(),
-                                        });
-                                    }
-
-                                    ItemState::Rooted => {
-                                        self.rooted_types.insert(export_key);
-                                    }
-                                }
-                            }
+                            self.export_local(orig_key, export_key);
                         }
 
                         _ => unreachable!("Invalid specifier should be pruned"),
@@ -389,46 +475,130 @@ impl<'a> NodeInitSession<'a> {
         }
     }
 
-    fn process_decl(&mut self, decl: &Decl, export: bool) -> Result<(), BindGenError> {
-        let (symbols, scope_kind) = match decl {
-            Decl::Class(ClassDecl { ref ident, .. }) => {
-                (vec![ident.sym.clone()], ScopeKind::ValueType)
-            }
+    /// Handle the export of a local name if it refers to a rooted item or imported item
+    ///   by adding an edge if it is an imported item
+    ///   or by marking the item as rooted (under its export key)
+    fn export_local(&mut self, orig_key: JsWord, export_key: JsWord) {
+        self.export_local_item(orig_key.clone(), export_key.clone());
 
-            Decl::Fn(FnDecl { ident, .. }) => (vec![ident.sym.clone()], ScopeKind::Value),
+        // Handle namespace
+        //   Imported namespaces are covered by the `Export::Named` edges of `export_local_item()`
+        if let Some(ItemState::Rooted) = self.namespace_scope.get(&orig_key) {
+            self.rooted_namespaces.insert(export_key.clone());
+        }
 
-            Decl::Var(VarDecl { decls, .. }) => {
-                let mut symbols = Vec::new();
-                decls.iter().for_each(|decl| match decl.name {
-                    Pat::Ident(ref ident) => {
-                        symbols.push(ident.sym.clone());
-                    }
+        // Handle module-valued items
+        if let Some(module) = self.module_scope.get(&orig_key) {
+            self.rooted_modules.insert(export_key, module.clone());
+        }
+    }
 
-                    _ => todo!("Handle all patterns"),
-                });
+    /// Handle the export of a local value or type (see `export_local()`)
+    fn export_local_item(&mut self, orig_key: JsWord, export_key: JsWord) {
+        // Handle value
+        if let Some(ref state) = self.value_scope.get(&orig_key) {
+            match state {
+                ItemState::Imported {
+                    ref source,
+                    ref src_key,
+                    ..
+                } => {
+                    self.export_edges.push(Export::Named {
+                        source: source.clone(),
+                        src_key: src_key.clone(),
+                        export_key: export_key.clone(),
+                    });
+                }
 
-                (symbols, ScopeKind::Value)
+                ItemState::Rooted => {
+                    self.rooted_values.insert(export_key.clone());
+                }
             }
+        }
 
-            Decl::TsInterface(TsInterfaceDecl { id, .. }) => {
-                (vec![id.sym.clone()], ScopeKind::Type)
+        // Handle type
+        if let Some(ref state) = self.type_scope.get(&orig_key) {
+            match state {
+                ItemState::Imported {
+                    ref source,
+                    ref src_key,
+                    ..
+                } => {
+                    self.export_edges.push(Export::Named {
+                        source: source.clone(),
+                        src_key: src_key.clone(),
+                        export_key: export_key.clone(),
+                    });
+                }
+
+                ItemState::Rooted => {
+                    self.rooted_types.insert(export_key);
+                }
             }
+        }
+    }
+
+    /// `export = foo`
+    ///   The module value is rooted under the default export key
+    ///   Members of a namespace merged with the module value are exported from the module itself
+    fn process_export_assignment(&mut self, orig_key: JsWord) {
+        let default_key = bind_common::default_export_key();
+
+        if let Some(ItemState::Rooted) = self.namespace_scope.get(&orig_key) {
+            self.export_edges.push(Export::All {
+                source: self.path.nested(orig_key.clone()),
+            });
+        }
 
-            Decl::TsTypeAlias(TsTypeAliasDecl { id, .. }) => {
-                (vec![id.sym.clone()], ScopeKind::Type)
+        if let Some(module) = self.module_scope.get(&orig_key) {
+            self.export_edges.push(Export::All {
+                source: module.clone(),
+            });
+        }
+
+        self.export_local_item(orig_key, default_key);
+    }
+
+    /// `export default function foo() { }`, `export default class Foo { }`, etc.
+    ///   The declaration is rooted under the default export key
+    ///   Its identifier (if any) is only in scope of this module
+    fn process_default_decl(&mut self, decl: &DefaultDecl) {
+        let (ident, scope_kind) = match decl {
+            DefaultDecl::Class(ClassExpr { ref ident, .. }) => {
+                (ident.as_ref(), ScopeKind::ValueType)
             }
 
-            Decl::TsEnum(TsEnumDecl { id, .. }) => (vec![id.sym.clone()], ScopeKind::Type),
+            DefaultDecl::Fn(FnExpr { ref ident, .. }) => (ident.as_ref(), ScopeKind::Value),
 
-            Decl::TsModule(m) => {
-                todo!(
-                    "TS modules not suppported: {}::{:?}",
-                    self.path.as_path().display(),
-                    m.id
-                );
+            DefaultDecl::TsInterfaceDecl(TsInterfaceDecl { ref id, .. }) => {
+                (Some(id), ScopeKind::Type)
             }
         };
 
+        let default_key = bind_common::default_export_key();
+        match scope_kind {
+            ScopeKind::Value => {
+                self.rooted_values.insert(default_key);
+            }
+
+            ScopeKind::Type => {
+                self.rooted_types.insert(default_key);
+            }
+
+            _ => {
+                self.rooted_types.insert(default_key.clone());
+                self.rooted_values.insert(default_key);
+            }
+        }
+
+        if let Some(ident) = ident {
+            self.scope_item(ident.sym.clone(), ItemState::Rooted, scope_kind);
+        }
+    }
+
+    fn process_decl(&mut self, decl: &Decl, export: bool) -> Result<(), BindGenError> {
+        let (symbols, scope_kind) = decl_symbols(decl);
+
         for symbol in symbols.into_iter() {
             match scope_kind {
                 ScopeKind::Value => {
@@ -453,6 +623,15 @@ impl<'a> NodeInitSession<'a> {
                     }
                     self.scope_item(symbol, ItemState::Rooted, scope_kind);
                 }
+
+                ScopeKind::Namespace => {
+                    if export {
+                        self.rooted_namespaces.insert(symbol.clone());
+                    }
+                    self.scope_item(symbol, ItemState::Rooted, scope_kind);
+                }
+
+                ScopeKind::All => unreachable!("Declarations are never scoped as all kinds"),
             }
         }
 
@@ -464,7 +643,7 @@ impl<'a> NodeInitSession<'a> {
         source: &CanonPath,
         spec: &ImportSpecifier,
     ) -> Result<(), BindGenError> {
-        match spec {
+        let (src_key, local) = match spec {
             ImportSpecifier::Named(ref named) => {
                 let src_key = named
                     .imported
@@ -472,36 +651,82 @@ impl<'a> NodeInitSession<'a> {
                     .map(|export_key| export_key.sym.clone())
                     .unwrap_or(named.local.sym.clone());
 
-                let as_key = named.local.sym.clone();
-
-                self.import_edges.push(Import::Named {
-                    source: source.clone(),
-                    src_key: src_key.clone(),
-                });
+                (src_key, &named.local)
+            }
 
-                let item = ItemState::Imported {
-                    source: source.clone(),
-                    src_key,
-                    as_key,
-                };
+            ImportSpecifier::Default(ref def) => (bind_common::default_export_key(), &def.local),
 
-                let import_key = named.local.sym.clone();
-                self.scope_item(import_key, item, ScopeKind::ValueType);
+            ImportSpecifier::Namespace(ref namespace) => {
+                self.module_scope
+                    .entry(namespace.local.sym.clone())
+                    .or_insert(source.clone());
 
-                Ok(())
+                return Ok(());
             }
+        };
+
+        let as_key = local.sym.clone();
 
-            ImportSpecifier::Default(def) => Err(BindGenError {
-                module_path: self.path.as_path().to_owned(),
-                kind: BindGenErrorKind::UnsupportedFeature(UnsupportedFeature::DefaultImport),
-                span: def.span,
-            }),
-
-            ImportSpecifier::Namespace(namespace) => Err(BindGenError {
-                module_path: self.path.as_path().to_owned(),
-                kind: BindGenErrorKind::UnsupportedFeature(UnsupportedFeature::DefaultImport),
-                span: namespace.span,
-            }),
+        self.import_edges.push(Import::Named {
+            source: source.clone(),
+            src_key: src_key.clone(),
+        });
+
+        let item = ItemState::Imported {
+            source: source.clone(),
+            src_key,
+            as_key,
+        };
+
+        let import_key = local.sym.clone();
+        self.scope_item(import_key, item, ScopeKind::All);
+
+        Ok(())
+    }
+}
+
+fn decl_symbols(decl: &Decl) -> (Vec<JsWord>, ScopeKind) {
+    match decl {
+        Decl::Class(ClassDecl { ref ident, .. }) => (vec![ident.sym.clone()], ScopeKind::ValueType),
+
+        Decl::Fn(FnDecl { ident, .. }) => (vec![ident.sym.clone()], ScopeKind::Value),
+
+        Decl::Var(VarDecl { decls, .. }) => {
+            let mut symbols = Vec::new();
+            decls.iter().for_each(|decl| match decl.name {
+                Pat::Ident(ref ident) => {
+                    symbols.push(ident.sym.clone());
+                }
+
+                _ => todo!("Handle all patterns"),
+            });
+
+            (symbols, ScopeKind::Value)
         }
+
+        Decl::TsInterface(TsInterfaceDecl { id, .. }) => (vec![id.sym.clone()], ScopeKind::Type),
+
+        Decl::TsTypeAlias(TsTypeAliasDecl { id, .. }) => (vec![id.sym.clone()], ScopeKind::Type),
+
+        Decl::TsEnum(TsEnumDecl { id, .. }) => (vec![id.sym.clone()], ScopeKind::Type),
+
+        Decl::TsModule(TsModuleDecl { id, global, .. }) => match id {
+            // Global augmentations are only visible through the global scope
+            TsModuleName::Ident(..) if *global => (Vec::new(), ScopeKind::Namespace),
+
+            TsModuleName::Ident(ref ident) => (vec![ident.sym.clone()], ScopeKind::Namespace),
+
+            // Ambient modules are only visible through imports of their specifier
+            TsModuleName::Str(..) => (Vec::new(), ScopeKind::Namespace),
+        },
+    }
+}
+
+/// The first declaration of a name takes precedence
+fn scope_insert(scope: &mut HashMap<JsWord, ItemState>, name: JsWord, state: ItemState) {
+    use std::collections::hash_map::Entry;
+
+    if let Entry::Vacant(vacant) = scope.entry(name) {
+        vacant.insert(state);
     }
 }
@@ -1,19 +1,26 @@
 use std::collections::HashMap;
 use std::convert::TryFrom;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use swc_common::{errors::Handler, BytePos, SourceMap, Span, SyntaxContext};
+use indexmap::IndexMap;
+use swc_atoms::JsWord;
+use swc_common::{errors::Handler, BytePos, SourceFile, SourceMap, Span, SyntaxContext};
 use swc_ecma_ast::Module;
 use swc_ecma_parser::{lexer::Lexer, JscTarget, Parser, SourceFileInput, Syntax, TsConfig};
 
 use super::bind_common;
 use super::error::*;
 use super::structures::CanonPath;
+use super::tsconfig::CompilerOptions;
 
 pub struct ParsedModuleCache {
     pub root: CanonPath,
     cache: HashMap<CanonPath, ModuleData>,
+
+    /// Modules declaring globals (i.e. scripts and global augmentations)
+    ///   in the order they were discovered
+    globals: Vec<CanonPath>,
 }
 
 impl ParsedModuleCache {
@@ -24,27 +31,116 @@ impl ParsedModuleCache {
     pub fn iter(&self) -> impl Iterator<Item = (&CanonPath, &ModuleData)> {
         self.cache.iter()
     }
+
+    /// Modules whose top-level declarations are in the global scope
+    pub fn globals(&self) -> impl Iterator<Item = &ModuleData> {
+        self.globals.iter().map(move |global| self.get(global))
+    }
 }
 
 pub struct ModuleData {
     pub path: CanonPath,
+    pub kind: ModuleKind,
     pub module_ast: Module,
     pub dependencies: HashMap<String, CanonPath>,
 }
 
-/// TODO: Take into account dependencies which may not be in the assumed location
-///    because of pacakge managers.
-///    i.e. need to take into account import { .. } from ".location/dependency"
-///    Assuming the input is correct, emit a log warning of dependency instead of erroring
+/// A file without any top-level `import` or `export` is a script
+///   Its top-level declarations are global
 ///
+/// Namespaces are modules nested within a file (see `CanonPath::nested()`)
+///
+/// Ambient modules are declared within a file (i.e. `declare module "fs" { }`)
+///   An ambient module with export declarations or an export assignment is a `Module`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ModuleKind {
+    Module,
+    Script,
+    Namespace,
+    Ambient,
+}
+
+impl ModuleKind {
+    /// Declarations without an `export` modifier are still exported
+    ///   (i.e. globals of a script and members of an ambient namespace or module)
+    pub fn is_export_context(&self) -> bool {
+        match self {
+            ModuleKind::Module => false,
+            ModuleKind::Script | ModuleKind::Namespace | ModuleKind::Ambient => true,
+        }
+    }
+
+    fn of_ambient(module_ast: &Module) -> ModuleKind {
+        use swc_ecma_ast::{ModuleDecl, ModuleItem};
+
+        let has_export_decls = module_ast.body.iter().any(|item| {
+            matches!(
+                item,
+                ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(..))
+                    | ModuleItem::ModuleDecl(ModuleDecl::ExportAll(..))
+                    | ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(..))
+                    | ModuleItem::ModuleDecl(ModuleDecl::TsExportAssignment(..))
+            )
+        });
+
+        if has_export_decls {
+            ModuleKind::Module
+        } else {
+            ModuleKind::Ambient
+        }
+    }
+
+    fn of(module_ast: &Module) -> ModuleKind {
+        use swc_ecma_ast::ModuleItem;
+
+        let is_module = module_ast.body.iter().any(|item| match item {
+            ModuleItem::ModuleDecl(..) => true,
+            ModuleItem::Stmt(..) => false,
+        });
+
+        if is_module {
+            ModuleKind::Module
+        } else {
+            ModuleKind::Script
+        }
+    }
+}
+
+/// `/// <reference path="..." />` and `/// <reference types="..." />` directives
+#[derive(Debug, Clone)]
+pub enum ReferenceDirective {
+    Path(String),
+    Types(String),
+}
+
 /// Starting from the root module, parse all Typescript '.d.ts' files in the project
 ///   and map to their canonical path.
+///
+/// Dependencies are located with the Typescript "node" module resolution strategy
+///   and the project's `tsconfig.json` options (see `bind_common::locate_dependency()`)
+///
+/// Files included by triple-slash reference directives are also parsed
+///
+/// Ambient module declarations (i.e. `declare module "fs" { }`) are virtual modules keyed
+///   by their specifier. Imports of a non-relative specifier resolve to the ambient module
+///   of the same name before the file system. If `root_module` is given, the ambient module
+///   of that name is the root instead of the root file.
 pub fn init<'a>(
     source_map: Arc<SourceMap>,
     handler: Handler,
+    compiler_options: &CompilerOptions,
     root_module_path: PathBuf,
+    root_module: Option<&str>,
 ) -> Result<ParsedModuleCache, BindGenError> {
     let mut module_cache: HashMap<CanonPath, ModuleData> = HashMap::new();
+    let mut globals: Vec<CanonPath> = Vec::new();
+
+    // Declarations of the same ambient module (possibly in different files) are merged
+    let mut ambient_modules: IndexMap<String, AmbientModule> = IndexMap::new();
+
+    // Specifiers not found on the file system (importer, specifier, span)
+    //   May refer to an ambient module which has not been parsed yet
+    let mut unresolved: Vec<(CanonPath, String, Span)> = Vec::new();
 
     let root_module_path =
         CanonPath::try_from(root_module_path.clone()).map_err(|e| BindGenError {
@@ -65,17 +161,84 @@ pub fn init<'a>(
 
         let span = span.unwrap_or(Span::new(BytePos(0), BytePos(0), SyntaxContext::empty()));
 
-        let module_ast = open_module(&source_map, &handler, &current_path, span.clone())?;
+        let (source_file, module_ast) =
+            open_module(&source_map, &handler, &current_path, span)?;
+
+        let references = scan_references(compiler_options, &current_path, &source_file)?;
+        work_stack.extend(
+            references
+                .iter()
+                .rev()
+                .map(|(p, s)| (p.clone(), Some(*s))),
+        );
 
-        let dependencies = scan_dependencies(&current_path, &module_ast, span)?;
+        let (dependencies, missing) =
+            scan_dependencies(compiler_options, &current_path, &module_ast, span)?;
         work_stack.extend(
             dependencies
                 .iter()
-                .map(|(_, (p, s))| (p.clone(), Some(s.clone()))),
+                .map(|(_, (p, s))| (p.clone(), Some(*s))),
+        );
+        unresolved.extend(
+            missing
+                .into_iter()
+                .map(|(spec, s)| (current_path.clone(), spec, s)),
         );
 
+        for (specifier, body) in ambient_module_decls(&module_ast) {
+            let ambient = ambient_modules
+                .entry(specifier.to_string())
+                .or_insert_with(|| AmbientModule {
+                    path: current_path.ambient(specifier.clone()),
+                    span: body.span,
+                    items: Vec::new(),
+                    dependencies: HashMap::new(),
+                });
+
+            // Specifiers are resolved relative to the declaring file
+            let declared_path = current_path.ambient(specifier);
+            let (dependencies, missing) =
+                scan_dependencies(compiler_options, &declared_path, &body, body.span)?;
+            work_stack.extend(
+                dependencies
+                    .iter()
+                    .map(|(_, (p, s))| (p.clone(), Some(*s))),
+            );
+            unresolved.extend(
+                missing
+                    .into_iter()
+                    .map(|(spec, s)| (ambient.path.clone(), spec, s)),
+            );
+
+            ambient
+                .dependencies
+                .extend(dependencies.into_iter().map(|(k, (p, _))| (k, p)));
+            ambient.items.extend(body.body);
+        }
+
+        let kind = ModuleKind::of(&module_ast);
+        if let ModuleKind::Script = kind {
+            globals.push(current_path.clone());
+        }
+
+        for (namespace_path, namespace_ast) in split_namespaces(&current_path, &module_ast)? {
+            if namespace_path.is_global_augmentation() {
+                globals.push(namespace_path.clone());
+            }
+
+            let namespace_data = ModuleData {
+                path: namespace_path.clone(),
+                kind: ModuleKind::Namespace,
+                module_ast: namespace_ast,
+                dependencies: HashMap::new(),
+            };
+
+            module_cache.insert(namespace_path, namespace_data);
+        }
+
         let module_data = ModuleData {
             path: current_path.clone(),
+            kind,
             module_ast,
             dependencies: dependencies.into_iter().map(|(k, (p, _))| (k, p)).collect(),
         };
@@ -83,21 +246,359 @@ pub fn init<'a>(
         module_cache.insert(current_path.clone(), module_data);
     }
 
+    let ambient_paths: HashMap<String, CanonPath> = ambient_modules
+        .iter()
+        .map(|(specifier, ambient)| (specifier.clone(), ambient.path.clone()))
+        .collect();
+
+    for (_, ambient) in ambient_modules.into_iter() {
+        let mut module_ast = Module {
+            span: ambient.span,
+            body: ambient.items,
+            shebang: None,
+        };
+        hoist_imports(&mut module_ast);
+
+        for (namespace_path, namespace_ast) in split_namespaces(&ambient.path, &module_ast)? {
+            if namespace_path.is_global_augmentation() {
+                globals.push(namespace_path.clone());
+            }
+
+            let namespace_data = ModuleData {
+                path: namespace_path.clone(),
+                kind: ModuleKind::Namespace,
+                module_ast: namespace_ast,
+                dependencies: HashMap::new(),
+            };
+
+            module_cache.insert(namespace_path, namespace_data);
+        }
+
+        let module_data = ModuleData {
+            path: ambient.path.clone(),
+            kind: ModuleKind::of_ambient(&module_ast),
+            module_ast,
+            dependencies: ambient.dependencies,
+        };
+
+        module_cache.insert(ambient.path, module_data);
+    }
+
+    for (importer, specifier, span) in unresolved.into_iter() {
+        let ambient_path = ambient_paths
+            .get(&specifier)
+            .cloned()
+            .ok_or_else(|| BindGenError {
+                module_path: importer.as_path().to_owned(),
+                kind: BindGenErrorKind::ModuleNotFound(specifier.clone()),
+                span,
+            })?;
+
+        module_cache
+            .get_mut(&importer)
+            .expect("Importer missing from cache")
+            .dependencies
+            .insert(specifier, ambient_path);
+    }
+
+    // Ambient modules take precedence over files found with non-relative specifiers
+    for (_, module_data) in module_cache.iter_mut() {
+        for (specifier, dependency) in module_data.dependencies.iter_mut() {
+            if bind_common::is_path_specifier(Path::new(specifier)) {
+                continue;
+            }
+
+            if let Some(ambient_path) = ambient_paths.get(specifier) {
+                *dependency = ambient_path.clone();
+            }
+        }
+    }
+
+    let root = match root_module {
+        Some(specifier) => ambient_paths
+            .get(specifier)
+            .cloned()
+            .ok_or_else(|| BindGenError {
+                module_path: root_module_path.as_path().to_owned(),
+                kind: BindGenErrorKind::ModuleNotFound(specifier.to_string()),
+                span: Span::new(BytePos(0), BytePos(0), SyntaxContext::empty()),
+            })?,
+
+        None => root_module_path,
+    };
+
     Ok(ParsedModuleCache {
-        root: root_module_path,
+        root,
         cache: module_cache,
+        globals,
     })
 }
 
+struct AmbientModule {
+    path: CanonPath,
+    span: Span,
+    items: Vec<swc_ecma_ast::ModuleItem>,
+    dependencies: HashMap<String, CanonPath>,
+}
+
+/// Top-level `declare module "specifier" { }` declarations of a file
+///   A shorthand declaration (i.e. `declare module "specifier";`) has an empty body
+fn ambient_module_decls(module_ast: &Module) -> Vec<(JsWord, Module)> {
+    use swc_ecma_ast::*;
+
+    let mut decls = Vec::new();
+    for module_item in module_ast.body.iter() {
+        let decl = match module_item {
+            ModuleItem::Stmt(Stmt::Decl(Decl::TsModule(ref decl))) => decl,
+
+            _ => continue,
+        };
+
+        let specifier = match decl.id {
+            TsModuleName::Str(ref specifier) => specifier.value.clone(),
+
+            TsModuleName::Ident(..) => continue,
+        };
+
+        let body = match decl.body {
+            Some(TsNamespaceBody::TsModuleBlock(ref block)) => block.body.clone(),
+
+            _ => Vec::new(),
+        };
+
+        let module = Module {
+            span: decl.span,
+            body,
+            shebang: None,
+        };
+
+        decls.push((specifier, module));
+    }
+
+    decls
+}
+
+/// Create a module for each namespace declared in the module (recursively)
+///   Declarations of the same namespace are merged
+///   Global augmentations (i.e. `declare global { }`) are namespaces of the module
+///     (see `CanonPath::global_augmentation()`)
+///   `namespace A.B { }` is equivalent to `namespace A { namespace B { } }`
+fn split_namespaces(
+    module_path: &CanonPath,
+    module_ast: &Module,
+) -> Result<Vec<(CanonPath, Module)>, BindGenError> {
+    use swc_ecma_ast::*;
+
+    let mut namespaces: IndexMap<CanonPath, Vec<ModuleItem>> = IndexMap::new();
+
+    for module_item in module_ast.body.iter() {
+        let decl = match module_item {
+            ModuleItem::Stmt(Stmt::Decl(Decl::TsModule(ref decl))) => decl,
+
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                decl: Decl::TsModule(ref decl),
+                ..
+            })) => decl,
+
+            _ => continue,
+        };
+
+        let unsupported = |feature| BindGenError {
+            module_path: module_path.as_path().to_owned(),
+            kind: BindGenErrorKind::UnsupportedFeature(feature),
+            span: decl.span,
+        };
+
+        let namespace_path = match decl.id {
+            // Global augmentations are only declared in files and ambient modules
+            TsModuleName::Ident(..) if decl.global => match module_path.enclosing() {
+                None => module_path.global_augmentation(),
+
+                Some(..) => return Err(unsupported(UnsupportedFeature::GlobalAugmentation)),
+            },
+
+            TsModuleName::Ident(ref ident) => module_path.nested(ident.sym.clone()),
+
+            // Split by `ambient_module_decls()`
+            TsModuleName::Str(..) if module_path.is_file() => continue,
+
+            TsModuleName::Str(..) => return Err(unsupported(UnsupportedFeature::AmbientModule)),
+        };
+
+        let items = namespaces.entry(namespace_path).or_default();
+
+        match decl.body {
+            Some(TsNamespaceBody::TsModuleBlock(ref block)) => {
+                items.extend(block.body.iter().cloned());
+            }
+
+            Some(TsNamespaceBody::TsNamespaceDecl(ref inner)) => {
+                let inner_decl = TsModuleDecl {
+                    span: inner.span,
+                    declare: inner.declare,
+                    global: inner.global,
+                    id: TsModuleName::Ident(inner.id.clone()),
+                    body: Some((*inner.body).clone()),
+                };
+
+                items.push(ModuleItem::Stmt(Stmt::Decl(Decl::TsModule(inner_decl))));
+            }
+
+            None => (),
+        }
+    }
+
+    let mut modules = Vec::new();
+    for (namespace_path, items) in namespaces.into_iter() {
+        let namespace_ast = Module {
+            span: module_ast.span,
+            body: items,
+            shebang: None,
+        };
+
+        modules.append(&mut split_namespaces(&namespace_path, &namespace_ast)?);
+        modules.push((namespace_path, namespace_ast));
+    }
+
+    Ok(modules)
+}
+
+fn scan_references(
+    compiler_options: &CompilerOptions,
+    module_path: &CanonPath,
+    source_file: &SourceFile,
+) -> Result<Vec<(CanonPath, Span)>, BindGenError> {
+    let mut references = Vec::new();
+
+    for (directive, span) in parse_reference_directives(source_file) {
+        let located = match directive {
+            ReferenceDirective::Path(ref path) => {
+                bind_common::locate_reference_path(module_path.as_path(), path.as_ref())?
+            }
+
+            ReferenceDirective::Types(ref types) => bind_common::locate_reference_types(
+                compiler_options,
+                module_path.as_path(),
+                types.as_ref(),
+            )?,
+        };
+
+        match located {
+            Some(path) => references.push((path, span)),
+
+            None => {
+                let spec = match directive {
+                    ReferenceDirective::Path(path) => path,
+                    ReferenceDirective::Types(types) => types,
+                };
+
+                return Err(BindGenError {
+                    module_path: module_path.as_path().to_owned(),
+                    kind: BindGenErrorKind::ModuleNotFound(spec),
+                    span,
+                });
+            }
+        }
+    }
+
+    Ok(references)
+}
+
+/// Triple-slash directives are only recognized at the top of a file
+///   (i.e. only preceded by comments and whitespace)
+///   https://www.typescriptlang.org/docs/handbook/triple-slash-directives.html
+///
+/// Other directives (e.g. `lib` and `no-default-lib`) are ignored
+fn parse_reference_directives(source_file: &SourceFile) -> Vec<(ReferenceDirective, Span)> {
+    let mut directives = Vec::new();
+    let mut in_block_comment = false;
+    let mut offset = 0;
+
+    for line in source_file.src.split('\n') {
+        let line_offset = offset;
+        offset += line.len() + 1;
+
+        let mut rest = line.trim();
+
+        if in_block_comment {
+            match rest.find("*/") {
+                Some(end) => {
+                    in_block_comment = false;
+                    rest = rest[end + 2..].trim_start();
+                }
+
+                None => continue,
+            }
+        }
+
+        // Code following a block comment on the same line ends the directives
+        while let Some(comment) = rest.strip_prefix("/*") {
+            match comment.find("*/") {
+                Some(end) => rest = comment[end + 2..].trim_start(),
+
+                None => {
+                    in_block_comment = true;
+                    rest = "";
+                }
+            }
+        }
+
+        if rest.is_empty() {
+            continue;
+        }
+
+        if let Some(directive) = rest.strip_prefix("///") {
+            if let Some(directive) = parse_reference_directive(directive) {
+                let lo = source_file.start_pos + BytePos(line_offset as u32);
+                let hi = lo + BytePos(line.len() as u32);
+                directives.push((directive, Span::new(lo, hi, SyntaxContext::empty())));
+            }
+        } else if rest.starts_with("//") {
+            continue;
+        } else {
+            break;
+        }
+    }
+
+    directives
+}
+
+fn parse_reference_directive(directive: &str) -> Option<ReferenceDirective> {
+    let directive = directive.trim();
+    if !directive.starts_with("<reference") {
+        return None;
+    }
+
+    let attribute = |name: &str| -> Option<String> {
+        let start = directive.find(&format!("{}=", name))? + name.len() + 1;
+        let rest = &directive[start..];
+
+        let quote = rest.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+        let end = rest[1..].find(quote)?;
+
+        Some(rest[1..end + 1].to_string())
+    };
+
+    attribute("path")
+        .map(ReferenceDirective::Path)
+        .or_else(|| attribute("types").map(ReferenceDirective::Types))
+}
+
+/// Resolved dependencies and non-relative specifiers which are not found
+type Dependencies = (HashMap<String, (CanonPath, Span)>, Vec<(String, Span)>);
+
+/// Non-relative specifiers which are not found are returned separately
+///   (they may refer to an ambient module)
 fn scan_dependencies(
+    compiler_options: &CompilerOptions,
     module_path: &CanonPath,
     module_ast: &Module,
     _original_span: Span,
-) -> Result<HashMap<String, (CanonPath, Span)>, BindGenError> {
+) -> Result<Dependencies, BindGenError> {
     use swc_ecma_ast::*;
 
     let handle_decl =
-        |decl: &ModuleDecl| -> Result<Option<(String, CanonPath, Span)>, BindGenError> {
+        |decl: &ModuleDecl| -> Result<Option<(String, Option<CanonPath>, Span)>, BindGenError> {
             let maybe_dep: Option<(&Str, &Span)> = match decl {
                 ModuleDecl::Import(ImportDecl {
                     ref src, ref span, ..
@@ -109,88 +610,100 @@ fn scan_dependencies(
                     ref src, ref span, ..
                 }) => src.as_ref().map(|src| (src, span)),
 
-                ModuleDecl::ExportDefaultDecl(ref export) => {
-                    return Err(BindGenError {
-                        module_path: module_path.as_path().to_owned(),
-                        kind: BindGenErrorKind::UnsupportedFeature(
-                            UnsupportedFeature::DefaultExport,
-                        ),
-                        span: export.span.clone(),
-                    })
-                }
+                ModuleDecl::ExportDefaultDecl(..) => None,
 
                 ModuleDecl::ExportDefaultExpr(ref export) => {
+                    if bind_common::default_export_ident(export).is_some() {
+                        return Ok(None);
+                    }
+
                     return Err(BindGenError {
                         module_path: module_path.as_path().to_owned(),
                         kind: BindGenErrorKind::UnsupportedFeature(
                             UnsupportedFeature::DefaultExport,
                         ),
-                        span: export.span.clone(),
+                        span: export.span,
                     });
                 }
 
                 ModuleDecl::ExportAll(ExportAll { ref src, ref span }) => Some((src, span)),
 
-                ModuleDecl::TsImportEquals(TsImportEqualsDecl { ref span, .. }) => {
-                    return Err(BindGenError {
-                        module_path: module_path.as_path().to_owned(),
-                        kind: BindGenErrorKind::UnsupportedFeature(
-                            UnsupportedFeature::TsImportEquals,
-                        ),
-                        span: span.clone(),
-                    });
+                ModuleDecl::TsImportEquals(ref import) => {
+                    if let Some(src) = bind_common::import_equals_specifier(import) {
+                        Some((src, &import.span))
+                    } else {
+                        return Err(BindGenError {
+                            module_path: module_path.as_path().to_owned(),
+                            kind: BindGenErrorKind::UnsupportedFeature(
+                                UnsupportedFeature::TsImportEquals,
+                            ),
+                            span: import.span,
+                        });
+                    }
                 }
 
-                ModuleDecl::TsExportAssignment(TsExportAssignment { ref span, .. }) => {
+                ModuleDecl::TsExportAssignment(ref export) => {
+                    if bind_common::export_assignment_ident(export).is_some() {
+                        return Ok(None);
+                    }
+
                     return Err(BindGenError {
                         module_path: module_path.as_path().to_owned(),
                         kind: BindGenErrorKind::UnsupportedFeature(
                             UnsupportedFeature::TsExportAssignment,
                         ),
-                        span: span.clone(),
+                        span: export.span,
                     });
                 }
 
-                ModuleDecl::TsNamespaceExport(TsNamespaceExportDecl { ref span, .. }) => {
-                    // TODO: Handle TsNamespaceExport?
-                    //   What is TsNamespaceExport??
-                    return Err(BindGenError {
-                        module_path: module_path.as_path().to_owned(),
-                        kind: BindGenErrorKind::UnsupportedFeature(
-                            UnsupportedFeature::TsNamespaceExport,
-                        ),
-                        span: span.clone(),
-                    });
-                }
+                // `export as namespace X` (UMD global name)
+                ModuleDecl::TsNamespaceExport(..) => None,
             };
 
             maybe_dep
                 .map(|(src, span)| {
                     let dep_buf = PathBuf::from(src.value.to_string());
-                    bind_common::locate_dependency(module_path.as_path(), &dep_buf).map(
-                        |path_result| {
-                            path_result.map(|path| (src.value.to_string(), path, span.clone()))
-                        },
+                    bind_common::locate_dependency(
+                        compiler_options,
+                        module_path.as_path(),
+                        &dep_buf,
                     )
+                    .and_then(|path_result| match path_result {
+                        Some(path) => Ok((src.value.to_string(), Some(path), *span)),
+
+                        None if !bind_common::is_path_specifier(&dep_buf) => {
+                            Ok((src.value.to_string(), None, *span))
+                        }
+
+                        None => Err(BindGenError {
+                            module_path: module_path.as_path().to_owned(),
+                            kind: BindGenErrorKind::ModuleNotFound(src.value.to_string()),
+                            span: *span,
+                        }),
+                    })
                 })
                 .transpose()
-                .map(|opt| opt.flatten())
         };
 
     let mut dep_buf = HashMap::new();
+    let mut missing_buf = Vec::new();
     for module_item in module_ast.body.iter() {
         match module_item {
-            ModuleItem::ModuleDecl(ref decl) => {
-                if let Some((src, dep, span)) = handle_decl(decl)? {
+            ModuleItem::ModuleDecl(ref decl) => match handle_decl(decl)? {
+                Some((src, Some(dep), span)) => {
                     dep_buf.insert(src, (dep, span));
                 }
-            }
+
+                Some((src, None, span)) => missing_buf.push((src, span)),
+
+                None => (),
+            },
 
             ModuleItem::Stmt(..) => (),
         }
     }
 
-    Ok(dep_buf)
+    Ok((dep_buf, missing_buf))
 }
 
 fn open_module<'a>(
@@ -198,12 +711,12 @@ fn open_module<'a>(
     handler: &Handler,
     path: &CanonPath,
     span: Span,
-) -> Result<Module, BindGenError> {
+) -> Result<(Arc<SourceFile>, Module), BindGenError> {
     let file_handle = source_map
         .load_file(path.as_path())
         .map_err(|io_err| BindGenError {
             kind: BindGenErrorKind::IoError(io_err),
-            span: span.clone(),
+            span,
             module_path: path.as_path().to_owned(),
         })?;
 
@@ -227,14 +740,14 @@ fn open_module<'a>(
 
         BindGenError {
             kind: BindGenErrorKind::ParserError,
-            span: span.clone(),
+            span,
             module_path: path.as_path().to_owned(),
         }
     })?;
 
     hoist_imports(&mut module);
 
-    Ok(module)
+    Ok((file_handle, module))
 }
 
 fn hoist_imports(module: &mut Module) {
@@ -250,7 +763,10 @@ fn hoist_imports(module: &mut Module) {
 
     for module_item in module_items {
         match module_item {
-            import @ ModuleItem::ModuleDecl(ModuleDecl::Import(..)) => module.body.push(import),
+            import @ ModuleItem::ModuleDecl(ModuleDecl::Import(..))
+            | import @ ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals(..)) => {
+                module.body.push(import)
+            }
 
             other => other_buffer.push(other),
         }
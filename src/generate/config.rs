@@ -24,6 +24,144 @@ pub struct GenConfig {
     /// Serde name needs to be kept in sync with `OPTION_WRAP_TOP_LEVEL_VARS`
     #[serde(rename = "wrap-top-level-vars")]
     pub wrap_top_level_vars: bool,
+
+    /// Pyret name of default exports. Defaults to `default`.
+    /// Used by:
+    ///     * TS-NUM
+    /// Serde name needs to be kept in sync with `OPTION_DEFAULT_EXPORT_NAME`
+    #[serde(rename = "default-export-name")]
+    pub default_export_name: DefaultExportName,
+
+    /// How optional trailing parameters of exported functions are bound. Defaults to `overloads`.
+    /// Used by:
+    ///     * TS-FULL
+    ///     * TS-NUM
+    /// Serde name needs to be kept in sync with `OPTION_OPTIONAL_PARAMS`
+    #[serde(rename = "optional-params")]
+    pub optional_params: OptionalParams,
+
+    /// How overloaded functions are bound. Defaults to `suffixed`.
+    /// Used by:
+    ///     * TS-FULL
+    ///     * TS-NUM
+    /// Serde name needs to be kept in sync with `OPTION_OVERLOADS`
+    #[serde(rename = "overloads")]
+    pub overloads: Overloads,
+
+    /// Pyret type of objects used as dictionaries. Defaults to `immutable`.
+    /// Used by:
+    ///     * TS-FULL
+    /// Serde name needs to be kept in sync with `OPTION_STRING_DICTS`
+    #[serde(rename = "string-dicts")]
+    pub string_dicts: StringDicts,
+
+    /// Read Pyret string dicts passed to TS lazily. Defaults to false.
+    /// Used by:
+    ///     * TS-FULL
+    /// Serde name needs to be kept in sync with `OPTION_LAZY_DICTS`
+    #[serde(rename = "lazy-dicts")]
+    pub lazy_dicts: bool,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DefaultExportName {
+    /// Export under `default`
+    #[serde(rename = "default")]
+    Default,
+
+    /// Export under the identifier of the exported declaration (i.e. `foo` for
+    ///   `export default function foo() { }`). Anonymous declarations fall back to `default`.
+    #[serde(rename = "declaration")]
+    Declaration,
+}
+
+impl std::str::FromStr for DefaultExportName {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "default" => Ok(DefaultExportName::Default),
+            "declaration" => Ok(DefaultExportName::Declaration),
+            _ => Err(format!("Unknown default export name \"{}\"", s)),
+        }
+    }
+}
+
+/// Optional parameters of function types nested in other types are always passed
+///   (as if they were required)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OptionalParams {
+    /// Export a function per arity. The function taking every parameter keeps its name
+    ///   Functions omitting optional parameters are suffixed by their arity
+    ///   (i.e. `foo-1` and `foo` for `function foo(x: number, y?: number)`)
+    #[serde(rename = "overloads")]
+    Overloads,
+
+    /// Optional parameters are `Option` arguments
+    #[serde(rename = "option")]
+    Option,
+}
+
+impl std::str::FromStr for OptionalParams {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "overloads" => Ok(OptionalParams::Overloads),
+            "option" => Ok(OptionalParams::Option),
+            _ => Err(format!("Unknown optional parameter binding \"{}\"", s)),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Overloads {
+    /// Export a function per signature suffixed by its position in declaration order
+    ///   (i.e. `foo-1` and `foo-2` for two declarations of `foo`)
+    #[serde(rename = "suffixed")]
+    Suffixed,
+
+    /// Export a single function dispatching on the number of arguments
+    ///   Falls back to `suffixed` if several signatures accept the same number of arguments
+    #[serde(rename = "dispatch")]
+    Dispatch,
+}
+
+impl std::str::FromStr for Overloads {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "suffixed" => Ok(Overloads::Suffixed),
+            "dispatch" => Ok(Overloads::Dispatch),
+            _ => Err(format!("Unknown overload binding \"{}\"", s)),
+        }
+    }
+}
+
+/// TS dictionaries are copied into Pyret string dicts (converting every value)
+///   Pyret string dicts are either copied into TS objects or read lazily (see `GenConfig.lazy_dicts`)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StringDicts {
+    /// `StringDict` (changes made by TS to a copy are not visible to Pyret)
+    #[serde(rename = "immutable")]
+    Immutable,
+
+    /// `MutableStringDict` (lazily read dicts are updated by TS)
+    #[serde(rename = "mutable")]
+    Mutable,
+}
+
+impl std::str::FromStr for StringDicts {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "immutable" => Ok(StringDicts::Immutable),
+            "mutable" => Ok(StringDicts::Mutable),
+            _ => Err(format!("Unknown string dict binding \"{}\"", s)),
+        }
+    }
 }
 
 impl Default for GenConfig {
@@ -32,6 +170,57 @@ impl Default for GenConfig {
             output_constructor_wrappers: true,
             output_opaque_interfaces: true,
             wrap_top_level_vars: true,
+            default_export_name: DefaultExportName::Default,
+            optional_params: OptionalParams::Overloads,
+            overloads: Overloads::Suffixed,
+            string_dicts: StringDicts::Immutable,
+            lazy_dicts: false,
+        }
+    }
+}
+
+/// How the generated JS binds the root module
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RootBinding {
+    /// `require(require_path)`
+    Require,
+
+    /// A property of `globalThis` (i.e. a library loaded by a script tag)
+    ///   Defaults to the UMD global name of the root module (i.e. `export as namespace X`)
+    Global(Option<String>),
+}
+
+impl std::str::FromStr for RootBinding {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "require" => Ok(RootBinding::Require),
+            "global" => Ok(RootBinding::Global(None)),
+            _ => match s.strip_prefix("global:") {
+                Some(name) if !name.is_empty() => Ok(RootBinding::Global(Some(name.to_string()))),
+                _ => Err(format!("Unknown root binding \"{}\"", s)),
+            },
+        }
+    }
+}
+
+/// Global declarations bound instead of the exports of the root module
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GlobalSelection {
+    /// Every global declaration (i.e. of scripts and global augmentations)
+    All,
+
+    /// Global declarations of the given names
+    Only(Vec<String>),
+}
+
+impl GlobalSelection {
+    pub fn is_selected(&self, name: &str) -> bool {
+        match self {
+            GlobalSelection::All => true,
+
+            GlobalSelection::Only(ref names) => names.iter().any(|n| n == name),
         }
     }
 }
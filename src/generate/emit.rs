@@ -1,37 +1,206 @@
 #[macro_use]
 mod macros;
 
-//mod ts_full_js_emit;
-//mod ts_full_json_emit;
+mod ts_full_js_emit;
+mod ts_full_json_emit;
 
 mod ts_num_js_emit;
 mod ts_num_json_emit;
 
-use std::collections::HashSet;
 use std::fs::File;
 use std::io::{BufWriter, Write};
 use std::path::Path;
 
-use swc_ecma_ast::Module as AstModule;
+use swc_atoms::JsWord;
 
 use crate::compile_opt::CompileOpt;
+use crate::generate::bind_common;
+use crate::generate::bind_graph_init::Export;
+use crate::generate::config::{
+    DefaultExportName, GlobalSelection, OptionalParams, Overloads, RootBinding,
+};
 use crate::generate::error::EmitError;
-use crate::generate::js_pp::PrettyPrinter;
 use crate::generate::structures::*;
-use crate::generate::type_structs::Type;
-use crate::generate::typify_graph::ModuleGraph;
+use crate::generate::type_construction;
+use crate::generate::type_structs::{FnType, MemberKind, Type};
+use crate::generate::typify_graph::{ModuleGraph, ModuleNode};
+
+/// Pyret name suffix of construct signatures (see `ItemPath::construct()`)
+///   Also the field of construct signatures in records of callable objects
+pub(super) const CONSTRUCT_SUFFIX: &str = "construct";
+
+/// Field of call signatures in records of callable objects (see `Type::Callable`)
+pub(super) const CALL_FIELD: &str = "call";
+
+/// Location of an exported item within the root module
+///   Items exported from namespaces are qualified by their namespaces
+#[derive(Debug, Clone)]
+pub struct ItemPath {
+    namespaces: Vec<JsWord>,
+    name: JsWord,
+
+    /// Pyret name of a default export (see `DefaultExportName`)
+    pyret_alias: Option<JsWord>,
+
+    /// The item is the value of its enclosing module (i.e. `export = foo`)
+    module_value: bool,
+
+    /// Suffix of the Pyret name (i.e. the arity of `foo-1`, see `OptionalParams` and `Overloads`)
+    pyret_suffix: Option<String>,
+
+    /// The item is bound as a function constructing the accessed value with `new`
+    ///   (i.e. construct signatures of `interface FooConstructor { new (x: number): Foo }`)
+    constructs: bool,
+
+    /// The item is an instance property bound as a function of the instance (see `ItemPath::getter()`)
+    instance_access: Option<InstanceAccess>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum InstanceAccess {
+    Get,
+    Set,
+}
+
+impl ItemPath {
+    /// Pyret name of the item (i.e. `Outer-Inner-name`)
+    pub fn pyret_name(&self) -> String {
+        let mut pyret_name = String::new();
+        for namespace in self.namespaces.iter() {
+            pyret_name.push_str(namespace);
+            pyret_name.push('-');
+        }
+        pyret_name.push_str(self.pyret_alias.as_ref().unwrap_or(&self.name));
+        if let Some(ref suffix) = self.pyret_suffix {
+            pyret_name.push('-');
+            pyret_name.push_str(suffix);
+        }
+
+        pyret_name
+    }
+
+    /// Name of the item in its enclosing module (i.e. its export key)
+    pub fn name(&self) -> &JsWord {
+        &self.name
+    }
+
+    /// The same item under a suffixed Pyret name
+    ///   Suffixes of suffixed items are appended (i.e. `foo-2-1`)
+    pub fn with_pyret_suffix(&self, suffix: String) -> ItemPath {
+        let suffix = match self.pyret_suffix {
+            Some(ref previous) => format!("{}-{}", previous, suffix),
+            None => suffix,
+        };
+
+        ItemPath {
+            pyret_suffix: Some(suffix),
+            ..self.clone()
+        }
+    }
+
+    /// Member of the item (i.e. the static member `create` of the class `Foo` is `Foo-create`)
+    ///   Members of module values are members of their enclosing module
+    pub fn member(&self, key: &JsWord) -> ItemPath {
+        let mut namespaces = self.namespaces.clone();
+        if !self.module_value {
+            namespaces.push(self.name.clone());
+        }
+
+        ItemPath {
+            namespaces,
+            name: key.clone(),
+            pyret_alias: None,
+            module_value: false,
+            pyret_suffix: None,
+            constructs: false,
+            instance_access: None,
+        }
+    }
+
+    /// Function reading an instance property of the item (i.e. `Foo-get-x(foo)` for `x` of `class Foo`)
+    pub fn getter(&self, key: &JsWord) -> ItemPath {
+        ItemPath {
+            pyret_alias: Some(format!("get-{}", key).into()),
+            instance_access: Some(InstanceAccess::Get),
+            ..self.member(key)
+        }
+    }
+
+    /// Function writing an instance property of the item (i.e. `Foo-set-x(foo, x)` for `x` of `class Foo`)
+    pub fn setter(&self, key: &JsWord) -> ItemPath {
+        ItemPath {
+            pyret_alias: Some(format!("set-{}", key).into()),
+            instance_access: Some(InstanceAccess::Set),
+            ..self.member(key)
+        }
+    }
+
+    /// Construct signatures of the item (i.e. `Foo-construct`, see `ItemPath::constructs`)
+    pub fn construct(&self) -> ItemPath {
+        ItemPath {
+            constructs: true,
+            ..self.with_pyret_suffix(CONSTRUCT_SUFFIX.to_string())
+        }
+    }
+
+    /// The item is bound with `new` (see `ItemPath::constructs`)
+    pub fn constructs(&self) -> bool {
+        self.constructs
+    }
+
+    /// The item is the default export of the root module
+    ///   Under CommonJS interop, the root module itself may be the default export
+    pub fn is_root_default(&self) -> bool {
+        self.namespaces.is_empty()
+            && self.name == bind_common::default_export_key()
+            && !self.module_value
+    }
+
+    /// Name of the item (or its outermost namespace) in the root module
+    pub fn top_level_key(&self) -> &JsWord {
+        self.namespaces.first().unwrap_or(&self.name)
+    }
+
+    /// JS expression accessing the item from `root` (i.e. `root["Outer"]["Inner"]["name"]`)
+    ///   Module values are accessed as their enclosing module (i.e. `root["Outer"]["Inner"]`)
+    ///   Instance properties are accessed by functions of the instance (i.e. `function (self) { return self["x"]; }`)
+    pub fn js_access(&self, root: &str) -> String {
+        match self.instance_access {
+            Some(InstanceAccess::Get) => {
+                return format!("(function (self) {{ return self[\"{}\"]; }})", self.name);
+            }
+            Some(InstanceAccess::Set) => {
+                return format!("(function (self, value) {{ self[\"{}\"] = value; }})", self.name);
+            }
+            None => (),
+        }
+
+        let name = if self.module_value {
+            None
+        } else {
+            Some(&self.name)
+        };
+
+        let mut js_access = root.to_string();
+        for key in self.namespaces.iter().chain(name) {
+            js_access.push_str(&format!("[\"{}\"]", key));
+        }
+
+        js_access
+    }
+}
 
 pub trait JsonEmitter {
     fn export_type(
         &mut self,
         current_module: &Path,
-        name: &str,
+        item: &ItemPath,
         typ: &Type,
     ) -> Result<(), EmitError>;
     fn export_value(
         &mut self,
         current_module: &Path,
-        name: &str,
+        item: &ItemPath,
         value_type: &Type,
     ) -> Result<(), EmitError>;
     fn finalize(self, current_module: &Path) -> Result<String, EmitError>;
@@ -41,16 +210,16 @@ pub trait JsEmitter {
     fn handle_type(
         &mut self,
         current_module: &Path,
-        name: &str,
+        item: &ItemPath,
         typ: &Type,
     ) -> Result<(), EmitError>;
     fn handle_value(
         &mut self,
         current_module: &Path,
-        name: &str,
+        item: &ItemPath,
         value_type: &Type,
     ) -> Result<(), EmitError>;
-    fn finalize(self, current_module: &Path) -> Result<AstModule, EmitError>;
+    fn finalize(self, current_module: &Path) -> Result<String, EmitError>;
 }
 
 struct Context<JS: JsEmitter, JSON: JsonEmitter> {
@@ -66,7 +235,9 @@ pub fn ts_num_emit(
     use self::ts_num_js_emit::TsNumJsOutput as JsEmitter;
     use self::ts_num_json_emit::TsNumJsonOutput as JsonEmitter;
 
-    let js_emitter = JsEmitter::new(options);
+    let root_global = root_global(options, root_module_path, typed_graph)?;
+
+    let js_emitter = JsEmitter::new(options, root_global);
     let json_emitter = JsonEmitter::new(options);
 
     emit(
@@ -78,19 +249,54 @@ pub fn ts_num_emit(
     )
 }
 
+/// Property path from `globalThis` binding the root module (see `RootBinding`)
+///   `None` if the root module is required
+///   Globals are bound from `globalThis` itself
+fn root_global(
+    options: &CompileOpt,
+    root_module_path: &CanonPath,
+    typed_graph: &ModuleGraph,
+) -> Result<Option<Vec<String>>, EmitError> {
+    let property_path = |name: &str| name.split('.').map(|key| key.to_string()).collect();
+
+    if options.bind_globals.is_some() {
+        return Ok(Some(Vec::new()));
+    }
+
+    match options.root_binding {
+        RootBinding::Require => Ok(None),
+
+        RootBinding::Global(Some(ref name)) => Ok(Some(property_path(name))),
+
+        RootBinding::Global(None) => typed_graph
+            .nodes
+            .get(root_module_path)
+            .and_then(|node| node.umd_global.as_ref())
+            .map(|name| Some(property_path(name)))
+            .ok_or_else(|| EmitError::MissingUmdGlobal(root_module_path.as_path().to_owned())),
+    }
+}
+
 pub fn ts_full_emit(
     options: &CompileOpt,
     root_module_path: &CanonPath,
     typed_graph: &ModuleGraph,
 ) -> Result<(), EmitError> {
-    //use self::ts_full_js_emit::TsFullJsOutput as JsEmitter;
-    //use self::ts_full_json_emit::TsFullJsonOutput as JsonEmitter;
+    use self::ts_full_js_emit::TsFullJsOutput as JsEmitter;
+    use self::ts_full_json_emit::TsFullJsonOutput as JsonEmitter;
+
+    let root_global = root_global(options, root_module_path, typed_graph)?;
 
-    //let js_emitter = JsEmitter::new(options);
-    //let json_emitter = JsonEmitter::new(options);
+    let js_emitter = JsEmitter::new(options, typed_graph, root_global);
+    let json_emitter = JsonEmitter::new(options, typed_graph);
 
-    //emit(options, root_module_path, typed_graph, js_emitter, json_emitter)
-    todo!("TS-FULL")
+    emit(
+        options,
+        root_module_path,
+        typed_graph,
+        js_emitter,
+        json_emitter,
+    )
 }
 
 pub fn emit<JS: JsEmitter, JSON: JsonEmitter>(
@@ -105,7 +311,7 @@ pub fn emit<JS: JsEmitter, JSON: JsonEmitter>(
     let file_name = options
         .file_stem
         .as_ref()
-        .map(|f| std::ffi::OsStr::new(f))
+        .map(std::ffi::OsStr::new)
         .unwrap_or_else(|| {
             root_module_path
                 .as_path()
@@ -154,13 +360,11 @@ pub fn emit<JS: JsEmitter, JSON: JsonEmitter>(
         let file = File::create(&js_output_path)
             .map_err(|io_err| EmitError::IoError(root_path.to_owned(), io_err))?;
 
-        let file = BufWriter::new(file);
+        let mut file = BufWriter::new(file);
 
-        let ast_module = context.js_output.finalize(root_path)?;
+        let output = context.js_output.finalize(root_path)?;
 
-        // NOTE: Cannot use swc_ecma_codegen for whatever reason
-        //   Provided emitter appears to rely on SourceMap and Spans
-        let _ = PrettyPrinter::print(file, &ast_module)
+        file.write_all(output.as_bytes())
             .map_err(|io_err| EmitError::IoError(root_path.to_owned(), io_err))?;
     });
 
@@ -173,53 +377,497 @@ fn traverse<JS: JsEmitter, JSON: JsonEmitter>(
     graph: &ModuleGraph,
     context: &mut Context<JS, JSON>,
 ) -> Result<(), EmitError> {
-    let mut visited: HashSet<&CanonPath> = HashSet::new();
+    match options.bind_globals {
+        Some(ref selection) => traverse_globals(options, root, selection, graph, context),
+
+        None => traverse_module(options, root, &[], false, &mut Vec::new(), graph, context),
+    }
+}
+
+/// Emit the selected global declarations as if they were exported from the root module
+fn traverse_globals<JS: JsEmitter, JSON: JsonEmitter>(
+    options: &CompileOpt,
+    root: &CanonPath,
+    selection: &GlobalSelection,
+    graph: &ModuleGraph,
+    context: &mut Context<JS, JSON>,
+) -> Result<(), EmitError> {
+    if let GlobalSelection::Only(ref names) = selection {
+        let is_declared = |name: &JsWord| {
+            graph.globals.iter().any(|global| {
+                let node = graph.nodes.get(global).unwrap();
+                node.rooted_export_types.contains_key(name)
+                    || node.rooted_export_values.contains_key(name)
+                    || node.rooted_export_namespaces.contains(name)
+            })
+        };
+
+        for name in names.iter() {
+            if !is_declared(&JsWord::from(name.as_str())) {
+                return Err(EmitError::GlobalNotFound(
+                    root.as_path().to_owned(),
+                    name.clone(),
+                ));
+            }
+        }
+    }
+
+    for global in graph.globals.iter() {
+        traverse_module(options, global, &[], false, &mut Vec::new(), graph, context)?;
+    }
+
+    Ok(())
+}
+
+/// Emit everything exported from a module
+///   Relies on the reduced export edges pointing directly to the rooted items
+///
+/// Modules exported as namespaces may export themselves (directly or indirectly)
+///   Such namespaces are skipped (`visiting` are the modules being traversed)
+///
+/// The module value of a namespace is skipped if the enclosing module also exports it as a value
+///   (i.e. `import f = require("./m"); export { f }`, see `ItemPath::module_value`)
+fn traverse_module<JS: JsEmitter, JSON: JsonEmitter>(
+    options: &CompileOpt,
+    module_path: &CanonPath,
+    namespaces: &[JsWord],
+    module_value_bound: bool,
+    visiting: &mut Vec<CanonPath>,
+    graph: &ModuleGraph,
+    context: &mut Context<JS, JSON>,
+) -> Result<(), EmitError> {
+    if visiting.contains(module_path) {
+        return Ok(());
+    }
+    visiting.push(module_path.clone());
+
+    let node = graph.nodes.get(module_path).unwrap();
+
+    let item_path = |name: &JsWord, decl_ident: Option<&JsWord>| ItemPath {
+        namespaces: namespaces.to_vec(),
+        name: name.clone(),
+        pyret_alias: default_export_alias(options, name, decl_ident),
+        module_value: node.export_assignment && *name == bind_common::default_export_key(),
+        pyret_suffix: None,
+        constructs: false,
+        instance_access: None,
+    };
+
+    let nested = |name: &JsWord| {
+        let mut nested = namespaces.to_vec();
+        nested.push(name.clone());
+        nested
+    };
+
+    let edges = graph.export_edges.get(module_path).unwrap();
+
+    let exports_value = |export_key: &JsWord| {
+        node.rooted_export_values.contains_key(export_key)
+            || edges.iter().any(|edge| match edge {
+                Export::NamedValue {
+                    export_key: ref key,
+                    ..
+                } => key == export_key,
+
+                _ => false,
+            })
+    };
+
+    let default_ident = node.default_export_ident.as_ref();
+
+    for (export_key, typ) in node.rooted_export_types.iter() {
+        let item = item_path(export_key, default_ident);
+        emit_type(options, &node.path, &item, typ, context)?;
+    }
+
+    for (export_key, typ) in node.rooted_export_values.iter() {
+        let item = item_path(export_key, default_ident);
+        if !(module_value_bound && item.module_value) {
+            emit_value(options, &node.path, &item, typ, context)?;
+        }
+    }
+
+    let rooted_namespaces = node
+        .rooted_export_namespaces
+        .iter()
+        .chain(node.rooted_export_modules.keys());
+
+    for export_key in rooted_namespaces {
+        let namespace_path = node.namespace_module(export_key).unwrap();
+        traverse_module(
+            options,
+            &namespace_path,
+            &nested(export_key),
+            exports_value(export_key),
+            visiting,
+            graph,
+            context,
+        )?;
+    }
+
+    for edge in edges {
+        match edge {
+            Export::NamedType {
+                ref source,
+                ref src_key,
+                ref export_key,
+            } => {
+                let source_node = graph.nodes.get(source).unwrap();
+                let typ = source_node.rooted_export_types.get(src_key).unwrap();
+                let item = item_path(export_key, decl_ident(source_node, src_key));
+                emit_type(options, source, &item, typ, context)?;
+            }
+
+            Export::NamedValue {
+                ref source,
+                ref src_key,
+                ref export_key,
+            } => {
+                let source_node = graph.nodes.get(source).unwrap();
+                let typ = source_node.rooted_export_values.get(src_key).unwrap();
+                let item = item_path(export_key, decl_ident(source_node, src_key));
+                if !(module_value_bound && item.module_value) {
+                    emit_value(options, source, &item, typ, context)?;
+                }
+            }
+
+            Export::NamedNamespace {
+                ref source,
+                ref src_key,
+                ref export_key,
+            } => {
+                let namespace_path = graph
+                    .nodes
+                    .get(source)
+                    .unwrap()
+                    .namespace_module(src_key)
+                    .unwrap();
+                traverse_module(
+                    options,
+                    &namespace_path,
+                    &nested(export_key),
+                    exports_value(export_key),
+                    visiting,
+                    graph,
+                    context,
+                )?;
+            }
+
+            Export::Named { .. } | Export::All { .. } => {
+                unreachable!("Export edges should be reduced")
+            }
+        }
+    }
+
+    visiting.pop();
+
+    Ok(())
+}
 
-    let mut stack: Vec<&CanonPath> = vec![root];
+/// Identifier of the declaration rooted in `node` under `src_key`
+fn decl_ident<'a>(node: &'a ModuleNode, src_key: &'a JsWord) -> Option<&'a JsWord> {
+    if *src_key == bind_common::default_export_key() {
+        node.default_export_ident.as_ref()
+    } else {
+        Some(src_key)
+    }
+}
+
+fn default_export_alias(
+    options: &CompileOpt,
+    export_key: &JsWord,
+    decl_ident: Option<&JsWord>,
+) -> Option<JsWord> {
+    if *export_key != bind_common::default_export_key() {
+        return None;
+    }
+
+    match options.gen_config.default_export_name {
+        DefaultExportName::Default => None,
+
+        DefaultExportName::Declaration => decl_ident.cloned(),
+    }
+}
+
+/// Items not in the selection of bound globals are skipped (see `GlobalSelection`)
+fn is_selected(options: &CompileOpt, item: &ItemPath) -> bool {
+    options
+        .bind_globals
+        .as_ref()
+        .map(|selection| selection.is_selected(item.top_level_key()))
+        .unwrap_or(true)
+}
 
-    while stack.is_empty() == false {
-        let node_path = stack.pop().unwrap();
+fn emit_type<JS: JsEmitter, JSON: JsonEmitter>(
+    options: &CompileOpt,
+    source: &CanonPath,
+    item: &ItemPath,
+    typ: &Type,
+    context: &mut Context<JS, JSON>,
+) -> Result<(), EmitError> {
+    if !is_selected(options, item) {
+        return Ok(());
+    }
+
+    opt!(options.emit_config, json, {
+        context
+            .json_output
+            .export_type(source.as_path(), item, typ)?;
+    });
+    opt!(options.emit_config, js, {
+        context.js_output.handle_type(source.as_path(), item, typ)?;
+    });
 
-        if visited.contains(node_path) {
-            continue;
+    // Static members are values of the class itself
+    if let Type::Class(ref class_type) = typ {
+        if !class_type.is_abstract && options.gen_config.output_constructor_wrappers {
+            let constructors = match type_construction::class_value_type(class_type) {
+                Type::Callable(ref callable) => callable.construct_type(),
+                _ => None,
+            };
+
+            if let Some(construct_type) = constructors {
+                emit_value(options, source, &item.construct(), &construct_type, context)?;
+            }
         }
-        visited.insert(node_path);
 
-        let node = graph.nodes.get(node_path).unwrap();
+        for (key, member) in class_type.static_members.iter() {
+            emit_value(options, source, &item.member(key), &member.typ, context)?;
+        }
 
-        for (export_key, typ) in node.rooted_export_types.iter() {
-            opt!(options.emit_config, json, {
-                context
-                    .json_output
-                    .export_type(node.path.as_path(), export_key, typ)?;
-            });
-            opt!(options.emit_config, js, {
-                context
-                    .js_output
-                    .handle_type(node.path.as_path(), export_key, typ)?;
-            });
+        // Methods are methods of the datatype, other instance members are read (and written) by functions
+        let instance = type_construction::instance_type(class_type);
+        let mut members: Vec<_> = class_type.members.iter().collect();
+        members.sort_by_key(|&(key, _)| key);
+
+        for (key, member) in members {
+            let (readable, writable) = match member.kind {
+                MemberKind::Method => continue,
+                MemberKind::Property => (true, !member.readonly),
+                MemberKind::Accessor { getter, setter } => (getter, setter),
+            };
+
+            if readable {
+                let getter_type = FnType {
+                    params: vec![instance.clone()],
+                    optional_params: 0,
+                    rest_param: None,
+                    return_type: Box::new(member.typ.clone()),
+                };
+                let getter_type = Type::forall(class_type.type_params.clone(), Type::Fn(getter_type));
+                emit_value(options, source, &item.getter(key), &getter_type, context)?;
+            }
+
+            if writable {
+                let setter_type = FnType {
+                    params: vec![instance.clone(), member.typ.clone()],
+                    optional_params: 0,
+                    rest_param: None,
+                    return_type: Box::new(Type::Void),
+                };
+                let setter_type = Type::forall(class_type.type_params.clone(), Type::Fn(setter_type));
+                emit_value(options, source, &item.setter(key), &setter_type, context)?;
+            }
         }
+    }
+
+    Ok(())
+}
 
-        for (export_key, typ) in node.rooted_export_values.iter() {
-            opt!(options.emit_config, json, {
-                context
-                    .json_output
-                    .export_value(node.path.as_path(), export_key, typ)?;
-            });
+fn emit_value<JS: JsEmitter, JSON: JsonEmitter>(
+    options: &CompileOpt,
+    source: &CanonPath,
+    item: &ItemPath,
+    value_type: &Type,
+    context: &mut Context<JS, JSON>,
+) -> Result<(), EmitError> {
+    if !is_selected(options, item) {
+        return Ok(());
+    }
 
-            opt!(options.emit_config, js, {
-                context
-                    .js_output
-                    .handle_value(node.path.as_path(), export_key, typ)?;
-            });
+    // The object is bound as its call signatures
+    //   Construct signatures and fields are bound as members (i.e. `Foo-construct` and `Foo-x`)
+    if let Type::Callable(ref callable) = value_type {
+        if let Some(call_type) = callable.call_type() {
+            emit_value(options, source, item, &call_type, context)?;
         }
 
-        let edges = graph.export_edges.get(node_path).unwrap();
+        if let Some(construct_type) = callable.construct_type() {
+            emit_value(options, source, &item.construct(), &construct_type, context)?;
+        }
 
-        for edge in edges {
-            stack.push(edge.export_source());
+        for (key, field_type) in callable.fields.iter() {
+            emit_value(options, source, &item.member(key), field_type, context)?;
         }
+
+        return Ok(());
+    }
+
+    let bindings = bind_overloads(options, item, value_type)
+        .into_iter()
+        .flat_map(|(item, value_type)| bind_optional_params(options, &item, &value_type));
+
+    for (item, value_type) in bindings {
+        opt!(options.emit_config, json, {
+            context
+                .json_output
+                .export_value(source.as_path(), &item, &value_type)?;
+        });
+
+        opt!(options.emit_config, js, {
+            context
+                .js_output
+                .handle_value(source.as_path(), &item, &value_type)?;
+        });
     }
 
     Ok(())
 }
+
+/// Type parameters and function type of a (possibly generic) function type
+fn fn_signature(typ: &Type) -> Option<(&[JsWord], &FnType)> {
+    match typ {
+        Type::Fn(ref fn_type) => Some((&[], fn_type)),
+
+        Type::Forall {
+            ref type_params,
+            ref body,
+        } => match **body {
+            Type::Fn(ref fn_type) => Some((type_params, fn_type)),
+            _ => None,
+        },
+
+        _ => None,
+    }
+}
+
+/// Signatures of a function per number of optional parameters passed (from none to all)
+///   Only the signature passing every optional parameter keeps the rest parameter
+fn arity_signatures(fn_type: &FnType) -> Vec<FnType> {
+    (fn_type.required_params()..=fn_type.params.len())
+        .map(|arity| FnType {
+            params: fn_type.params[..arity].to_vec(),
+            optional_params: 0,
+            rest_param: if arity == fn_type.params.len() {
+                fn_type.rest_param.clone()
+            } else {
+                None
+            },
+            return_type: fn_type.return_type.clone(),
+        })
+        .collect()
+}
+
+/// Bindings of an overloaded function (see `Overloads`)
+fn bind_overloads(options: &CompileOpt, item: &ItemPath, value_type: &Type) -> Vec<(ItemPath, Type)> {
+    let signatures = match value_type {
+        Type::Overloaded(ref signatures) => signatures,
+
+        _ => return vec![(item.clone(), value_type.clone())],
+    };
+
+    if let Overloads::Dispatch = options.gen_config.overloads {
+        if let Some(dispatch) = dispatch_by_arity(signatures) {
+            return vec![(item.clone(), dispatch)];
+        }
+    }
+
+    signatures
+        .iter()
+        .enumerate()
+        .map(|(index, signature)| {
+            let suffix = (index + 1).to_string();
+            (item.with_pyret_suffix(suffix), signature.clone())
+        })
+        .collect()
+}
+
+/// Signatures of an overloaded function ordered by the number of arguments they take from Pyret
+///   Signatures with optional parameters are split per arity (see `arity_signatures()`)
+///   Rest arguments are passed as a single Pyret list
+///
+/// None if several signatures take the same number of arguments
+fn dispatch_by_arity(signatures: &[Type]) -> Option<Type> {
+    let mut dispatch: Vec<(&[JsWord], FnType)> = Vec::new();
+    for signature in signatures.iter() {
+        let (type_params, fn_type) = fn_signature(signature)?;
+        for arity_type in arity_signatures(fn_type) {
+            dispatch.push((type_params, arity_type));
+        }
+    }
+
+    dispatch.sort_by_key(|(_, fn_type)| fn_type.pyret_arity());
+
+    let overlaps = dispatch
+        .windows(2)
+        .any(|pair| pair[0].1.pyret_arity() == pair[1].1.pyret_arity());
+
+    if overlaps {
+        return None;
+    }
+
+    let dispatch = dispatch
+        .into_iter()
+        .map(|(type_params, fn_type)| Type::forall(type_params.to_vec(), Type::Fn(fn_type)))
+        .collect();
+
+    Some(Type::Overloaded(dispatch))
+}
+
+/// Bindings of an exported function with optional parameters (see `OptionalParams`)
+///   The bound function types have no optional parameters
+fn bind_optional_params(
+    options: &CompileOpt,
+    item: &ItemPath,
+    value_type: &Type,
+) -> Vec<(ItemPath, Type)> {
+    let (type_params, fn_type) = match fn_signature(value_type) {
+        Some(signature) => signature,
+        None => return vec![(item.clone(), value_type.clone())],
+    };
+
+    if fn_type.optional_params == 0 {
+        return vec![(item.clone(), value_type.clone())];
+    }
+
+    let required_params = fn_type.required_params();
+    let bind = |fn_type: FnType| Type::forall(type_params.to_vec(), Type::Fn(fn_type));
+
+    match options.gen_config.optional_params {
+        // The signature passing every optional parameter keeps the name of the function
+        OptionalParams::Overloads => arity_signatures(fn_type)
+            .into_iter()
+            .map(|arity_type| {
+                let arity = arity_type.params.len();
+                if arity == fn_type.params.len() {
+                    (item.clone(), bind(arity_type))
+                } else {
+                    (item.with_pyret_suffix(arity.to_string()), bind(arity_type))
+                }
+            })
+            .collect(),
+
+        OptionalParams::Option => {
+            let params = fn_type
+                .params
+                .iter()
+                .enumerate()
+                .map(|(index, param)| {
+                    if index < required_params {
+                        param.clone()
+                    } else {
+                        Type::Optional(Box::new(param.clone()))
+                    }
+                })
+                .collect();
+
+            let option_type = FnType {
+                params,
+                optional_params: 0,
+                ..fn_type.clone()
+            };
+
+            vec![(item.clone(), bind(option_type))]
+        }
+    }
+}
@@ -73,6 +73,10 @@ macro_rules! stmt {
         })
     };
 
+    (throw $v: expr) => {
+        Stmt::Throw(ThrowStmt { span: DUMMY_SP, arg: Box::new($v) })
+    };
+
     (if $test: expr => $branch: expr) => {
         Stmt::If(IfStmt {
             span: DUMMY_SP,
@@ -121,6 +125,32 @@ macro_rules! expr {
         }))
     };
 
+    (Num $n: expr) => {
+        Expr::Lit(Lit::Num(Number {
+            span: DUMMY_SP,
+            value: $n,
+        }))
+    };
+
+    (Paren $expr: expr) => {
+        Expr::Paren(ParenExpr {
+            span: DUMMY_SP,
+            expr: Box::new($expr),
+        })
+    };
+
+    (New $class: expr => $($arg: expr),*) => {
+        Expr::New(NewExpr {
+            span: DUMMY_SP,
+            callee: Box::new($class),
+            args: Some(vec![$(ExprOrSpread {
+                spread: None,
+                expr: Box::new($arg)
+            }),*]),
+            type_args: None,
+        })
+    };
+
     (Fn $fn: expr) => {
         Expr::Fn(FnExpr {
             ident: None,
@@ -174,6 +204,24 @@ macro_rules! expr {
         })
     };
 
+    (+ $lhs: expr, $rhs: expr) => {
+        Expr::Bin(BinExpr {
+            span: DUMMY_SP,
+            op: BinaryOp::Add,
+            left: Box::new($lhs),
+            right: Box::new($rhs),
+        })
+    };
+
+    (? $test: expr => $cons: expr, $alt: expr) => {
+        Expr::Cond(CondExpr {
+            span: DUMMY_SP,
+            test: Box::new($test),
+            cons: Box::new($cons),
+            alt: Box::new($alt),
+        })
+    };
+
     (Assign $assign: ident = $value: expr) => {
         Expr::Assign(AssignExpr {
             span: DUMMY_SP,
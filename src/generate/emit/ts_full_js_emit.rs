@@ -1,107 +1,971 @@
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 
 use indexmap::IndexMap;
+use swc_atoms::JsWord;
 
-use crate::generate::type_structs::*;
-use crate::generate::error::EmitError;
-use crate::generate::emit_common;
 use crate::compile_opt::CompileOpt;
+use crate::generate::error::EmitError;
+use crate::generate::structures::CanonPath;
+use crate::generate::type_structs::*;
+use crate::generate::typify_graph::ModuleGraph;
+use crate::generate::StringDicts;
+
+use super::{fn_signature, ItemPath, JsEmitter, CALL_FIELD, CONSTRUCT_SUFFIX};
+
+/// Pyret data values are objects with the variant name under `$name`
+///   and the fields as properties
+const PY_VARIANT_NAME: &str = "$name";
+
+/// Pyret tuples are objects with the elements under `vals`
+const PY_TUPLE_VALS: &str = "vals";
+
+/// Compiled Pyret `string-dict` module (required by the dictionary conversions)
+const PY_STRING_DICT_MODULE: &str = "./string-dict.arr.js";
 
-use super::JsEmitter;
+const C_PY_NUMBER_TS_NUMBER: &str = "C_py_number_ts_number";
+const C_PY_LIST_TS_ARRAY: &str = "C_py_list_ts_array";
+const ROOT_DEFAULT: &str = "root_default";
 
+macro_rules! root_value {
+    ($i: expr) => {
+        if $i.is_root_default() {
+            ROOT_DEFAULT.to_string()
+        } else {
+            $i.js_access("root")
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Conversion {
+    TsToPy,
+    PyToTs,
+}
+
+impl Conversion {
+    /// Arguments are converted in the opposite direction of their function
+    fn opposite(self) -> Conversion {
+        match self {
+            Conversion::TsToPy => Conversion::PyToTs,
+            Conversion::PyToTs => Conversion::TsToPy,
+        }
+    }
+}
+
+/// Values are converted by JS expressions built from their types (see `convert()`)
+///   Values of named types are converted by functions shared by every reference to the type
+///   (which also converts values of recursive types)
+///
+/// Values which need no conversion are passed through
 pub(super) struct TsFullJsOutput<'a> {
     options: &'a CompileOpt<'a>,
-    overrides: IndexMap<String, String>
+    graph: &'a ModuleGraph,
+    root_global: Option<Vec<String>>,
+    overrides: IndexMap<String, String>,
+    conversions: Vec<String>,
+
+    /// Identifiers of the conversion functions of named types (`None` if values are passed through)
+    named_conversions: HashMap<(CanonPath, JsWord), Option<String>>,
+
+    /// Named types whose conversion functions are being built (see `convert_named()`)
+    converting: Vec<(CanonPath, JsWord)>,
+
+    /// Named types referenced while their conversion functions were being built
+    recursive: HashSet<(CanonPath, JsWord)>,
+
+    /// Instantiations of generic types being converted (recursive instantiations are passed through)
+    instantiating: Vec<(CanonPath, JsWord)>,
+
+    anon_counter: u64,
+    uses_root_default: bool,
+    uses_string_dicts: bool,
 }
 
 impl<'a> TsFullJsOutput<'a> {
-    pub fn new(options: &'a CompileOpt<'a>) -> Self {
+    /// `root_global` is the property path from `globalThis` binding the root module
+    ///   (if it is not required)
+    pub fn new(
+        options: &'a CompileOpt<'a>,
+        graph: &'a ModuleGraph,
+        root_global: Option<Vec<String>>,
+    ) -> Self {
         TsFullJsOutput {
             options,
+            graph,
+            root_global,
             overrides: IndexMap::new(),
+            conversions: Vec::new(),
+            named_conversions: HashMap::new(),
+            converting: Vec::new(),
+            recursive: HashSet::new(),
+            instantiating: Vec::new(),
+            anon_counter: 0,
+            uses_root_default: false,
+            uses_string_dicts: false,
         }
     }
 
-    fn build_constructor(&self,
-        class_name: &str,
-        constructor_name: &str,
-        constructor: &FnType
+    fn anon_inc(&mut self) -> u64 {
+        self.anon_counter += 1;
+        self.anon_counter
+    }
+
+    fn tmp_binding(&mut self) -> String {
+        format!("___{}", self.anon_inc())
+    }
+
+    fn prelude(&self, output: &mut String) {
+        // const root = require(require_path);
+        //   OR
+        // const root = globalThis["Outer"]["Inner"];
+        let root_import = match self.root_global {
+            Some(ref root_global) => {
+                let mut global = "globalThis".to_string();
+                for key in root_global.iter() {
+                    global.push_str(&format!("[{:?}]", key));
+                }
+
+                global
+            }
+
+            None => format!("require({:?})", self.options.require_path),
+        };
+        output.push_str(&format!("const root = {};\n", root_import));
+
+        // Bound globals are exported individually (not all of `globalThis`)
+        if self.options.bind_globals.is_none() {
+            output.push_str("module.exports = Object.assign({}, root);\n");
+        }
+
+        // CommonJS modules (without `__esModule`) are their own default export
+        if self.uses_root_default {
+            output.push_str(&format!(
+                "const {} = root[\"__esModule\"] ? root[\"default\"] : root;\n",
+                ROOT_DEFAULT
+            ));
+        }
+        output.push('\n');
+
+        // Need to use `toFixnum` for non-fixnum Pyret numbers
+        output.push_str(&format!(
+            "function {}(py_num) {{\nreturn typeof py_num === \"number\" ? py_num : py_num.toFixnum();\n}}\n\n",
+            C_PY_NUMBER_TS_NUMBER
+        ));
+
+        // Raw arrays are passed through
+        //   Pyret lists are `link` values (with `first` and `rest` fields) ending in `empty`
+        output.push_str(&format!(
+            "function {0}(py_list) {{\nif (Array.isArray(py_list)) {{ return py_list; }}\nvar array = [];\nfor (var l = py_list; l[{1:?}] === \"link\"; l = l[\"rest\"]) {{ array.push(l[\"first\"]); }}\nreturn array;\n}}\n\n",
+            C_PY_LIST_TS_ARRAY,
+            PY_VARIANT_NAME
+        ));
+
+        if self.uses_string_dicts {
+            output.push_str(&dict_conversions(
+                self.options.gen_config.string_dicts,
+                self.options.gen_config.lazy_dicts,
+            ));
+            output.push('\n');
+        }
+    }
+
+    /// Declaration of a named type (see `ModuleNode::declared_types`)
+    fn resolve_named(&self, name: &JsWord, source: &CanonPath) -> Option<&'a Type> {
+        self.graph
+            .nodes
+            .get(source)
+            .and_then(|node| node.declared_types.get(name))
+    }
+
+    /// JS expression converting `value` of type `typ`
+    ///   Classes, opaque interfaces and anonymous unions are passed through
+    fn convert(&mut self, conversion: Conversion, typ: &Type, value: &str) -> String {
+        match (conversion, typ) {
+            (Conversion::PyToTs, Type::Number) => format!("{}({})", C_PY_NUMBER_TS_NUMBER, value),
+
+            (conversion, Type::Fn(ref fn_type)) => self.convert_fn(conversion, fn_type, value, false),
+
+            (conversion, Type::Forall { ref body, .. }) => self.convert(conversion, body, value),
+
+            (conversion, Type::Named { ref name, ref source }) => {
+                self.convert_named(conversion, name, source, value)
+            }
+
+            (conversion, Type::TyApp { ref base, ref args }) => match **base {
+                Type::Named { ref name, ref source } => {
+                    self.convert_instance(conversion, name, source, args, value)
+                }
+
+                _ => value.to_string(),
+            },
+
+            (conversion, Type::Enum(ref enum_type)) => {
+                self.convert_named(conversion, &enum_type.name, &enum_type.origin, value)
+            }
+
+            (conversion, Type::Interface { ref fields, .. })
+                if !self.options.gen_config.output_opaque_interfaces => {
+                self.convert_record(conversion, fields, value)
+            }
+
+            (conversion, Type::Literal { ref fields }) => self.convert_record(conversion, fields, value),
+
+            (conversion, Type::UnsizedArray(ref elem_type)) => {
+                let elem = self.tmp_binding();
+                let converted = self.convert(conversion, elem_type, &elem);
+                if converted == elem {
+                    return value.to_string();
+                }
+
+                format!("{}.map(function ({}) {{ return {}; }})", value, elem, converted)
+            }
+
+            (conversion, Type::Tuple(ref elem_types)) => {
+                let converter = self.convert_tuple(conversion, elem_types);
+                format!("{}({})", converter, value)
+            }
+
+            // `undefined` is `none`
+            (Conversion::TsToPy, Type::Optional(ref typ)) => {
+                let converted = self.convert(conversion, typ, value);
+                format!(
+                    "({0} === undefined ? {1} : {2})",
+                    value,
+                    py_variant("\"none\"", ""),
+                    py_variant("\"some\"", &format!("\"value\": {}", converted))
+                )
+            }
+
+            (Conversion::PyToTs, Type::Optional(ref typ)) => {
+                let converted = self.convert(conversion, typ, &format!("{}[\"value\"]", value));
+                format!(
+                    "({0}[{1:?}] === \"some\" ? {2} : undefined)",
+                    value,
+                    PY_VARIANT_NAME,
+                    converted
+                )
+            }
+
+            (conversion, Type::Callable(ref callable)) => {
+                let converter = self.convert_callable(conversion, callable);
+                format!("{}({})", converter, value)
+            }
+
+            (Conversion::TsToPy, Type::Dict(ref value_type)) => {
+                self.uses_string_dicts = true;
+                let converted = self.convert(conversion, value_type, "v");
+                format!("C_ts_dict_py_dict({}, function (v) {{ return {}; }})", value, converted)
+            }
+
+            (Conversion::PyToTs, Type::Dict(ref value_type)) => {
+                self.uses_string_dicts = true;
+                let converted = self.convert(conversion, value_type, "v");
+                let converted_back = self.convert(Conversion::TsToPy, value_type, "v");
+                format!(
+                    "C_py_dict_ts_dict({}, function (v) {{ return {}; }}, function (v) {{ return {}; }})",
+                    value,
+                    converted,
+                    converted_back
+                )
+            }
+
+            _ => value.to_string(),
+        }
+    }
+
+    /// Function calling (or constructing with `new`) `value` with converted arguments
+    ///   Arguments are converted in the opposite direction of the result
+    ///
+    /// Rest arguments are converted element by element
+    ///   From Pyret: a Pyret list or raw array spread into the call
+    ///   From TS: a raw array of the rest arguments
+    fn convert_fn(&mut self, conversion: Conversion, fn_type: &FnType, value: &str, construct: bool)
+        -> String {
+
+        let mut params: Vec<String> = Vec::new();
+        let mut args: Vec<String> = Vec::new();
+
+        for param_type in fn_type.params.iter() {
+            let param = self.tmp_binding();
+            args.push(self.convert(conversion.opposite(), param_type, &param));
+            params.push(param);
+        }
+
+        if let Some(ref elem_type) = fn_type.rest_param {
+            let rest = self.tmp_binding();
+            let elem = self.tmp_binding();
+            let converted_elem = self.convert(conversion.opposite(), elem_type, &elem);
+
+            let (rest_param, rest_array) = match conversion {
+                Conversion::TsToPy => (rest.clone(), format!("{}({})", C_PY_LIST_TS_ARRAY, rest)),
+                Conversion::PyToTs => (format!("...{}", rest), rest.clone()),
+            };
+
+            let converted = if converted_elem == elem {
+                rest_array
+            } else {
+                format!("{}.map(function ({}) {{ return {}; }})", rest_array, elem, converted_elem)
+            };
+
+            params.push(rest_param);
+            args.push(match conversion {
+                Conversion::TsToPy => format!("...{}", converted),
+                Conversion::PyToTs => converted,
+            });
+        }
+
+        let call = if construct {
+            format!("Reflect.construct({}, [{}])", value, args.join(", "))
+        } else {
+            format!("{}({})", value, args.join(", "))
+        };
+
+        let result = self.tmp_binding();
+        let converted_result = self.convert(conversion, &fn_type.return_type, &result);
+
+        format!(
+            "function ({}) {{ var {} = {}; return {}; }}",
+            params.join(", "),
+            result,
+            call,
+            converted_result
+        )
+    }
+
+    /// Function dispatching on the number of arguments to the wrapper of a signature
+    ///   Assumes the signatures take distinct numbers of arguments (see `FnType::pyret_arity()`)
+    fn convert_overloaded(&mut self, name: &str, signatures: &[&FnType], value: &str, construct: bool)
+        -> String {
+
+        let args = self.tmp_binding();
+        let mut wrappers = String::new();
+        let mut dispatch = String::new();
+
+        for fn_type in signatures.iter() {
+            let wrapper = self.tmp_binding();
+            let converter = self.convert_fn(Conversion::TsToPy, fn_type, value, construct);
+            wrappers.push_str(&format!("var {} = {};\n", wrapper, converter));
+            dispatch.push_str(&format!(
+                "if ({}.length === {}) {{ return {}(...{}); }}\n",
+                args,
+                fn_type.pyret_arity(),
+                wrapper,
+                args
+            ));
+        }
+
+        format!(
+            "(function () {{\n{0}return function (...{1}) {{\n{2}throw new Error({3:?} + {1}.length + \" arguments\");\n}};\n}})()",
+            wrappers,
+            args,
+            dispatch,
+            format!("{}: no signature takes ", name)
+        )
+    }
+
+    /// Values of named types are converted by the conversion functions of the type
+    ///   (`C_ts_Foo_py_Foo()` and `C_py_Foo_ts_Foo()`)
+    fn convert_named(&mut self, conversion: Conversion, name: &JsWord, source: &CanonPath, value: &str)
+        -> String {
+
+        let key = (source.clone(), name.clone());
+        if self.converting.contains(&key) {
+            self.recursive.insert(key.clone());
+        }
+
+        let id = match self.named_conversions.get(&key) {
+            Some(id) => id.clone(),
+            None => self.define_named_conversions(name, source),
+        };
+
+        match (id, conversion) {
+            (Some(id), Conversion::TsToPy) => format!("C_ts_{0}_py_{0}({1})", id, value),
+            (Some(id), Conversion::PyToTs) => format!("C_py_{0}_ts_{0}({1})", id, value),
+            (None, _) => value.to_string(),
+        }
+    }
+
+    /// Conversion functions of a named type (`None` if values of the type are passed through)
+    fn define_named_conversions(&mut self, name: &JsWord, source: &CanonPath) -> Option<String> {
+        let key = (source.clone(), name.clone());
+        let declared = match self.resolve_named(name, source) {
+            Some(declared) => declared,
+            None => {
+                self.named_conversions.insert(key, None);
+                return None;
+            }
+        };
+
+        // Named types declared in several modules share their identifier
+        let taken: HashSet<&String> = self.named_conversions.values().flatten().collect();
+        let mut id = name.to_string();
+        let mut index = 1;
+        while taken.contains(&id) {
+            index += 1;
+            id = format!("{}{}", name, index);
+        }
+
+        // Recursive references call the conversion functions being defined
+        self.named_conversions.insert(key.clone(), Some(id.clone()));
+        self.converting.push(key.clone());
+
+        let (ts_to_py, py_to_ts) = match declared {
+            Type::DataUnion(ref data_union) => self.build_data_union_dispatch(&id, data_union),
+
+            Type::LitUnion(ref lit_values) => self.build_lit_union_dispatch(&id, lit_values),
+
+            Type::Enum(ref enum_type) => self.build_enum_dispatch(&id, enum_type),
+
+            declared => {
+                let ts_to_py = self.convert(Conversion::TsToPy, declared, "ts_value");
+                let py_to_ts = self.convert(Conversion::PyToTs, declared, "py_value");
+
+                self.converting.pop();
+                if ts_to_py == "ts_value" && py_to_ts == "py_value" && !self.recursive.contains(&key) {
+                    self.named_conversions.insert(key, None);
+                    return None;
+                }
+                self.converting.push(key.clone());
+
+                (
+                    format!("function C_ts_{0}_py_{0}(ts_value) {{\nreturn {1};\n}}\n", id, ts_to_py),
+                    format!("function C_py_{0}_ts_{0}(py_value) {{\nreturn {1};\n}}\n", id, py_to_ts),
+                )
+            }
+        };
+
+        self.converting.pop();
+        self.conversions.push(ts_to_py);
+        self.conversions.push(py_to_ts);
+
+        Some(id)
+    }
+
+    /// Values of instantiations of generic types are converted as the instantiated declaration
+    fn convert_instance(
+        &mut self,
+        conversion: Conversion,
+        name: &JsWord,
+        source: &CanonPath,
+        args: &[Type],
+        value: &str,
     ) -> String {
+        let key = (source.clone(), name.clone());
+        let declared = match self.resolve_named(name, source) {
+            Some(declared) => declared,
+            None => return value.to_string(),
+        };
+
+        if self.instantiating.contains(&key) {
+            return value.to_string();
+        }
+
+        let instance = declared.instantiate(args);
+
+        self.instantiating.push(key);
+        let converted = self.convert(conversion, &instance, value);
+        self.instantiating.pop();
+
+        converted
+    }
+
+    /// Records are copied field by field (if any field is converted)
+    ///   Missing optional fields are `none`
+    fn convert_record(&mut self, conversion: Conversion, fields: &HashMap<JsWord, Type>, value: &str)
+        -> String {
+
+        let param = match conversion {
+            Conversion::TsToPy => "ts_value",
+            Conversion::PyToTs => "py_value",
+        };
+
+        let mut converted_fields: Vec<String> = Vec::new();
+        let mut converts = false;
+        for (field, typ) in fields.iter() {
+            let field_value = format!("{}[{:?}]", param, &**field);
+            let converted = self.convert(conversion, typ, &field_value);
+            converts |= converted != field_value;
+
+            converted_fields.push(format!("{:?}: {}", &**field, converted));
+        }
+
+        if !converts {
+            return value.to_string();
+        }
+
+        format!(
+            "(function ({}) {{ return {{ {} }}; }})({})",
+            param,
+            converted_fields.join(", "),
+            value
+        )
+    }
+
+    /// Conversions between the members of a data union and the Pyret variants
+    ///   dispatching on the discriminant (TS to Pyret) or the variant name (Pyret to TS)
+    ///
+    fn build_data_union_dispatch(&mut self, type_name: &str, data_union: &DataUnion) -> (String, String) {
+        let tag = format!("{:?}", &*data_union.tag);
+
+        let mut ts_to_py_cases = String::new();
+        let mut py_to_ts_cases = String::new();
+        let variant_names = data_union.variant_names();
+        for (variant, variant_name) in data_union.variants.iter().zip(variant_names) {
+            let variant_name = format!("{:?}", variant_name);
+            let tag_value = variant.tag_value.to_js();
+
+            let mut py_fields: Vec<String> = Vec::new();
+            let mut ts_fields: Vec<String> = vec![format!("{}: {}", tag, tag_value)];
+            for (field, typ) in variant.fields.iter() {
+                let field_value = format!("ts_value[{:?}]", &**field);
+                let converted = self.convert(Conversion::TsToPy, typ, &field_value);
+                py_fields.push(format!("{:?}: {}", &**field, converted));
+
+                let field_value = format!("py_value[{:?}]", &**field);
+                let converted = self.convert(Conversion::PyToTs, typ, &field_value);
+                ts_fields.push(format!("{:?}: {}", &**field, converted));
+            }
+
+            ts_to_py_cases.push_str(&format!(
+                "case {}: return {};\n",
+                tag_value,
+                py_variant(&variant_name, &py_fields.join(", "))
+            ));
+
+            py_to_ts_cases.push_str(&format!(
+                "case {}: return {{ {} }};\n",
+                variant_name,
+                ts_fields.join(", ")
+            ));
+        }
+
+        let ts_to_py = dispatch_fn(
+            &format!("C_ts_{0}_py_{0}", type_name),
+            "ts_value",
+            &format!("ts_value[{}]", tag),
+            &ts_to_py_cases,
+            &format!("{}: unknown discriminant", type_name),
+        );
+
+        let py_to_ts = dispatch_fn(
+            &format!("C_py_{0}_ts_{0}", type_name),
+            "py_value",
+            &format!("py_value[{:?}]", PY_VARIANT_NAME),
+            &py_to_ts_cases,
+            &format!("{}: unknown variant", type_name),
+        );
+
+        (ts_to_py, py_to_ts)
+    }
+
+    /// Conversions between the literals of an enumeration and the singleton Pyret variants
+    fn build_lit_union_dispatch(&self, type_name: &str, lit_values: &[LitValue]) -> (String, String) {
+        let variants: Vec<(String, &LitValue)> = lit_variant_names(lit_values.iter())
+            .into_iter()
+            .zip(lit_values.iter())
+            .collect();
+
+        self.build_singleton_dispatch(type_name, &variants)
+    }
+
+    /// Conversions between the member values of an enum and the singleton Pyret variants
+    ///   Members sharing a value are converted to the first of them
+    fn build_enum_dispatch(&self, type_name: &str, enum_type: &EnumType) -> (String, String) {
+        let variants: Vec<(String, &LitValue)> = enum_type.variant_names()
+            .into_iter()
+            .zip(enum_type.members.iter().map(|member| &member.value))
+            .collect();
+
+        self.build_singleton_dispatch(type_name, &variants)
+    }
+
+    /// Conversions between JS literals and singleton Pyret variants (`(variant name, literal)`)
+    fn build_singleton_dispatch(&self, type_name: &str, variants: &[(String, &LitValue)])
+        -> (String, String) {
+
+        let mut ts_to_py_cases = String::new();
+        let mut py_to_ts_cases = String::new();
+        let mut converted: Vec<&LitValue> = Vec::new();
+        for (variant_name, lit_value) in variants.iter() {
+            let variant_name = format!("{:?}", variant_name);
+            let lit = lit_value.to_js();
+
+            if !converted.contains(lit_value) {
+                converted.push(lit_value);
+                ts_to_py_cases.push_str(&format!(
+                    "case {}: return {};\n",
+                    lit,
+                    py_variant(&variant_name, "")
+                ));
+            }
+
+            py_to_ts_cases.push_str(&format!("case {}: return {};\n", variant_name, lit));
+        }
+
+        let ts_to_py = dispatch_fn(
+            &format!("C_ts_{0}_py_{0}", type_name),
+            "ts_value",
+            "ts_value",
+            &ts_to_py_cases,
+            &format!("{}: unknown literal", type_name),
+        );
+
+        let py_to_ts = dispatch_fn(
+            &format!("C_py_{0}_ts_{0}", type_name),
+            "py_value",
+            &format!("py_value[{:?}]", PY_VARIANT_NAME),
+            &py_to_ts_cases,
+            &format!("{}: unknown variant", type_name),
+        );
+
+        (ts_to_py, py_to_ts)
+    }
+
+    /// Function converting a tuple element by element (throwing on a length mismatch)
+    ///   TS tuples may omit trailing optional elements (Pyret tuples always have every element)
+    fn convert_tuple(&mut self, conversion: Conversion, elem_types: &[Type]) -> String {
+        let min_length = match conversion {
+            Conversion::TsToPy => elem_types
+                .iter()
+                .rposition(|elem_type| !matches!(elem_type, Type::Optional(..)))
+                .map(|last_required| last_required + 1)
+                .unwrap_or(0),
+
+            Conversion::PyToTs => elem_types.len(),
+        };
+
+        let (param, elems) = match conversion {
+            Conversion::TsToPy => ("ts_tuple", "ts_tuple".to_string()),
+            Conversion::PyToTs => ("py_tuple", format!("py_tuple[{:?}]", PY_TUPLE_VALS)),
+        };
+
+        let converted: Vec<String> = elem_types
+            .iter()
+            .enumerate()
+            .map(|(index, elem_type)| {
+                self.convert(conversion, elem_type, &format!("{}[{}]", elems, index))
+            })
+            .collect();
+
+        let result = match conversion {
+            Conversion::TsToPy => format!("{{ {:?}: [{}] }}", PY_TUPLE_VALS, converted.join(", ")),
+            Conversion::PyToTs => format!("[{}]", converted.join(", ")),
+        };
+
+        let (length_check, expected_length) = if min_length == elem_types.len() {
+            (
+                format!("{}.length !== {}", elems, elem_types.len()),
+                elem_types.len().to_string(),
+            )
+        } else {
+            (
+                format!("{0}.length < {1} || {0}.length > {2}", elems, min_length, elem_types.len()),
+                format!("{} to {}", min_length, elem_types.len()),
+            )
+        };
 
-        let list = {
-            let mut params = String::new();
-            for (index, _) in constructor.params.iter().enumerate() {
-                params.push('p');
-                params.push_str(&index.to_string());
+        format!(
+            "(function ({0}) {{ if ({1}) {{ throw new Error(\"Expected a tuple of length {2}, got \" + {3}.length); }} return {4}; }})",
+            param,
+            length_check,
+            expected_length,
+            elems,
+            result
+        )
+    }
+
+    /// Function converting a callable object to a record of its signatures and fields (or back)
+    fn convert_callable(&mut self, conversion: Conversion, callable: &CallableType) -> String {
+        let has_call = !callable.call_signatures.is_empty();
+        let has_construct = !callable.construct_signatures.is_empty();
+
+        match conversion {
+            Conversion::TsToPy => {
+                let mut fields: Vec<String> = callable.fields
+                    .iter()
+                    .map(|(field, typ)| {
+                        let field_value = format!("ts_value[{:?}]", &**field);
+                        format!("{:?}: {}", &**field, self.convert(conversion, typ, &field_value))
+                    })
+                    .collect();
+
+                if has_call {
+                    let call = self.convert_signatures(conversion, &callable.call_signatures, "ts_value", false);
+                    fields.push(format!("{:?}: {}", CALL_FIELD, call));
+                }
 
-                if index < constructor.params.len() - 1 {
-                    params.push(',');
+                if has_construct {
+                    let construct =
+                        self.convert_signatures(conversion, &callable.construct_signatures, "ts_value", true);
+                    fields.push(format!("{:?}: {}", CONSTRUCT_SUFFIX, construct));
                 }
+
+                format!("(function (ts_value) {{ return {{ {} }}; }})", fields.join(", "))
+            }
+
+            // Calls with `new` use the construct signatures
+            Conversion::PyToTs => {
+                let mut signatures = Vec::new();
+                if has_call {
+                    let value = format!("py_value[{:?}]", CALL_FIELD);
+                    let call = self.convert_signatures(conversion, &callable.call_signatures, &value, false);
+                    signatures.push(format!("var call = {};", call));
+                }
+
+                if has_construct {
+                    let value = format!("py_value[{:?}]", CONSTRUCT_SUFFIX);
+                    let construct =
+                        self.convert_signatures(conversion, &callable.construct_signatures, &value, false);
+                    signatures.push(format!("var construct = {};", construct));
+                }
+
+                let body = match (has_call, has_construct) {
+                    (true, true) => "return new.target ? construct.apply(undefined, arguments) : call.apply(undefined, arguments);",
+                    (true, false) => "return call.apply(undefined, arguments);",
+                    (false, _) => "return construct.apply(undefined, arguments);",
+                };
+
+                let fields: Vec<String> = callable.fields
+                    .iter()
+                    .map(|(field, typ)| {
+                        let field_value = format!("py_value[{:?}]", &**field);
+                        format!("ts_value[{:?}] = {};", &**field, self.convert(conversion, typ, &field_value))
+                    })
+                    .collect();
+
+                format!(
+                    "(function (py_value) {{ {} var ts_value = function () {{ {} }}; {} return ts_value; }})",
+                    signatures.join(" "),
+                    body,
+                    fields.join(" ")
+                )
+            }
+        }
+    }
+
+    /// Function calling (or constructing with `new`) the value with the arguments and result converted
+    ///   Arguments and results of overloaded signatures are passed through
+    fn convert_signatures(&mut self, conversion: Conversion, signatures: &[Type], value: &str, construct: bool)
+        -> String {
+
+        let signature = match signatures {
+            [signature] => fn_signature(signature),
+            _ => None,
+        };
+
+        match signature {
+            Some((_, fn_type)) => self.convert_fn(conversion, fn_type, value, construct),
+            None if construct => {
+                format!("function () {{ return Reflect.construct({}, arguments); }}", value)
             }
+            None => format!("function () {{ return {}.apply(undefined, arguments); }}", value),
+        }
+    }
+}
+
+/// Conversions between JS objects and Pyret string dicts (`C_ts_dict_py_dict()` and `C_py_dict_ts_dict()`)
+///   Pyret dicts are always built eagerly. With `lazy`, JS objects are proxies reading through to the
+///   Pyret dict (writes are only allowed for mutable dicts)
+fn dict_conversions(string_dicts: StringDicts, lazy: bool) -> String {
+    let (make, set, get, has, keys) = match string_dicts {
+        StringDicts::Immutable => ("make-string-dict", "set", "get-value", "has-key", "keys-list"),
+        StringDicts::Mutable => (
+            "make-mutable-string-dict",
+            "set-now",
+            "get-value-now",
+            "has-key-now",
+            "keys-list-now",
+        ),
+    };
+
+    let ts_to_py = match string_dicts {
+        StringDicts::Immutable => format!(
+            "var py_dict = SD[{0:?}]();\nObject.keys(ts_dict).forEach(function (key) {{ py_dict = py_dict[{1:?}](key, convert(ts_dict[key])); }});\nreturn py_dict;",
+            make, set
+        ),
+        StringDicts::Mutable => format!(
+            "var py_dict = SD[{0:?}]();\nObject.keys(ts_dict).forEach(function (key) {{ py_dict[{1:?}](key, convert(ts_dict[key])); }});\nreturn py_dict;",
+            make, set
+        ),
+    };
+
+    let py_keys = format!(
+        "function py_dict_keys(py_dict) {{\nvar keys = [];\nfor (var l = py_dict[{0:?}](); l[{1:?}] === \"link\"; l = l[\"rest\"]) {{ keys.push(l[\"first\"]); }}\nreturn keys;\n}}\n",
+        keys, PY_VARIANT_NAME
+    );
 
-            params
+    let py_to_ts = if lazy {
+        let set_trap = match string_dicts {
+            StringDicts::Immutable => {
+                "set: function () { throw new Error(\"Cannot modify an immutable string dict\"); }".to_string()
+            }
+            StringDicts::Mutable => format!(
+                "set: function (target, key, value) {{ py_dict[{:?}](key, convert_back(value)); return true; }}",
+                set
+            ),
         };
 
-        let params = &list;
-        let body = format!("return new root.{}({});", class_name, list);
+        format!(
+            "return new Proxy({{}}, {{\nget: function (target, key) {{ return typeof key === \"string\" && py_dict[{0:?}](key) ? convert(py_dict[{1:?}](key)) : undefined; }},\nhas: function (target, key) {{ return typeof key === \"string\" && py_dict[{0:?}](key); }},\nownKeys: function () {{ return py_dict_keys(py_dict); }},\ngetOwnPropertyDescriptor: function (target, key) {{ return typeof key === \"string\" && py_dict[{0:?}](key) ? {{ value: convert(py_dict[{1:?}](key)), writable: {3}, enumerable: true, configurable: true }} : undefined; }},\n{2}\n}});",
+            has,
+            get,
+            set_trap,
+            match string_dicts {
+                StringDicts::Immutable => "false",
+                StringDicts::Mutable => "true",
+            }
+        )
+    } else {
+        format!(
+            "var ts_dict = {{}};\npy_dict_keys(py_dict).forEach(function (key) {{ ts_dict[key] = convert(py_dict[{:?}](key)); }});\nreturn ts_dict;",
+            get
+        )
+    };
+
+    format!(
+        "{0}function C_ts_dict_py_dict(ts_dict, convert) {{\nvar SD = require({1:?});\n{2}\n}}\n\nfunction C_py_dict_ts_dict(py_dict, convert, convert_back) {{\n{3}\n}}\n",
+        py_keys, PY_STRING_DICT_MODULE, ts_to_py, py_to_ts
+    )
+}
 
-        format!("function {}({}) {{ {} }}", constructor_name, params, body)
+/// `{ "$name": variant_name, fields }`
+fn py_variant(variant_name: &str, fields: &str) -> String {
+    if fields.is_empty() {
+        format!("{{ {:?}: {} }}", PY_VARIANT_NAME, variant_name)
+    } else {
+        format!("{{ {:?}: {}, {} }}", PY_VARIANT_NAME, variant_name, fields)
     }
 }
 
+/// Function switching on the scrutinee (throwing if no case matches)
+fn dispatch_fn(fn_name: &str, param: &str, scrutinee: &str, cases: &str, error: &str) -> String {
+    format!(
+        "function {0}({1}) {{\nswitch ({2}) {{\n{3}default: throw new Error({4:?} + \" \" + {2});\n}}\n}}\n",
+        fn_name,
+        param,
+        scrutinee,
+        cases,
+        error
+    )
+}
+
 impl<'a> JsEmitter for TsFullJsOutput<'a> {
-    fn handle_value(&mut self, current_module: &Path, name: &str, value_type: &Type)
+    fn handle_value(&mut self, current_module: &Path, item: &ItemPath, value_type: &Type)
         -> Result<(), EmitError> {
-        // Do nothing for now
+
+        let name = item.pyret_name();
+
+        if item.is_root_default() {
+            self.uses_root_default = true;
+        }
+
+        let value = root_value!(item);
+
+        let wrapper = match value_type {
+            // Signatures accepting distinct numbers of arguments (see `Overloads::Dispatch`)
+            Type::Overloaded(ref signatures) => {
+                let signatures = signatures
+                    .iter()
+                    .map(|signature| fn_signature(signature)
+                        .map(|(_, fn_type)| fn_type)
+                        .ok_or_else(|| EmitError::Misc(
+                            current_module.to_owned(),
+                            format!("Cannot bind signature of {}: {:?}", name, signature)
+                        )))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                self.convert_overloaded(&name, &signatures, &value, item.constructs())
+            }
+
+            // Construct signatures are bound as functions calling the value with `new`
+            typ if typ.is_fn() => {
+                let (_, fn_type) = fn_signature(typ).unwrap();
+                self.convert_fn(Conversion::TsToPy, fn_type, &value, item.constructs())
+            }
+
+            typ => {
+                let converted = self.convert(Conversion::TsToPy, typ, &value);
+                if self.options.gen_config.wrap_top_level_vars {
+                    format!("function () {{ return {}; }}", converted)
+                } else {
+                    converted
+                }
+            }
+        };
+
+        self.overrides.insert(name, wrapper);
+
         Ok(())
     }
 
-    fn handle_type(&mut self, current_module: &Path, name: &str, typ: &Type)
+    /// Variants of unions and enums are exported as members of the type (i.e. `Color-Red`)
+    ///   Variants with fields are functions taking the fields (in declaration order)
+    fn handle_type(&mut self, _current_module: &Path, item: &ItemPath, typ: &Type)
         -> Result<(), EmitError> {
-        match typ {
-            Type::Class(ref class_type) => {
-                opt!(self.options.gen_config, output_constructor_wrappers, {
 
-                    for (index, constructor) in class_type.constructors.iter().enumerate() {
+        let variants: Vec<(String, Vec<JsWord>)> = match typ {
+            Type::DataUnion(ref data_union) => data_union.variant_names()
+                .into_iter()
+                .zip(data_union.variants.iter())
+                .map(|(variant_name, variant)| {
+                    let fields = variant.fields.iter().map(|(field, _)| field.clone()).collect();
+                    (variant_name, fields)
+                })
+                .collect(),
 
-                        let constructor_name =
-                            emit_common::constuctor_name(index, &*class_type.name);
+            Type::LitUnion(ref lit_values) => lit_variant_names(lit_values.iter())
+                .into_iter()
+                .map(|variant_name| (variant_name, Vec::new()))
+                .collect(),
 
-                        let string_constructor =
-                            self.build_constructor(&*class_type.name, &constructor_name, constructor);
+            Type::Enum(ref enum_type) => enum_type.variant_names()
+                .into_iter()
+                .map(|variant_name| (variant_name, Vec::new()))
+                .collect(),
 
-                        self.overrides.insert(constructor_name, string_constructor);
-                    }
-                });
+            _ => return Ok(()),
+        };
 
-                Ok(())
-            }
+        for (variant_name, fields) in variants.into_iter() {
+            let variant_item = item.member(&JsWord::from(variant_name.as_str()));
+            let variant_name = format!("{:?}", variant_name);
 
-            _ => Ok(()),
-        }
-    }
+            let value = if fields.is_empty() {
+                py_variant(&variant_name, "")
+            } else {
+                let params: Vec<String> = fields.iter().map(|_| self.tmp_binding()).collect();
+                let fields: Vec<String> = fields
+                    .iter()
+                    .zip(params.iter())
+                    .map(|(field, param)| format!("{:?}: {}", &**field, param))
+                    .collect();
 
-    fn finalize(self, current_module: &Path, default_require_path: String)
-        -> Result<String, EmitError> {
+                format!(
+                    "function ({}) {{ return {}; }}",
+                    params.join(", "),
+                    py_variant(&variant_name, &fields.join(", "))
+                )
+            };
 
-        let mut output = String::new();
+            self.overrides.insert(variant_item.pyret_name(), value);
+        }
 
-        let require_path = self.options.require_path
-            .as_ref()
-            .map(|p| p.clone())
-            .unwrap_or(&default_require_path);
+        Ok(())
+    }
 
-        output.push_str(
-            &format!("const root = require(\"{}\");\n", require_path)
-        );
+    fn finalize(self, _current_module: &Path) -> Result<String, EmitError> {
+        let mut output = String::new();
+        self.prelude(&mut output);
 
-        output.push_str(
-            &format!("module.exports = root;\n\n")
-        );
+        for conversion in self.conversions.into_iter() {
+            output.push_str(&conversion);
+            output.push('\n');
+        }
 
         for (override_key, override_value) in self.overrides.into_iter() {
             output.push_str(
-                &format!("module.exports[\"{}\"] = {};\n", override_key, override_value)
+                &format!("module.exports[{:?}] = {};\n", override_key, override_value)
             );
         }
 
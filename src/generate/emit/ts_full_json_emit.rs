@@ -1,14 +1,17 @@
+use std::collections::HashMap;
 use std::path::Path;
 
 use serde_json::{json, Map, Value};
-use serde_json::error::Error as JsonError;
+use swc_atoms::JsWord;
 
 use crate::compile_opt::CompileOpt;
-use crate::generate::emit_common;
 use crate::generate::error::EmitError;
+use crate::generate::structures::CanonPath;
+use crate::generate::typify_graph::ModuleGraph;
+use crate::generate::StringDicts;
 use crate::generate::type_structs::*;
 
-use super::JsonEmitter;
+use super::{ItemPath, JsonEmitter, CALL_FIELD, CONSTRUCT_SUFFIX};
 
 macro_rules! local_type {
     ($name: expr) => {
@@ -20,12 +23,6 @@ macro_rules! local_type {
     }
 }
 
-macro_rules! opaque_record {
-    () => {
-        json!(["record", {}])
-    }
-}
-
 
 /// ``` text
 /// datatype formats:
@@ -33,6 +30,11 @@ macro_rules! opaque_record {
 ///     [ "arrow", [params], return-type ]
 ///     [ "forall", [type-param-names], poly-type ]
 ///     [ "tyapp", type, [type-args] ]
+///     [ "tuple", [element-types] ]
+///
+///     variant:
+///         [ variant-name, [ [field-name, type], ... ] ]
+///         [ variant-name ]                              (singleton)
 ///
 ///     type:
 ///         [ "local", name ]
@@ -52,133 +54,266 @@ macro_rules! opaque_record {
 ///     "local-type-name": ["local", "local-type-name"]
 /// }
 /// ```
+fn type_param_names(type_params: &[JsWord]) -> Vec<&str> {
+    type_params.iter().map(|p| &**p).collect()
+}
+
+/// Datatypes are named by the identifiers of their declarations (see `Type::Named`)
+///   Exported items alias the datatypes under their Pyret names
 pub(super) struct TsFullJsonOutput<'a> {
-    anon_counter: u64,
     provides_values: Map<String, Value>,
     provides_aliases: Map<String, Value>,
     provides_datatypes: Map<String, Value>,
     options: &'a CompileOpt<'a>,
+    graph: &'a ModuleGraph,
+
+    /// Named structural types being inlined (recursive references are untyped)
+    inlining: Vec<(CanonPath, JsWord)>,
+
+    /// String dict types are provided by the builtin `string-dict` module
+    uses_string_dicts: bool,
 }
 
 impl<'a> TsFullJsonOutput<'a> {
-    pub fn new(options: &'a CompileOpt<'a>) -> Self {
+    pub fn new(options: &'a CompileOpt<'a>, graph: &'a ModuleGraph) -> Self {
         TsFullJsonOutput {
             options,
-            anon_counter: 0,
+            graph,
             provides_values: Map::new(),
             provides_aliases: Map::new(),
             provides_datatypes: Map::new(),
+            inlining: Vec::new(),
+            uses_string_dicts: false,
+        }
+    }
+
+    /// Declaration of a named type (see `ModuleNode::declared_types`)
+    fn resolve_named(&self, name: &JsWord, source: &CanonPath) -> Option<&'a Type> {
+        self.graph
+            .nodes
+            .get(source)
+            .and_then(|node| node.declared_types.get(name))
+    }
+
+    /// Declarations bound as Pyret datatypes (the rest are aliases of their structure)
+    fn is_nominal(&self, typ: &Type) -> bool {
+        match typ {
+            Type::Class(..)
+                | Type::Enum(..)
+                | Type::LitUnion(..)
+                | Type::DataUnion(..)
+                | Type::Opaque { .. } => true,
+
+            Type::Interface { .. } => self.options.gen_config.output_opaque_interfaces,
+
+            _ => false,
         }
     }
 
-    fn anon_inc(&mut self) -> u64 {
-        self.anon_counter += 1;
+    /// Reference to the datatype named `name` (defined by `typ` if it is not defined yet)
+    fn datatype(&mut self, name: &str, typ: &Type) -> Value {
+        if !self.provides_datatypes.contains_key(name) {
+            // Recursive references see the (placeholder) entry
+            self.provides_datatypes.insert(name.to_string(), Value::Null);
+            let datatype = self.define_type(name, typ);
+            self.provides_datatypes.insert(name.to_string(), datatype);
+        }
 
-        self.anon_counter
+        local_type!(@V name)
     }
 
-    fn define_constructor(
-        &self,
-        constructor: &FnType,
-        self_class_type: Value,
-    ) -> Value {
-        let params: Vec<Value> = constructor.params
-                    .iter()
-                    .map(|t| TsFullJsonOutput::in_place_type_to_value(t))
-                    .collect();
+    /// Named nominal types are datatypes. Named structural types are inlined
+    ///   Unresolved types are untyped
+    fn named_type_to_value(&mut self, name: &JsWord, source: &CanonPath, args: &[Type]) -> Value {
+        let declared = match self.resolve_named(name, source) {
+            Some(declared) => declared,
+            None => return json!("tany"),
+        };
+
+        if self.is_nominal(declared) {
+            let datatype = self.datatype(name, declared);
+            if args.is_empty() {
+                return datatype;
+            }
 
-        let return_type = self_class_type;
+            let args: Vec<Value> = args.iter().map(|t| self.in_place_type_to_value(t)).collect();
+            return json!(["tyapp", datatype, args]);
+        }
 
-        json!([
-            "arrow",
-            params,
-            return_type
-        ])
+        let key = (source.clone(), name.clone());
+        if self.inlining.contains(&key) {
+            return json!("tany");
+        }
+
+        let instance = declared.instantiate(args);
+
+        self.inlining.push(key);
+        let value = self.in_place_type_to_value(&instance);
+        self.inlining.pop();
+
+        value
     }
 
-    fn define_type(&mut self, typ: &Type) -> Value {
+    /// Fields of a record type
+    fn record_to_value(&mut self, fields: &HashMap<JsWord, Type>) -> Value {
+        let mut map = Map::new();
+        for (key, field_typ) in fields.iter() {
+            let field_typ = self.in_place_type_to_value(field_typ);
+            map.insert(key.to_string(), field_typ);
+        }
 
-        macro_rules! opaque_type {
-            ($name: expr) => {
-                json!(["data", $name, [], [], {}]);
+        json!(["record", map])
+    }
+
+    /// Opaque datatype with the instance methods as methods
+    ///   Methods take the instance as their first parameter
+    ///
+    /// Datatypes are nominal (unlike interfaces, which are bound as records with the inherited fields)
+    ///   Instances of derived classes are not instances of their superclasses
+    fn define_class(&mut self, class_type: &ClassType) -> Value {
+        let type_params = type_param_names(&class_type.type_params);
+        let self_type = if type_params.is_empty() {
+            local_type!(@V &*class_type.name)
+        } else {
+            let args: Vec<Value> = type_params.iter().map(|p| json!(["tid", p])).collect();
+            json!(["tyapp", local_type!(@V &*class_type.name), args])
+        };
+
+        let mut methods = Map::new();
+        for (key, member) in class_type.members.iter() {
+            if let MemberKind::Method = member.kind {
+                let method_type = self.method_type_to_value(&self_type, &member.typ);
+                methods.insert(key.to_string(), method_type);
             }
         }
 
+        json!(["data", &*class_type.name, type_params, [], methods])
+    }
+
+    /// Type of a method taking `self_type` as its first parameter
+    ///   Overloaded methods are untyped (see `Type::Overloaded`)
+    fn method_type_to_value(&mut self, self_type: &Value, typ: &Type) -> Value {
         match typ {
-            Type::Fn {
-                ..
-            } => TsFullJsonOutput::in_place_type_to_value(typ),
+            Type::Fn(..) => {
+                let mut method_type = self.in_place_type_to_value(typ);
+                if let Some(params) = method_type[1].as_array_mut() {
+                    params.insert(0, self_type.clone());
+                }
 
-            Type::Class(ClassType {
-                ref name,
-                ..
-            }) => opaque_type!(name),
+                method_type
+            }
 
-            Type::Interface {
-                ref name,
-                ref fields,
-                ..
-            } => {
-                opt!(self.options.gen_config, output_opaque_interfaces, {
-                    return opaque_type!(name);
-                });
+            Type::Forall {
+                ref type_params,
+                ref body,
+            } => json!([
+                "forall",
+                type_param_names(type_params),
+                self.method_type_to_value(self_type, body)
+            ]),
 
-                let mut map = Map::new();
-                for (key, field_typ) in fields.iter() {
-                   let field_typ = TsFullJsonOutput::in_place_type_to_value(field_typ);
-                   map.insert(key.to_string(), field_typ);
+            typ => self.in_place_type_to_value(typ),
+        }
+    }
+
+    /// One variant per member of the union (named by its discriminant)
+    ///   The discriminant is implied by the variant and is not a field
+    fn define_data_union(&mut self, name: &str, data_union: &DataUnion) -> Value {
+        let variants: Vec<Value> = data_union.variants
+            .iter()
+            .zip(data_union.variant_names())
+            .map(|(variant, variant_name)| {
+                if variant.fields.is_empty() {
+                    return json!([variant_name]);
                 }
 
-                json!(["record", map])
-            }
+                let fields: Vec<Value> = variant.fields
+                    .iter()
+                    .map(|(field, typ)| {
+                        json!([&**field, self.in_place_type_to_value(typ)])
+                    })
+                    .collect();
 
-            Type::Literal {
-                ..
-            } => todo!("define type literal"),
-            //opaque_type!(format!("anon{}", self.anon_inc())),
+                json!([variant_name, fields])
+            })
+            .collect();
+
+        json!(["data", name, [], variants, {}])
+    }
 
-            Type::Named { .. } => todo!("Cannot define a named type"),
+    /// One singleton variant per literal
+    fn define_lit_union(name: &str, lit_values: &[LitValue]) -> Value {
+        let variants: Vec<Value> = lit_variant_names(lit_values.iter())
+            .into_iter()
+            .map(|variant_name| json!([variant_name]))
+            .collect();
 
-            Type::UnsizedArray(ref _e_type) => todo!("Cannot re-define the unsized array type"),
+        json!(["data", name, [], variants, {}])
+    }
 
-            Type::Array(ref _e_type, ref _size) => todo!("Cannot re-define the array type"),
+    /// One singleton variant per member (named by the member)
+    fn define_enum(name: &str, enum_type: &EnumType) -> Value {
+        let variants: Vec<Value> = enum_type.variant_names()
+            .into_iter()
+            .map(|variant_name| json!([variant_name]))
+            .collect();
 
-            Type::Alias { .. } => todo!("Aliases are not in the datatypes section"),
+        json!(["data", name, [], variants, {}])
+    }
 
-            Type::Union => opaque_type!(format!("union{}", self.anon_inc())),
+    /// Datatype of a nominal type (see `is_nominal()`)
+    fn define_type(&mut self, name: &str, typ: &Type) -> Value {
 
-            Type::Opaque {
-                ref name,
+        macro_rules! opaque_type {
+            ($name: expr) => {
+                json!(["data", $name, [], [], {}])
+            };
+
+            ($name: expr, $type_params: expr) => {
+                json!(["data", $name, type_param_names($type_params), [], {}])
+            };
+        }
+
+        match typ {
+            Type::Class(ref class_type) => self.define_class(class_type),
+
+            Type::Interface {
+                ref type_params,
                 ..
-            } => opaque_type!(name),
-
-            Type::Boolean   |
-            Type::Number    |
-            Type::String    |
-            Type::Void      |
-            Type::Object    |
-            Type::Any       |
-            Type::Never     |
-            Type::Undefined |
-            Type::Null => todo!("Cannot redefine primitives"),
+            } => opaque_type!(name, type_params),
+
+            Type::DataUnion(ref data_union) => self.define_data_union(name, data_union),
+
+            Type::LitUnion(ref lit_values) => TsFullJsonOutput::define_lit_union(name, lit_values),
+
+            Type::Enum(ref enum_type) => TsFullJsonOutput::define_enum(name, enum_type),
+
+            _ => opaque_type!(name),
         }
     }
 
     /// Generates the Value representing the Type embedded within another Type.
     /// Assumes types are already defined in the datatypes section.
-    fn in_place_type_to_value(typ: &Type) -> Value {
+    fn in_place_type_to_value(&mut self, typ: &Type) -> Value {
         match typ {
             Type::Fn(FnType {
                 ref params,
+                ref rest_param,
                 ref return_type,
+                ..
             })=> {
-                let params: Vec<Value> = params
+                let mut params: Vec<Value> = params
                     .iter()
-                    .map(|t| TsFullJsonOutput::in_place_type_to_value(t))
+                    .map(|t| self.in_place_type_to_value(t))
                     .collect();
 
+                // Rest arguments are passed as a Pyret list or raw array
+                if rest_param.is_some() {
+                    params.push(json!("tany"));
+                }
+
                 let return_type =
-                    TsFullJsonOutput::in_place_type_to_value(return_type);
+                    self.in_place_type_to_value(return_type);
 
                 // [ "arrow", [params], return-type ]
                 json!([
@@ -188,38 +323,73 @@ impl<'a> TsFullJsonOutput<'a> {
                 ])
             }
 
-            Type::Class(ClassType {
-                ref name,
-                ..
-            }) => local_type!(@V name),
+            Type::Class(ref class_type) => self.datatype(&class_type.name, typ),
 
             Type::Interface {
                 ref name,
+                ref fields,
                 ..
-            } => local_type!(@V name),
+            } => {
+                if self.options.gen_config.output_opaque_interfaces {
+                    self.datatype(name, typ)
+                } else {
+                    self.record_to_value(fields)
+                }
+            }
 
-            // TODO: How to handle a type alias?
             Type::Alias {
-                ref name,
+                ref aliasing_type,
                 ..
-            } => local_type!(@V name),
+            } => self.in_place_type_to_value(aliasing_type),
 
             Type::Named {
                 ref name,
-                ..
-            } => local_type!(@V name),
+                ref source,
+            } => self.named_type_to_value(name, source, &[]),
 
-            Type::Opaque {
-                ref name,
-                ..
-            } => local_type!(@V name),
+            // [ "forall", [type-param-names], poly-type ]
+            Type::Forall {
+                ref type_params,
+                ref body,
+            } => {
+                let body = self.in_place_type_to_value(body);
+                json!(["forall", type_param_names(type_params), body])
+            }
 
-            Type::Literal {
-                ..
-            } => opaque_record!(),
+            // Instantiations of named structural types are inlined
+            Type::TyApp {
+                ref base,
+                ref args,
+            } if is_named(base) => match **base {
+                Type::Named {
+                    ref name,
+                    ref source,
+                } => self.named_type_to_value(name, source, args),
+
+                _ => unreachable!(),
+            },
+
+            // [ "tyapp", type, [type-args] ]
+            Type::TyApp {
+                ref base,
+                ref args,
+            } => {
+                let base = self.in_place_type_to_value(base);
+                let args: Vec<Value> = args
+                    .iter()
+                    .map(|t| self.in_place_type_to_value(t))
+                    .collect();
 
-            Type::UnsizedArray(ref e_type) => {
-                let e_type = TsFullJsonOutput::in_place_type_to_value(e_type);
+                json!(["tyapp", base, args])
+            }
+
+            Type::TypeVar(ref name) => json!(["tid", &**name]),
+
+            // Pyret has no overloaded function types (see `Overloads::Dispatch`)
+            Type::Overloaded(..) => json!("tany"),
+
+            Type::Optional(ref typ) => {
+                let typ = self.in_place_type_to_value(typ);
                 json!([
                     "tyapp",
                     {
@@ -227,17 +397,72 @@ impl<'a> TsFullJsonOutput<'a> {
                         "origin":
                         {
                             "import-type":"uri",
-                            "uri":"builtin://global"
+                            "uri":"builtin://option"
                         },
-                        "name":"RawArray"
+                        "name":"Option"
                     },
-                    [e_type],
+                    [typ],
                 ])
             }
 
-            Type::Array(ref e_type, ref _size) => {
-                // TODO: Use size somehow
-                let e_type = TsFullJsonOutput::in_place_type_to_value(e_type);
+            Type::Dict(ref value_type) => {
+                self.uses_string_dicts = true;
+                let value_type = self.in_place_type_to_value(value_type);
+                let dict_type = match self.options.gen_config.string_dicts {
+                    StringDicts::Immutable => "StringDict",
+                    StringDicts::Mutable => "MutableStringDict",
+                };
+
+                json!([
+                    "tyapp",
+                    {
+                        "tag":"name",
+                        "origin":
+                        {
+                            "import-type":"uri",
+                            "uri":"builtin://string-dict"
+                        },
+                        "name":dict_type
+                    },
+                    [value_type],
+                ])
+            }
+
+            Type::Opaque {
+                ref name,
+                ..
+            } => self.datatype(name, typ),
+
+            Type::Enum(ref enum_type) => self.datatype(&enum_type.name, typ),
+
+            Type::Literal {
+                ref fields,
+            } => self.record_to_value(fields),
+
+            // Record of the signatures and fields
+            //   (Pyret cannot call or construct objects, see `emit::CALL_FIELD`)
+            Type::Callable(ref callable) => {
+                let mut map = Map::new();
+                for (key, field_typ) in callable.fields.iter() {
+                    map.insert(key.to_string(), self.in_place_type_to_value(field_typ));
+                }
+
+                if let Some(call_type) = callable.call_type() {
+                    map.insert(CALL_FIELD.to_string(), self.in_place_type_to_value(&call_type));
+                }
+
+                if let Some(construct_type) = callable.construct_type() {
+                    map.insert(
+                        CONSTRUCT_SUFFIX.to_string(),
+                        self.in_place_type_to_value(&construct_type),
+                    );
+                }
+
+                json!(["record", map])
+            }
+
+            Type::UnsizedArray(ref e_type) => {
+                let e_type = self.in_place_type_to_value(e_type);
                 json!([
                     "tyapp",
                     {
@@ -249,10 +474,20 @@ impl<'a> TsFullJsonOutput<'a> {
                         },
                         "name":"RawArray"
                     },
-                    e_type
+                    [e_type],
                 ])
             }
 
+            // [ "tuple", [element-types] ]
+            Type::Tuple(ref elem_types) => {
+                let elem_types: Vec<Value> = elem_types
+                    .iter()
+                    .map(|t| self.in_place_type_to_value(t))
+                    .collect();
+
+                json!(["tuple", elem_types])
+            }
+
             Type::Boolean => json!("Boolean"),
 
             Type::Number => json!("Number"),
@@ -264,20 +499,41 @@ impl<'a> TsFullJsonOutput<'a> {
             // TODO: Better way to handle primitive 'object' type?
             Type::Object => json!("tany"),
 
-            Type::Any => json!("tany"),
+            Type::Any | Type::Unevaluated { .. } => json!("tany"),
 
             Type::Never => json!("tbot"),
 
-            Type::Undefined => {
-                todo!("Undefined primitive type");
-            }
+            Type::Undefined => json!("Nothing"),
 
-            Type::Null => {
-                todo!();
-            }
+            Type::Null => json!("tany"),
 
             // TODO: Union types default to Any
-            Type::Union => json!("tany"),
+            Type::Union(..) => json!("tany"),
+
+            // TODO: Anonymous data unions are not in the datatypes section
+            Type::DataUnion(..) => json!("tany"),
+
+            // Literal types are not distinguished from their primitive types
+            Type::Lit(ref lit_value) => {
+                self.in_place_type_to_value(&lit_value.base_type())
+            }
+
+            // TODO: Anonymous enumerations are not in the datatypes section
+            //   Default to the primitive type of the literals (if they share one)
+            Type::LitUnion(ref lit_values) => {
+                let base_types: Vec<Value> = lit_values
+                    .iter()
+                    .map(|lit_value| {
+                        self.in_place_type_to_value(&lit_value.base_type())
+                    })
+                    .collect();
+
+                match base_types.split_first() {
+                    Some((first, rest)) if rest.iter().all(|t| t == first) => first.clone(),
+
+                    _ => json!("tany"),
+                }
+            }
         }
     }
 
@@ -285,71 +541,103 @@ impl<'a> TsFullJsonOutput<'a> {
 
 impl<'a> JsonEmitter for TsFullJsonOutput<'a> {
 
-    fn export_value(&mut self, current_module: &Path, name: &str, value_type: &Type)
+    fn export_value(&mut self, _current_module: &Path, item: &ItemPath, value_type: &Type)
         -> Result<(), EmitError> {
 
-        let value_type = TsFullJsonOutput::in_place_type_to_value(value_type);
+        let value_type = match value_type {
+            Type::Fn(..) | Type::Forall { .. } | Type::Overloaded(..) => {
+                self.in_place_type_to_value(value_type)
+            }
 
-        self.provides_values.insert(name.to_string(), value_type);
+            // Variables are read through getters (see `wrap_top_level_vars`)
+            _ if !item.constructs() && self.options.gen_config.wrap_top_level_vars => {
+                json!(["arrow", [], self.in_place_type_to_value(value_type)])
+            }
+
+            _ => self.in_place_type_to_value(value_type),
+        };
+
+        self.provides_values.insert(item.pyret_name(), value_type);
 
         Ok(())
     }
 
-    fn export_type(&mut self, current_module: &Path, name: &str, typ: &Type)
+    /// Nominal types are aliases of their datatypes (see `is_nominal()`)
+    ///   Variants of unions and enums are exported as members of the type (i.e. `Color-Red`)
+    ///
+    /// Structural types are aliases of their structure
+    fn export_type(&mut self, _current_module: &Path, item: &ItemPath, typ: &Type)
         -> Result<(), EmitError> {
 
-        match typ {
+        let pyret_name = item.pyret_name();
 
-            Type::Alias {
-                ref name,
-                ref aliasing_type,
-            } => {
+        if !self.is_nominal(typ) {
+            let actual_type = self.in_place_type_to_value(typ);
+            self.provides_aliases.insert(pyret_name, actual_type);
 
-                self.provides_aliases.insert(name.to_string(), local_type!(@V name));
-                return Ok(());
-            }
-
-            Type::Class(ref class_type) => {
-                opt!(self.options.gen_config, output_constructor_wrappers, {
-                    for (index, constructor) in class_type.constructors.iter().enumerate() {
-                        let constructor_name =
-                            emit_common::constuctor_name(index, &*class_type.name);
+            return Ok(());
+        }
 
-                        let local_type = local_type!(@V name);
+        let datatype_name = match typ {
+            Type::Class(ref class_type) => class_type.name.clone(),
+            Type::Enum(ref enum_type) => enum_type.name.clone(),
+            Type::Interface { ref name, .. } | Type::Opaque { ref name, .. } => name.clone(),
+            _ => item.name().clone(),
+        };
 
-                        let constructor =
-                            self.define_constructor(
-                                constructor,
-                                local_type,
-                            );
+        let local_type = self.datatype(&datatype_name, typ);
 
-                        self.provides_values.insert(constructor_name, constructor);
-                    }
-                });
+        match typ {
+            Type::DataUnion(ref data_union) => {
+                let variant_names = data_union.variant_names();
+                for (variant, variant_name) in data_union.variants.iter().zip(variant_names) {
+                    let variant_type = if variant.fields.is_empty() {
+                        local_type.clone()
+                    } else {
+                        let params: Vec<Value> = variant.fields
+                            .iter()
+                            .map(|(_, field_type)| self.in_place_type_to_value(field_type))
+                            .collect();
+
+                        json!(["arrow", params, local_type.clone()])
+                    };
+
+                    let variant_item = item.member(&JsWord::from(variant_name));
+                    self.provides_values.insert(variant_item.pyret_name(), variant_type);
+                }
             }
 
-            typ @ Type::Interface { .. } => {
-                let actual_type = self.define_type(typ);
-                self.provides_aliases.insert(name.to_string(), actual_type);
+            Type::LitUnion(ref lit_values) => {
+                for variant_name in lit_variant_names(lit_values.iter()) {
+                    let variant_item = item.member(&JsWord::from(variant_name));
+                    self.provides_values.insert(variant_item.pyret_name(), local_type.clone());
+                }
+            }
 
-                return Ok(());
+            Type::Enum(ref enum_type) => {
+                for variant_name in enum_type.variant_names() {
+                    let variant_item = item.member(&JsWord::from(variant_name));
+                    self.provides_values.insert(variant_item.pyret_name(), local_type.clone());
+                }
             }
 
             _ => (),
         }
 
-        let local_type = local_type!(@V name);
-        let actual_type = self.define_type(typ);
-
-        self.provides_aliases.insert(name.to_string(), local_type);
-        self.provides_datatypes.insert(name.to_string(), actual_type);
+        self.provides_aliases.insert(pyret_name, local_type);
 
         Ok(())
     }
 
     fn finalize(self, current_module: &Path) -> Result<String, EmitError> {
+        let requires = if self.uses_string_dicts {
+            json!([{ "import-type": "builtin", "name": "string-dict" }])
+        } else {
+            json!([])
+        };
+
         let map = json!({
-            "requires": [],
+            "requires": requires,
             "provides": {
                 "shorthands": { },
                 "values": self.provides_values,
@@ -361,3 +649,9 @@ impl<'a> JsonEmitter for TsFullJsonOutput<'a> {
         serde_json::to_string_pretty(&map).map_err(|e| EmitError::JsonError(current_module.to_owned(), e))
     }
 }
+
+/// The type is a reference to a declaration
+fn is_named(typ: &Type) -> bool {
+    matches!(typ, Type::Named { .. })
+}
+
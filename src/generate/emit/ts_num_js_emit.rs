@@ -9,16 +9,23 @@ use indexmap::IndexMap;
 
 use crate::generate::type_structs::*;
 use crate::generate::error::EmitError;
+use crate::generate::js_pp::PrettyPrinter;
 use crate::compile_opt::CompileOpt;
 
-use super::JsEmitter;
+use super::{ItemPath, JsEmitter};
 
-const C_TS_NUMBER_PY_NUMBER: &'static str = "C_ts_number_py_number";
-const C_PY_NUMBER_TS_NUMBER: &'static str = "C_py_number_ts_number";
+const C_TS_NUMBER_PY_NUMBER: &str = "C_ts_number_py_number";
+const C_PY_NUMBER_TS_NUMBER: &str = "C_py_number_ts_number";
+const C_PY_LIST_TS_ARRAY: &str = "C_py_list_ts_array";
+const ROOT_DEFAULT: &str = "root_default";
 
 macro_rules! root_value {
     ($i: expr) => {
-        format!("root[\"{}\"]", $i)
+        if $i.is_root_default() {
+            ROOT_DEFAULT.to_string()
+        } else {
+            $i.js_access("root")
+        }
     }
 }
 
@@ -32,14 +39,20 @@ pub(super) struct TsNumJsOutput<'a> {
     options: &'a CompileOpt<'a>,
     overrides: IndexMap<String, Expr>,
     anon_counter: u64,
+    uses_root_default: bool,
+    root_global: Option<Vec<String>>,
 }
 
 impl<'a> TsNumJsOutput<'a> {
-    pub fn new(options: &'a CompileOpt<'a>) -> Self {
+    /// `root_global` is the property path from `globalThis` binding the root module
+    ///   (if it is not required)
+    pub fn new(options: &'a CompileOpt<'a>, root_global: Option<Vec<String>>) -> Self {
         TsNumJsOutput {
             options,
             overrides: IndexMap::new(),
             anon_counter: 0,
+            uses_root_default: false,
+            root_global,
         }
     }
 
@@ -54,9 +67,22 @@ impl<'a> TsNumJsOutput<'a> {
 
     fn prelude(&self, body: &mut Vec<ModuleItem>, require_path: &str) {
         // const root = require(require_path);
-        let root_import = {
-            let call = expr!(Call expr!(Ident "require") => expr!(String require_path));
-            stmt!(const "root" => call)
+        //   OR
+        // const root = globalThis["Outer"]["Inner"];
+        let root_import = match self.root_global {
+            Some(ref root_global) => {
+                let global = root_global
+                    .iter()
+                    .fold(expr!(Ident "globalThis"), |object, key| {
+                        expr!(Member object => expr!(String key.as_str()))
+                    });
+                stmt!(const "root" => global)
+            }
+
+            None => {
+                let call = expr!(Call expr!(Ident "require") => expr!(String require_path));
+                stmt!(const "root" => call)
+            }
         };
 
         // module.exports = Object.assign({}, root)
@@ -74,7 +100,15 @@ impl<'a> TsNumJsOutput<'a> {
             stmt!(Expr assign)
         };
 
+        // const root_default = root["__esModule"] ? root["default"] : root;
+        //   CommonJS modules (without `__esModule`) are their own default export
+        let root_default = {
+            let is_es_module = expr!(Member expr!(Ident "root") => expr!(String "__esModule"));
+            let es_default = expr!(Member expr!(Ident "root") => expr!(String "default"));
+            let interop = expr!(? is_es_module => es_default, expr!(Ident "root"));
 
+            stmt!(const ROOT_DEFAULT => interop)
+        };
 
         let c_ts_number_py_number = function!(
             param!(ident!("ts_num"))
@@ -107,10 +141,52 @@ impl<'a> TsNumJsOutput<'a> {
                 expr!(Fn("C_py_number_ts_number") @ c_py_number_ts_number)
         );
 
+        // Raw arrays are passed through
+        //   Pyret lists are `link` values (with `first` and `rest` fields) ending in `empty`
+        let c_py_list_ts_array = {
+            let is_array = expr!(Call expr!(DOT expr!(Ident "Array") => expr!(Ident "isArray")) =>
+                expr!(Ident "py_list")
+            );
+            let is_link = expr!(===
+                expr!(Member expr!(Ident "py_list") => expr!(String "$name")),
+                expr!(String "link")
+            );
+            let rest = expr!(Call expr!(Ident C_PY_LIST_TS_ARRAY) =>
+                expr!(Member expr!(Ident "py_list") => expr!(String "rest"))
+            );
+            let unshift_first = expr!(Call expr!(DOT expr!(Ident "array") => expr!(Ident "unshift")) =>
+                expr!(Member expr!(Ident "py_list") => expr!(String "first"))
+            );
+            let empty = expr!(Call expr!(DOT expr!(Ident "Array") => expr!(Ident "of")));
+
+            let c_py_list_ts_array = function!(
+                param!(ident!("py_list"))
+                =>
+                stmt!(if is_array => stmt!(return expr!(Ident "py_list")));
+                stmt!(if is_link => stmt!(block
+                    stmt!(const "array" => rest);
+                    stmt!(Expr unshift_first);
+                    stmt!(return expr!(Ident "array"))
+                ));
+                stmt!(return empty)
+            );
+
+            stmt!(const C_PY_LIST_TS_ARRAY =>
+                expr!(Fn(C_PY_LIST_TS_ARRAY) @ c_py_list_ts_array)
+            )
+        };
+
         body.push(ModuleItem::Stmt(root_import));
-        body.push(ModuleItem::Stmt(default_set));
+        // Bound globals are exported individually (not all of `globalThis`)
+        if self.options.bind_globals.is_none() {
+            body.push(ModuleItem::Stmt(default_set));
+        }
+        if self.uses_root_default {
+            body.push(ModuleItem::Stmt(root_default));
+        }
         body.push(ModuleItem::Stmt(c_ts_number_py_number));
         body.push(ModuleItem::Stmt(c_py_number_ts_number));
+        body.push(ModuleItem::Stmt(c_py_list_ts_array));
     }
 
     fn c_ts_number_py_number(&self, binding: &str) -> Expr {
@@ -121,6 +197,28 @@ impl<'a> TsNumJsOutput<'a> {
         expr!(Call expr!(Ident C_PY_NUMBER_TS_NUMBER) => expr!(Ident binding))
     }
 
+    /// Conversion of an argument passed to a function wrapped by `conversion`
+    ///   Arguments are converted in the opposite direction of the function
+    fn c_param(&mut self, conversion: Conversion, param_type: &Type, binding: &str) -> Option<Expr> {
+        let converted = match (conversion, param_type) {
+            (Conversion::TsToPy, Type::Fn(ref inner_fn_type)) => {
+                self.c_fn(Conversion::PyToTs, inner_fn_type, binding)
+            }
+
+            (Conversion::TsToPy, Type::Number) => self.c_py_number_ts_number(binding),
+
+            (Conversion::PyToTs, Type::Fn(ref inner_fn_type)) => {
+                self.c_fn(Conversion::TsToPy, inner_fn_type, binding)
+            }
+
+            (Conversion::PyToTs, Type::Number) => self.c_ts_number_py_number(binding),
+
+            _ => return None,
+        };
+
+        Some(converted)
+    }
+
     fn c_fn(&mut self,
         conversion: Conversion,
         fn_type: &FnType,
@@ -142,22 +240,58 @@ impl<'a> TsNumJsOutput<'a> {
                 expr: Box::new(expr!(Ident converted_id.as_str()))
             });
 
-            let converted = match (conversion, param_type) {
-                (Conversion::TsToPy, Type::Fn(ref inner_fn_type)) => {
-                    self.c_fn(Conversion::PyToTs, inner_fn_type, &param_id)
-                }
-
-                (Conversion::TsToPy, Type::Number) => self.c_py_number_ts_number(&param_id),
-
-                (Conversion::PyToTs, Type::Fn(ref inner_fn_type)) => {
-                    self.c_fn(Conversion::TsToPy, inner_fn_type, &param_id)
-                }
+            let converted = self.c_param(conversion, param_type, &param_id)
+                .unwrap_or_else(|| unreachable!("Invalid type: {} {:?} (param {})", binding, param_type, index));
 
-                (Conversion::PyToTs, Type::Number) => self.c_ts_number_py_number(&param_id),
+            body.push(stmt!(let converted_id => converted));
+        }
 
-                ref t => unreachable!("Invalid type: {} {:?} (param {})", binding, t, index),
+        // Rest arguments are converted element by element
+        //   From Pyret: a Pyret list or raw array spread into the call
+        //   From TS: a raw array of the rest arguments
+        if let Some(ref elem_type) = fn_type.rest_param {
+            let rest_id = self.tmp_binding();
+            let converted_id = self.tmp_binding();
+            let elem_id = self.tmp_binding();
+
+            let elem_conversion = self.c_param(conversion, elem_type, &elem_id)
+                .unwrap_or_else(|| unreachable!("Invalid type: {} {:?} (rest)", binding, elem_type));
+            let elem_fn = expr!(Fn function!(
+                param!(ident!(elem_id.as_str()))
+                =>
+                stmt!(return elem_conversion)
+            ));
+
+            let (rest_pat, rest_array, spread) = match conversion {
+                Conversion::TsToPy => (
+                    Pat::Ident(ident!(rest_id.as_str())),
+                    expr!(Call expr!(Ident C_PY_LIST_TS_ARRAY) => expr!(Ident rest_id.as_str())),
+                    Some(DUMMY_SP),
+                ),
+
+                Conversion::PyToTs => (
+                    Pat::Rest(RestPat {
+                        span: DUMMY_SP,
+                        dot3_token: DUMMY_SP,
+                        arg: Box::new(Pat::Ident(ident!(rest_id.as_str()))),
+                        type_ann: None,
+                    }),
+                    expr!(Ident rest_id.as_str()),
+                    None,
+                ),
             };
 
+            params.push(Param {
+                span: DUMMY_SP,
+                decorators: vec![],
+                pat: rest_pat,
+            });
+            result_call_args.push(ExprOrSpread {
+                spread,
+                expr: Box::new(expr!(Ident converted_id.as_str()))
+            });
+
+            let converted = expr!(Call expr!(DOT rest_array => expr!(Ident "map")) => elem_fn);
             body.push(stmt!(let converted_id => converted));
         }
 
@@ -204,15 +338,105 @@ impl<'a> TsNumJsOutput<'a> {
             function: wrapper
         })
     }
+
+    /// Function dispatching on the number of arguments to the wrapper of a signature
+    ///   Assumes the signatures take distinct numbers of arguments (see `FnType::pyret_arity()`)
+    fn c_overloaded(&mut self,
+        name: &str,
+        signatures: &[&FnType],
+        binding: &str
+    ) -> Expr {
+
+        let args_id = self.tmp_binding();
+        let args_length = expr!(DOT expr!(Ident args_id.as_str()) => expr!(Ident "length"));
+        let mut body: Vec<Stmt> = Vec::new();
+        let mut dispatch: Vec<Stmt> = Vec::new();
+
+        for fn_type in signatures.iter() {
+            let wrapper_id = self.tmp_binding();
+            let wrapper = self.c_fn(Conversion::TsToPy, fn_type, binding);
+            body.push(stmt!(const wrapper_id.as_str() => wrapper));
+
+            let test = expr!(=== args_length.clone(), expr!(Num fn_type.pyret_arity() as f64));
+
+            let call = expr!(Call-flat expr!(Ident wrapper_id.as_str()) => vec![ExprOrSpread {
+                spread: Some(DUMMY_SP),
+                expr: Box::new(expr!(Ident args_id.as_str())),
+            }]);
+
+            dispatch.push(stmt!(if test => stmt!(block stmt!(return call))));
+        }
+
+        let message = expr!(+
+            expr!(+ expr!(String format!("{}: no signature takes ", name)), args_length),
+            expr!(String " arguments")
+        );
+        let error = expr!(New expr!(Ident "Error") => message);
+        dispatch.push(stmt!(throw error));
+
+        let dispatch_fn = Function {
+            params: vec![Param {
+                span: DUMMY_SP,
+                decorators: vec![],
+                pat: Pat::Rest(RestPat {
+                    span: DUMMY_SP,
+                    dot3_token: DUMMY_SP,
+                    arg: Box::new(Pat::Ident(ident!(args_id.as_str()))),
+                    type_ann: None,
+                }),
+            }],
+            decorators: vec![],
+            span: DUMMY_SP,
+            body: Some(BlockStmt {
+                span: DUMMY_SP,
+                stmts: dispatch,
+            }),
+            is_generator: false,
+            is_async: false,
+            type_params: None,
+            return_type: None,
+        };
+        body.push(stmt!(return expr!(Fn dispatch_fn)));
+
+        // (function () { const ___1 = ...; return function (...args) { ... }; })()
+        let wrappers = Function {
+            params: vec![],
+            decorators: vec![],
+            span: DUMMY_SP,
+            body: Some(BlockStmt {
+                span: DUMMY_SP,
+                stmts: body,
+            }),
+            is_generator: false,
+            is_async: false,
+            type_params: None,
+            return_type: None,
+        };
+
+        expr!(Call expr!(Paren expr!(Fn wrappers)))
+    }
 }
 
 impl<'a> JsEmitter for TsNumJsOutput<'a> {
-    fn handle_value(&mut self, current_module: &Path, name: &str, value_type: &Type)
+    fn handle_value(&mut self, current_module: &Path, item: &ItemPath, value_type: &Type)
         -> Result<(), EmitError> {
 
+        let name = item.pyret_name();
+
+        if item.is_root_default() {
+            self.uses_root_default = true;
+        }
+
+        if item.constructs() {
+            return Err(EmitError::Misc(
+                    current_module.to_owned(),
+                    format!("TS-NUM does not support construct signatures: {}", name)
+                ));
+        }
+
         match value_type {
             Type::Number => {
-                let converted_value: Expr = self.c_ts_number_py_number(&root_value!(name));
+                let converted_value: Expr = self.c_ts_number_py_number(&root_value!(item));
 
                 let overide = if self.options.gen_config.wrap_top_level_vars {
                     expr!(Fn function!(
@@ -223,14 +447,33 @@ impl<'a> JsEmitter for TsNumJsOutput<'a> {
                     converted_value
                 };
 
-                self.overrides.insert(name.to_string(), overide);
+                self.overrides.insert(name, overide);
 
                 Ok(())
             }
 
             Type::Fn(ref fn_type) => {
-                let value = self.c_fn(Conversion::TsToPy, fn_type, &root_value!(name));
-                self.overrides.insert(name.to_string(), value);
+                let value = self.c_fn(Conversion::TsToPy, fn_type, &root_value!(item));
+                self.overrides.insert(name, value);
+                Ok(())
+            }
+
+            // Signatures accepting distinct numbers of arguments (see `Overloads::Dispatch`)
+            Type::Overloaded(ref signatures) => {
+                let signatures = signatures
+                    .iter()
+                    .map(|signature| match signature {
+                        Type::Fn(ref fn_type) => Ok(fn_type),
+
+                        _ => Err(EmitError::Misc(
+                                current_module.to_owned(),
+                                format!("TS-NUM does not support values of type: {:?}", signature)
+                            )),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                let value = self.c_overloaded(&name, &signatures, &root_value!(item));
+                self.overrides.insert(name, value);
                 Ok(())
             }
 
@@ -241,14 +484,14 @@ impl<'a> JsEmitter for TsNumJsOutput<'a> {
         }
     }
 
-    fn handle_type(&mut self, _current_module: &Path, _name: &str, _typ: &Type)
+    fn handle_type(&mut self, _current_module: &Path, _item: &ItemPath, _typ: &Type)
         -> Result<(), EmitError> {
 
         Ok(())
     }
 
     fn finalize(self, current_module: &Path)
-        -> Result<AstModule, EmitError> {
+        -> Result<String, EmitError> {
 
         let mut body = Vec::new();
         self.prelude(&mut body, &self.options.require_path);
@@ -269,10 +512,19 @@ impl<'a> JsEmitter for TsNumJsOutput<'a> {
             body.push(ModuleItem::Stmt(override_stmt));
         }
 
-        Ok(AstModule {
+        let ast_module = AstModule {
             span: DUMMY_SP,
             body,
             shebang: None,
-        })
+        };
+
+        // NOTE: Cannot use swc_ecma_codegen for whatever reason
+        //   Provided emitter appears to rely on SourceMap and Spans
+        let mut output = Vec::new();
+        PrettyPrinter::print(&mut output, &ast_module)
+            .map_err(|io_err| EmitError::IoError(current_module.to_owned(), io_err))?;
+
+        String::from_utf8(output)
+            .map_err(|e| EmitError::Misc(current_module.to_owned(), e.to_string()))
     }
 }
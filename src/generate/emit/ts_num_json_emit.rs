@@ -6,7 +6,7 @@ use crate::compile_opt::CompileOpt;
 use crate::generate::error::EmitError;
 use crate::generate::type_structs::*;
 
-use super::JsonEmitter;
+use super::{ItemPath, JsonEmitter};
 
 macro_rules! local_type {
     ($name: expr) => {
@@ -83,13 +83,20 @@ impl<'a> TsNumJsonOutput<'a> {
         match typ {
             Type::Fn(FnType {
                 ref params,
+                ref rest_param,
                 ref return_type,
+                ..
             })=> {
-                let params = params
+                let mut params = params
                     .iter()
                     .map(|t| TsNumJsonOutput::in_place_type_to_value(t))
                     .collect::<Result<Vec<_>, _>>()?;
 
+                // Rest arguments are passed as a Pyret list or raw array
+                if rest_param.is_some() {
+                    params.push(json!("tany"));
+                }
+
                 let return_type =
                     TsNumJsonOutput::in_place_type_to_value(return_type)?;
 
@@ -110,7 +117,7 @@ impl<'a> TsNumJsonOutput<'a> {
 
 impl<'a> JsonEmitter for TsNumJsonOutput<'a> {
 
-    fn export_value(&mut self, current_module: &Path, name: &str, value_type: &Type)
+    fn export_value(&mut self, current_module: &Path, item: &ItemPath, value_type: &Type)
         -> Result<(), EmitError> {
 
         let value_type = match value_type {
@@ -125,25 +132,36 @@ impl<'a> JsonEmitter for TsNumJsonOutput<'a> {
                 }
             }
 
+            // Pyret has no overloaded function types (see `Overloads::Dispatch`)
+            Type::Overloaded(ref signatures) => {
+                for signature in signatures.iter() {
+                    TsNumJsonOutput::in_place_type_to_value(signature)
+                        .map_err(|e| EmitError::Misc(current_module.to_owned(), e))?;
+                }
+
+                json!("tany")
+            }
+
             _ => TsNumJsonOutput::in_place_type_to_value(value_type)
                     .map_err(|e| EmitError::Misc(current_module.to_owned(), e))?,
         };
 
 
-        self.provides_values.insert(name.to_string(), value_type);
+        self.provides_values.insert(item.pyret_name(), value_type);
 
         Ok(())
     }
 
-    fn export_type(&mut self, current_module: &Path, name: &str, typ: &Type)
+    fn export_type(&mut self, current_module: &Path, item: &ItemPath, typ: &Type)
         -> Result<(), EmitError> {
 
+        let name = item.pyret_name();
         let local_type = local_type!(@V name);
         let actual_type = self.define_type(typ)
             .map_err(|e| EmitError::Misc(current_module.to_owned(), e))?;
 
-        self.provides_aliases.insert(name.to_string(), local_type);
-        self.provides_datatypes.insert(name.to_string(), actual_type);
+        self.provides_aliases.insert(name.clone(), local_type);
+        self.provides_datatypes.insert(name, actual_type);
 
         Ok(())
     }
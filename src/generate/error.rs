@@ -16,6 +16,10 @@ pub enum BindGenErrorKind {
     UnsupportedFeature(UnsupportedFeature),
     IoError(IoError),
     ParserError,
+    #[allow(dead_code)]
+    ModuleNotFound(String),
+    #[allow(dead_code)]
+    TypeError(TypeError),
 }
 
 impl From<IoError> for BindGenErrorKind {
@@ -26,13 +30,67 @@ impl From<IoError> for BindGenErrorKind {
 
 #[derive(Debug)]
 pub enum UnsupportedFeature {
-    NamespaceImport,
-    DefaultImport,
-    NamespaceExport,
     DefaultExport,
     TsImportEquals,
     TsExportAssignment,
-    TsNamespaceExport,
+    AmbientModule,
+    GlobalAugmentation,
+    ComputedKey,
+}
+
+/// Types which are valid TS but cannot be bound
+#[allow(dead_code)]
+#[derive(Debug)]
+pub enum TypeError {
+    /// Members of an intersection declare the field with different types
+    ConflictingIntersectionField(String),
+
+    /// Member of an intersection or extended interface is not a record type
+    ///   (interface, type literal or class)
+    NonRecordType,
+
+    /// Superclass which is not a class
+    NonClassHeritage,
+
+    /// Declarations of the same name which cannot be merged (i.e. `type Foo` and `interface Foo`)
+    ConflictingDeclaration(String),
+
+    /// Member of an intersection or heritage clause refers back to the type being bound
+    CircularType(String),
+
+    /// Type operator, indexed access, mapped or conditional type whose operands are not known
+    ///   (i.e. `keyof T` of a type parameter `T` or a condition inferring a type with `infer`)
+    ///   or tuple whose elements are not of its rest element type (i.e. `[string, ...number[]]`)
+    UnevaluableType,
+
+    /// Key type which is not a (union of) string or number literal(s), `string` or `number`
+    ///   (i.e. `boolean` of `Record<boolean, number>`)
+    NonKeyType,
+
+    /// Picked or indexed key which is not a field of the record type
+    UnknownField(String),
+
+    /// Value queried by `typeof` which is not declared or imported
+    UnknownValue(String),
+
+    /// Referenced type which is not declared or imported (i.e. `Promise` without the ES2015 library)
+    UnknownType(String),
+
+    /// Namespace of a qualified name which is not declared, imported or exported
+    ///   (i.e. `NodeJS` of `NodeJS.Timeout` without the Node typings)
+    UnknownNamespace(String),
+
+    /// Enum member initializer which is not a constant expression
+    ///   (i.e. `A = foo()` or a string member followed by a member without an initializer)
+    NonConstantEnumMember(String),
+}
+
+#[allow(dead_code)]
+#[derive(Debug)]
+pub enum TsConfigError {
+    IoError(PathBuf, IoError),
+    JsonError(PathBuf, JsonError),
+    CircularExtends(PathBuf),
 }
 
 #[derive(Debug)]
@@ -40,4 +98,12 @@ pub enum EmitError {
     IoError(PathBuf, IoError),
     JsonError(PathBuf, JsonError),
     Misc(PathBuf, String),
+
+    /// Root binding `global` without an `export as namespace X` in the root module
+    #[allow(dead_code)]
+    MissingUmdGlobal(PathBuf),
+
+    /// Selected global (see `GlobalSelection`) not declared by any script or global augmentation
+    #[allow(dead_code)]
+    GlobalNotFound(PathBuf, String),
 }
@@ -3,6 +3,7 @@ use std::collections::{HashMap, HashSet};
 use indexmap::IndexSet;
 
 use swc_atoms::JsWord;
+use swc_common::{BytePos, Span, SyntaxContext};
 
 use super::bind_graph_init::{Export, Import, ModuleGraph, ModuleNode};
 use super::error::*;
@@ -11,10 +12,12 @@ use super::structures::CanonPath;
 /// Modify graph such that import/export edges point directly towards the rooted item
 ///
 /// POST-CONDITION:
-///   All Import::Named edges transformed into Import::NamedType and/or Import::NamedValue
-///   All Export::Named edges transformed into Export::NamedType and/or Export::NamedValue
-///   All Export::All edges are transformed into a set of Export::NamedType and/or
-///      Export::NamedValue
+///   All Import::Named edges transformed into Import::NamedType, Import::NamedValue,
+///      and/or Import::NamedNamespace
+///   All Export::Named edges transformed into Export::NamedType, Export::NamedValue,
+///      and/or Export::NamedNamespace
+///   All Export::All edges are transformed into a set of Export::NamedType,
+///      Export::NamedValue, and/or Export::NamedNamespace
 ///   All new edges point directly to a rooted value
 pub fn reduce(mut graph: ModuleGraph) -> Result<ModuleGraph, BindGenError> {
     let scc_session = SccSession::init(&graph.nodes, &graph.export_edges);
@@ -87,10 +90,20 @@ pub fn reduce(mut graph: ModuleGraph) -> Result<ModuleGraph, BindGenError> {
 
 type Resolution = Option<(CanonPath, JsWord)>;
 
+/// Namespace re-exported or imported by `module_path` which its source does not export
+fn unresolved_namespace(module_path: &CanonPath, src_key: &JsWord) -> BindGenError {
+    BindGenError {
+        kind: BindGenErrorKind::TypeError(TypeError::UnknownNamespace(src_key.to_string())),
+        module_path: module_path.as_path().to_owned(),
+        span: Span::new(BytePos(0), BytePos(0), SyntaxContext::empty()),
+    }
+}
+
 #[derive(Clone, Copy)]
 enum ResolutionKind {
     Value,
     Type,
+    Namespace,
 }
 
 struct ResolutionSession<'a> {
@@ -168,6 +181,26 @@ impl<'a> ResolutionSession<'a> {
                         }
                     }
 
+                    Export::NamedNamespace {
+                        ref source,
+                        ref src_key,
+                        ref export_key,
+                    } => {
+                        let resolution = self.traverse(source, src_key, ResolutionKind::Namespace);
+
+                        match resolution {
+                            Some((path, key)) => {
+                                new_exports.push(Export::NamedNamespace {
+                                    source: path,
+                                    src_key: key,
+                                    export_key: export_key.clone(),
+                                });
+                            }
+
+                            None => return Err(unresolved_namespace(canon_path, src_key)),
+                        }
+                    }
+
                     Export::Named {
                         ref source,
                         ref src_key,
@@ -178,7 +211,13 @@ impl<'a> ResolutionSession<'a> {
                         let value_resolution =
                             self.traverse(source, src_key, ResolutionKind::Value);
 
-                        if type_resolution.is_none() && value_resolution.is_none() {
+                        let namespace_resolution =
+                            self.traverse(source, src_key, ResolutionKind::Namespace);
+
+                        if type_resolution.is_none()
+                            && value_resolution.is_none()
+                            && namespace_resolution.is_none()
+                        {
                             todo!(
                                 "Error in [{}]: import not resolved [{}]:{} (as {})",
                                 canon_path.as_path().display(),
@@ -203,6 +242,14 @@ impl<'a> ResolutionSession<'a> {
                                 export_key: export_key.clone(),
                             });
                         }
+
+                        if let Some((path, key)) = namespace_resolution {
+                            new_exports.push(Export::NamedNamespace {
+                                source: path,
+                                src_key: key,
+                                export_key: export_key.clone(),
+                            });
+                        }
                     }
 
                     Export::All { ref source } => {
@@ -261,6 +308,24 @@ impl<'a> ResolutionSession<'a> {
                         }
                     }
 
+                    Import::NamedNamespace {
+                        ref source,
+                        ref src_key,
+                    } => {
+                        let resolution = self.traverse(source, src_key, ResolutionKind::Namespace);
+
+                        match resolution {
+                            Some((path, key)) => {
+                                new_imports.push(Import::NamedNamespace {
+                                    source: path,
+                                    src_key: key,
+                                });
+                            }
+
+                            None => return Err(unresolved_namespace(canon_path, src_key)),
+                        }
+                    }
+
                     Import::Named {
                         ref source,
                         ref src_key,
@@ -270,7 +335,13 @@ impl<'a> ResolutionSession<'a> {
                         let value_resolution =
                             self.traverse(source, src_key, ResolutionKind::Value);
 
-                        if type_resolution.is_none() && value_resolution.is_none() {
+                        let namespace_resolution =
+                            self.traverse(source, src_key, ResolutionKind::Namespace);
+
+                        if type_resolution.is_none()
+                            && value_resolution.is_none()
+                            && namespace_resolution.is_none()
+                        {
                             todo!("Error: import not resolved");
                         }
 
@@ -287,6 +358,13 @@ impl<'a> ResolutionSession<'a> {
                                 src_key: key,
                             });
                         }
+
+                        if let Some((path, key)) = namespace_resolution {
+                            new_imports.push(Import::NamedNamespace {
+                                source: path,
+                                src_key: key,
+                            });
+                        }
                     }
                 }
             }
@@ -338,6 +416,18 @@ impl<'a> ResolutionSession<'a> {
                     }
                 }
 
+                Export::NamedNamespace {
+                    ref source,
+                    ref src_key,
+                    ref export_key,
+                } => {
+                    if let ResolutionKind::Namespace = kind {
+                        if export_key == key {
+                            worklist.push((source, src_key));
+                        }
+                    }
+                }
+
                 Export::Named {
                     ref source,
                     ref src_key,
@@ -386,6 +476,12 @@ impl<'a> ResolutionSession<'a> {
                         return Some((next_path.clone(), next_key.clone()));
                     }
                 }
+
+                ResolutionKind::Namespace => {
+                    if node.is_rooted_namespace(next_key) {
+                        return Some((next_path.clone(), next_key.clone()));
+                    }
+                }
             }
 
             self.worklist_exports(&mut worklist, next_path, next_key, kind);
@@ -399,6 +495,7 @@ impl<'a> ResolutionSession<'a> {
 struct ExportSet {
     types: HashSet<JsWord>,
     values: HashSet<JsWord>,
+    namespaces: HashSet<JsWord>,
     nebulous: HashSet<JsWord>,
 }
 
@@ -407,6 +504,7 @@ impl ExportSet {
         ExportSet {
             types: HashSet::new(),
             values: HashSet::new(),
+            namespaces: HashSet::new(),
             nebulous: HashSet::new(),
         }
     }
@@ -420,6 +518,10 @@ impl ExportSet {
             self.values.insert(v.clone());
         }
 
+        for n in other.namespaces.iter() {
+            self.namespaces.insert(n.clone());
+        }
+
         for n in other.nebulous.iter() {
             self.nebulous.insert(n.clone());
         }
@@ -429,6 +531,11 @@ impl ExportSet {
         ExportSet {
             types: self.types.difference(&other.types).cloned().collect(),
             values: self.values.difference(&other.values).cloned().collect(),
+            namespaces: self
+                .namespaces
+                .difference(&other.namespaces)
+                .cloned()
+                .collect(),
             nebulous: self.nebulous.difference(&other.nebulous).cloned().collect(),
         }
     }
@@ -454,6 +561,14 @@ impl<'a> ExpansionSession<'a> {
             set.types.insert(t.clone());
         }
 
+        for n in node.rooted_export_namespaces.iter() {
+            set.namespaces.insert(n.clone());
+        }
+
+        for n in node.rooted_export_modules.keys() {
+            set.namespaces.insert(n.clone());
+        }
+
         let edges = self.original_exports.get(path).unwrap();
 
         for edge in edges.iter() {
@@ -466,6 +581,10 @@ impl<'a> ExpansionSession<'a> {
                     set.values.insert(export_key.clone());
                 }
 
+                Export::NamedNamespace { ref export_key, .. } => {
+                    set.namespaces.insert(export_key.clone());
+                }
+
                 Export::Named { ref export_key, .. } => {
                     set.nebulous.insert(export_key.clone());
                 }
@@ -568,6 +687,14 @@ impl<'a> ExpansionSession<'a> {
                     });
                 }
 
+                for export_key in difference.namespaces.into_iter() {
+                    expanded.push(Export::NamedNamespace {
+                        source: scc_root.clone(),
+                        src_key: export_key.clone(),
+                        export_key,
+                    });
+                }
+
                 for export_key in difference.nebulous.into_iter() {
                     expanded.push(Export::Named {
                         source: scc_root.clone(),
@@ -650,7 +777,12 @@ impl<'a> SccSession<'a> {
         for (_, scc) in self.results.iter_mut() {
             for scc_member in scc.set.iter() {
                 for edge in self.original_exports.get(scc_member).unwrap().iter() {
-                    let edge_endpoint = edge.export_source();
+                    // Only Export::All edges export the entire export set of their source
+                    let edge_endpoint = match edge {
+                        Export::All { .. } => edge.export_source(),
+
+                        _ => continue,
+                    };
 
                     if scc.set.contains(edge_endpoint) == false {
                         scc.outgoing_edges.insert(edge_endpoint);
@@ -737,21 +869,17 @@ impl<'a> SccSession<'a> {
                 outgoing_edges: HashSet::new(),
             };
 
-            let work_stack = {
-                let mut tmp = Vec::new();
-                std::mem::swap(&mut tmp, &mut self.work_stack);
-                tmp
-            };
+            // Only the vertices above the current vertex on the stack belong to its SCC
+            loop {
+                let path = self.work_stack.pop().expect("Current vertex not on stack");
+                self.module_scc_map.insert(path, scc_id);
+                self.vertex_on_stack.remove(path);
+                scc.set.insert(path);
 
-            for path in work_stack.into_iter() {
-                if path != current_path {
-                    self.module_scc_map.insert(path, scc_id);
-                    self.vertex_on_stack.remove(path);
-                    scc.set.insert(path);
+                if path == current_path {
+                    break;
                 }
             }
-            self.module_scc_map.insert(current_path, scc_id);
-            scc.set.insert(current_path);
             self.results.insert(scc_id, scc);
 
             self.scc_index += 1;
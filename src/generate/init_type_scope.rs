@@ -1,19 +1,58 @@
 use std::collections::HashMap;
 
+use swc_atoms::JsWord;
 use swc_ecma_ast::*;
 
-use super::bind_init::ModuleData;
+use super::bind_common;
+use super::bind_graph_init::ModuleGraph;
+use super::bind_init::{ModuleData, ModuleKind, ParsedModuleCache as ModuleCache};
 
 use super::error::*;
-use super::structures::{CanonPath, ItemState, Scope};
+use super::structures::{CanonPath, ItemState, TypeScope};
 
-pub fn init(data: &ModuleData) -> Result<Scope<ItemState>, BindGenError> {
+/// Assumes the graph is reduced (see `graph_reduce::reduce()`)
+pub fn init(
+    cache: &ModuleCache,
+    graph: &ModuleGraph,
+    data: &ModuleData,
+) -> Result<TypeScope, BindGenError> {
     let mut init_session = InitSession {
         path: &data.path,
         dependency_map: &data.dependencies,
-        scope: Scope::new(),
+        graph,
+        scope: TypeScope::new(),
     };
 
+    // Namespaces see everything in scope of the enclosing module
+    if let ModuleKind::Namespace = data.kind {
+        let enclosing_path = data
+            .path
+            .enclosing()
+            .expect("Namespace without an enclosing module");
+        let enclosing_scope = init(cache, graph, cache.get(&enclosing_path))?;
+
+        for (symbol, item_state) in enclosing_scope.types.iter() {
+            let item_state = match item_state {
+                ItemState::Rooted => ItemState::Imported {
+                    source: enclosing_path.clone(),
+                    src_key: symbol.clone(),
+                    as_key: symbol.clone(),
+                },
+
+                imported => imported.clone(),
+            };
+
+            init_session.scope.types.insert(symbol.clone(), item_state);
+        }
+
+        for (symbol, namespace) in enclosing_scope.namespaces.iter() {
+            init_session
+                .scope
+                .namespaces
+                .insert(symbol.clone(), namespace.clone());
+        }
+    }
+
     for module_item in data.module_ast.body.iter() {
         init_session.process_module_item(module_item)?;
     }
@@ -21,10 +60,57 @@ pub fn init(data: &ModuleData) -> Result<Scope<ItemState>, BindGenError> {
     Ok(init_session.scope)
 }
 
+/// Type scopes of every module in the cache (see `init()`)
+///   Initialized once so that named types of other modules can be resolved without re-scanning them
+pub fn init_all(
+    cache: &ModuleCache,
+    graph: &ModuleGraph,
+) -> Result<HashMap<CanonPath, TypeScope>, BindGenError> {
+    cache
+        .iter()
+        .map(|(path, data)| Ok((path.clone(), init(cache, graph, data)?)))
+        .collect()
+}
+
+/// Types and namespaces declared in the global scope
+///   (i.e. by scripts and global augmentations)
+///
+/// Consulted after the scope of a module (global declarations are shadowed by module declarations)
+///   The first global declaration of a name takes precedence
+pub fn init_globals(cache: &ModuleCache) -> TypeScope {
+    let mut scope = TypeScope::new();
+
+    for global_module in cache.globals() {
+        for decl in bind_common::script_decls(&global_module.module_ast) {
+            if let Some(symbol) = decl_type_symbol(decl) {
+                if scope.types.get(&symbol).is_none() {
+                    let item_state = ItemState::Imported {
+                        source: global_module.path.clone(),
+                        src_key: symbol.clone(),
+                        as_key: symbol.clone(),
+                    };
+
+                    scope.types.insert(symbol, item_state);
+                }
+            }
+
+            if let Some(symbol) = decl_namespace_symbol(decl) {
+                if scope.namespaces.get(&symbol).is_none() {
+                    let namespace = global_module.path.nested(symbol.clone());
+                    scope.namespaces.insert(symbol, namespace);
+                }
+            }
+        }
+    }
+
+    scope
+}
+
 struct InitSession<'a> {
     path: &'a CanonPath,
     dependency_map: &'a HashMap<String, CanonPath>,
-    scope: Scope<ItemState>,
+    graph: &'a ModuleGraph,
+    scope: TypeScope,
 }
 
 impl<'a> InitSession<'a> {
@@ -56,8 +142,39 @@ impl<'a> InitSession<'a> {
                 Ok(())
             }
 
+            // `import x = require("...")`
+            //   The module value is the default export of the module under CommonJS interop
+            ModuleDecl::TsImportEquals(ref import) => {
+                let src =
+                    bind_common::import_equals_specifier(import).expect("Caught in bind init");
+                let src_canon_path: &CanonPath = get_dep_src!(self, src);
+                let local = import.id.sym.clone();
+
+                self.scope
+                    .namespaces
+                    .insert(local.clone(), src_canon_path.clone());
+
+                let item_state = ItemState::Imported {
+                    source: src_canon_path.clone(),
+                    src_key: bind_common::default_export_key(),
+                    as_key: local.clone(),
+                };
+
+                self.scope.types.insert(local, item_state);
+
+                Ok(())
+            }
+
             ModuleDecl::ExportDecl(ExportDecl { ref decl, .. }) => self.process_decl(decl),
 
+            ModuleDecl::ExportDefaultDecl(ExportDefaultDecl { ref decl, .. }) => {
+                if let Some(symbol) = default_decl_type_symbol(decl) {
+                    self.scope.types.insert(symbol, ItemState::Rooted);
+                }
+
+                Ok(())
+            }
+
             _ => Ok(()),
         }
     }
@@ -67,7 +184,7 @@ impl<'a> InitSession<'a> {
         source: &CanonPath,
         spec: &ImportSpecifier,
     ) -> Result<(), BindGenError> {
-        match spec {
+        let (src_key, local) = match spec {
             ImportSpecifier::Named(ref named) => {
                 let src_key = named
                     .imported
@@ -75,51 +192,86 @@ impl<'a> InitSession<'a> {
                     .map(|export_key| export_key.sym.clone())
                     .unwrap_or(named.local.sym.clone());
 
-                let as_key = named.local.sym.clone();
+                (src_key, &named.local)
+            }
 
-                let item_state = ItemState::Imported {
-                    source: source.clone(),
-                    src_key,
-                    as_key,
-                };
+            ImportSpecifier::Default(ref def) => (bind_common::default_export_key(), &def.local),
 
-                let import_key = named.local.sym.clone();
-                self.scope.insert(import_key, item_state);
+            ImportSpecifier::Namespace(ref namespace) => {
+                self.scope
+                    .namespaces
+                    .insert(namespace.local.sym.clone(), source.clone());
 
-                Ok(())
+                return Ok(());
             }
+        };
+
+        let as_key = local.sym.clone();
 
-            ImportSpecifier::Default(def) => Err(BindGenError {
-                module_path: self.path.as_path().to_owned(),
-                kind: BindGenErrorKind::UnsupportedFeature(UnsupportedFeature::DefaultImport),
-                span: def.span,
-            }),
-
-            ImportSpecifier::Namespace(namespace) => Err(BindGenError {
-                module_path: self.path.as_path().to_owned(),
-                kind: BindGenErrorKind::UnsupportedFeature(UnsupportedFeature::DefaultImport),
-                span: namespace.span,
-            }),
+        let import_key = local.sym.clone();
+
+        if let Some(namespace) = self.graph.exported_namespace(source, &src_key) {
+            self.scope.namespaces.insert(import_key.clone(), namespace);
         }
+
+        let item_state = ItemState::Imported {
+            source: source.clone(),
+            src_key,
+            as_key,
+        };
+
+        self.scope.types.insert(import_key, item_state);
+
+        Ok(())
     }
 
     fn process_decl(&mut self, decl: &Decl) -> Result<(), BindGenError> {
-        let symbol = match decl {
-            Decl::Class(ClassDecl { ref ident, .. }) => Some(ident.sym.clone()),
+        if let Some(symbol) = decl_type_symbol(decl) {
+            self.scope.types.insert(symbol, ItemState::Rooted);
+        }
 
-            Decl::TsInterface(TsInterfaceDecl { id, .. }) => Some(id.sym.clone()),
+        if let Some(symbol) = decl_namespace_symbol(decl) {
+            let namespace = self.path.nested(symbol.clone());
+            self.scope.namespaces.insert(symbol, namespace);
+        }
 
-            Decl::TsTypeAlias(TsTypeAliasDecl { id, .. }) => Some(id.sym.clone()),
+        Ok(())
+    }
+}
 
-            Decl::TsEnum(TsEnumDecl { id, .. }) => Some(id.sym.clone()),
+fn decl_type_symbol(decl: &Decl) -> Option<JsWord> {
+    match decl {
+        Decl::Class(ClassDecl { ref ident, .. }) => Some(ident.sym.clone()),
 
-            _ => None,
-        };
+        Decl::TsInterface(TsInterfaceDecl { id, .. }) => Some(id.sym.clone()),
 
-        if let Some(symbol) = symbol {
-            self.scope.insert(symbol, ItemState::Rooted);
-        }
+        Decl::TsTypeAlias(TsTypeAliasDecl { id, .. }) => Some(id.sym.clone()),
 
-        Ok(())
+        Decl::TsEnum(TsEnumDecl { id, .. }) => Some(id.sym.clone()),
+
+        _ => None,
+    }
+}
+
+fn default_decl_type_symbol(decl: &DefaultDecl) -> Option<JsWord> {
+    match decl {
+        DefaultDecl::Class(ClassExpr { ref ident, .. }) => ident.as_ref().map(|i| i.sym.clone()),
+
+        DefaultDecl::TsInterfaceDecl(TsInterfaceDecl { ref id, .. }) => Some(id.sym.clone()),
+
+        DefaultDecl::Fn(..) => None,
+    }
+}
+
+fn decl_namespace_symbol(decl: &Decl) -> Option<JsWord> {
+    match decl {
+        // Global augmentations are only visible through the global scope
+        Decl::TsModule(TsModuleDecl {
+            id: TsModuleName::Ident(ref ident),
+            global: false,
+            ..
+        }) => Some(ident.sym.clone()),
+
+        _ => None,
     }
 }
@@ -139,6 +139,7 @@ impl<W: Write> Visit for PrettyPrinter<W> {
 
         match n.op {
             BinaryOp::EqEqEq => self.write("==="),
+            BinaryOp::Add => self.write(" + "),
 
             _ => todo!("binop: {:#?}", n.op),
         }
@@ -146,6 +147,39 @@ impl<W: Write> Visit for PrettyPrinter<W> {
         self.visit_expr(&*n.right, &());
     }
 
+    fn visit_paren_expr(&mut self, n: &ParenExpr, _: &dyn Node) {
+        self.write("(");
+        self.visit_expr(&n.expr, &());
+        self.write(")");
+    }
+
+    fn visit_new_expr(&mut self, n: &NewExpr, _: &dyn Node) {
+        self.write("new ");
+        self.visit_expr(&n.callee, &());
+
+        self.write("(");
+
+        let args = n.args.as_ref().map(|args| &args[..]).unwrap_or(&[]);
+        let last = args.len();
+        for (index, arg) in args.iter().enumerate() {
+            self.visit_expr_or_spread(arg, &());
+
+            if index + 1 != last {
+                self.write(",");
+            }
+        }
+
+        self.write(")");
+    }
+
+    fn visit_cond_expr(&mut self, n: &CondExpr, _: &dyn Node) {
+        self.visit_expr(&n.test, &());
+        self.write("?");
+        self.visit_expr(&n.cons, &());
+        self.write(":");
+        self.visit_expr(&n.alt, &());
+    }
+
     fn visit_assign_expr(&mut self, n: &AssignExpr, _: &dyn Node) {
         self.visit_pat_or_expr(&n.left, &());
 
@@ -174,12 +208,25 @@ impl<W: Write> Visit for PrettyPrinter<W> {
         self.write(")");
     }
 
+    fn visit_expr_or_spread(&mut self, n: &ExprOrSpread, _: &dyn Node) {
+        if n.spread.is_some() {
+            self.write("...");
+        }
+
+        self.visit_expr(&n.expr, &());
+    }
+
     fn visit_pat(&mut self, p: &Pat, _: &dyn Node) {
         match p {
             Pat::Ident(ref i) => self.visit_ident(i, &()),
 
             Pat::Expr(ref e) => self.visit_expr(e, &()),
 
+            Pat::Rest(RestPat { ref arg, .. }) => {
+                self.write("...");
+                self.visit_pat(arg, &());
+            }
+
             Pat::Array(ArrayPat { ref elems, .. }) => {
                 self.write("[");
                 match elems.len() {
@@ -218,9 +265,14 @@ impl<W: Write> Visit for PrettyPrinter<W> {
         }
 
         self.write("(");
-        for param in n.function.params.iter() {
+        let last = n.function.params.len();
+        for (index, param) in n.function.params.iter().enumerate() {
             self.visit_pat(&param.pat, &());
-            self.write(",");
+
+            // Rest parameters cannot have a trailing comma
+            if index + 1 != last {
+                self.write(",");
+            }
         }
         self.write(") {\n");
 
@@ -277,7 +329,7 @@ impl<W: Write> Visit for PrettyPrinter<W> {
             }
         });
 
-        self.write("}");
+        self.indent_write("}");
     }
 
     fn visit_return_stmt(&mut self, n: &ReturnStmt, _: &dyn Node) {
@@ -292,6 +344,12 @@ impl<W: Write> Visit for PrettyPrinter<W> {
         }
     }
 
+    fn visit_throw_stmt(&mut self, n: &ThrowStmt, _: &dyn Node) {
+        self.indent_write("throw ");
+        self.visit_expr(&n.arg, &());
+        self.write(";\n");
+    }
+
     fn visit_expr_stmt(&mut self, e: &ExprStmt, _: &dyn Node) {
         self.emit_indent();
         self.visit_expr(&*e.expr, &());
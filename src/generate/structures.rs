@@ -24,6 +24,26 @@ impl<T> Scope<T> {
     pub fn get(&self, key: &JsWord) -> Option<&T> {
         self.map.get(key)
     }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&JsWord, &T)> {
+        self.map.iter()
+    }
+}
+
+/// Types and namespaces visible within a module
+///   Namespaces are mapped to the (nested) module they resolve to
+pub struct TypeScope {
+    pub types: Scope<ItemState>,
+    pub namespaces: Scope<CanonPath>,
+}
+
+impl TypeScope {
+    pub fn new() -> Self {
+        TypeScope {
+            types: Scope::new(),
+            namespaces: Scope::new(),
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -48,23 +68,90 @@ pub enum ItemState {
     Rooted,
 }
 
+/// Nested name of global augmentations (see `CanonPath::global_augmentation()`)
+///   Not an identifier so it cannot collide with a namespace
+const GLOBAL_AUGMENTATION: &str = "@global";
+
+/// Canonical path of a module
+///   Ambient modules (i.e. `declare module "fs" { }`) are declared within a file
+///   Namespaces are modules nested within a file or ambient module
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct CanonPath(PathBuf);
+pub struct CanonPath {
+    path: PathBuf,
+    ambient: Option<JsWord>,
+    nested: Vec<JsWord>,
+}
 
 impl CanonPath {
+    /// Path of the file declaring the module
     pub fn as_path(&self) -> &std::path::Path {
-        &self.0
+        &self.path
+    }
+
+    /// The module is the file itself (i.e. not an ambient module or namespace)
+    pub fn is_file(&self) -> bool {
+        self.ambient.is_none() && self.nested.is_empty()
+    }
+
+    /// Ambient module `specifier` declared within this file
+    pub fn ambient(&self, specifier: JsWord) -> CanonPath {
+        CanonPath {
+            path: self.path.clone(),
+            ambient: Some(specifier),
+            nested: Vec::new(),
+        }
+    }
+
+    /// Module of the namespace `name` declared within this module
+    pub fn nested(&self, name: JsWord) -> CanonPath {
+        let mut nested = self.nested.clone();
+        nested.push(name);
+
+        CanonPath {
+            path: self.path.clone(),
+            ambient: self.ambient.clone(),
+            nested,
+        }
+    }
+
+    /// Global augmentation (i.e. `declare global { }`) declared within this module
+    ///   Its declarations are global but everything in scope of this module is in its scope
+    pub fn global_augmentation(&self) -> CanonPath {
+        self.nested(JsWord::from(GLOBAL_AUGMENTATION))
+    }
+
+    pub fn is_global_augmentation(&self) -> bool {
+        self.nested
+            .last()
+            .map(|name| name == GLOBAL_AUGMENTATION)
+            .unwrap_or(false)
+    }
+
+    /// Module declaring this namespace
+    ///   None if this module is a file
+    pub fn enclosing(&self) -> Option<CanonPath> {
+        let (_, enclosing) = self.nested.split_last()?;
+
+        Some(CanonPath {
+            path: self.path.clone(),
+            ambient: self.ambient.clone(),
+            nested: enclosing.to_vec(),
+        })
     }
 
     #[cfg(test)]
     pub fn mock(b: PathBuf) -> Self {
-        CanonPath(b)
+        CanonPath {
+            path: b,
+            ambient: None,
+            nested: Vec::new(),
+        }
     }
 }
 
 impl From<CanonPath> for PathBuf {
     fn from(p: CanonPath) -> PathBuf {
-        p.0
+        p.path
     }
 }
 
@@ -72,7 +159,11 @@ impl std::convert::TryFrom<PathBuf> for CanonPath {
     type Error = std::io::Error;
 
     fn try_from(p: PathBuf) -> Result<Self, Self::Error> {
-        p.canonicalize().map(|p| CanonPath(p))
+        p.canonicalize().map(|p| CanonPath {
+            path: p,
+            ambient: None,
+            nested: Vec::new(),
+        })
     }
 }
 
@@ -80,6 +171,10 @@ impl<'a> std::convert::TryFrom<&'a std::path::Path> for CanonPath {
     type Error = std::io::Error;
 
     fn try_from(p: &std::path::Path) -> Result<Self, Self::Error> {
-        p.canonicalize().map(|p| CanonPath(p))
+        p.canonicalize().map(|p| CanonPath {
+            path: p,
+            ambient: None,
+            nested: Vec::new(),
+        })
     }
 }
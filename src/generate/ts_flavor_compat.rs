@@ -11,10 +11,19 @@ pub enum CompatError {
     TupleType,
     NeverType,
     AnyType,
+    UnionType,
+    OptionalType,
+    DictType,
+    CallableType,
+    ConstructType,
     InterfaceType,
+    InterfaceExtension,
     TypeLiteral,
     LiteralType,
+    EnumType,
+    ClassType,
     UndefinedType,
+    Generics,
 }
 
 macro_rules! basic_check {
@@ -37,8 +46,17 @@ pub fn compatible(detected: &TsFeatures, target: &TsFeatures) -> Result<(), Vec<
     basic_check!(detected, target, never_type       @ LTE [CompatError::NeverType => errors]);
     basic_check!(detected, target, undefined_type   @ LTE [CompatError::UndefinedType => errors]);
     basic_check!(detected, target, any_type         @ LTE [CompatError::AnyType => errors]);
+    basic_check!(detected, target, union_type       @ LTE [CompatError::UnionType => errors]);
+    basic_check!(detected, target, optional_type    @ LTE [CompatError::OptionalType => errors]);
+    basic_check!(detected, target, dict_type        @ LTE [CompatError::DictType => errors]);
+    basic_check!(detected, target, callable_type    @ LTE [CompatError::CallableType => errors]);
+    basic_check!(detected, target, construct_type   @ LTE [CompatError::ConstructType => errors]);
     basic_check!(detected, target, type_literal     @ LTE [CompatError::TypeLiteral => errors]);
     basic_check!(detected, target, literal_type     @ LTE [CompatError::LiteralType => errors]);
+    basic_check!(detected, target, explicit_enum_type @ LTE [CompatError::EnumType => errors]);
+    basic_check!(detected, target, interface_extension @ LTE [CompatError::InterfaceExtension => errors]);
+    basic_check!(detected, target, class_type       @ LTE [CompatError::ClassType => errors]);
+    basic_check!(detected, target, generics         @ LTE [CompatError::Generics => errors]);
 
     if errors.len() == 0 {
         Ok(())
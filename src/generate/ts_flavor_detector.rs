@@ -3,7 +3,7 @@ use swc_atoms::JsWord;
 use crate::ts::{TsFeatures, TsFeaturesBuilder};
 
 use super::structures::CanonPath;
-use super::type_structs::{ClassType, FnType, Type};
+use super::type_structs::{CallableType, ClassType, DataUnion, FnType, Type};
 use super::typify_graph::{ModuleGraph, ModuleNode};
 
 macro_rules! basic_scan {
@@ -52,22 +52,36 @@ fn scan_type<'a, 'b, 'c>(
 
         Type::Fn(FnType {
             ref params,
+            ref rest_param,
             ref return_type,
+            ..
         }) => {
             for param_type in params {
                 scan_type(builder, graph, param_type, parent_types);
             }
 
+            // Rest arguments are passed as Pyret lists or raw arrays
+            if let Some(ref elem_type) = rest_param {
+                scan_type(builder, graph, elem_type, parent_types);
+            }
+
             scan_type(builder, graph, &*return_type, parent_types);
 
             builder.fn_type(true);
         }
 
+        Type::Overloaded(ref signatures) => {
+            for signature in signatures {
+                scan_type(builder, graph, signature, parent_types);
+            }
+        }
+
         Type::Interface {
             ref name,
             ref origin,
+            ref type_params,
             ref fields,
-            ..
+            ref extends,
         } => {
             parent_types.push((name, origin));
             for field_type in fields.values() {
@@ -75,9 +89,72 @@ fn scan_type<'a, 'b, 'c>(
             }
             parent_types.pop();
 
+            if !type_params.is_empty() {
+                builder.generics(true);
+            }
+
+            // Inherited fields are scanned as fields
+            if !extends.is_empty() {
+                builder.interface_extension(true);
+            }
+
             builder.interfaces(true);
         }
 
+        Type::Class(ClassType {
+            ref name,
+            ref origin,
+            ref type_params,
+            ref constructors,
+            ref members,
+            ref static_members,
+            ref super_class,
+            ref implements,
+            ..
+        }) => {
+            parent_types.push((name, origin));
+            for constructor in constructors {
+                for param_type in constructor.params.iter() {
+                    scan_type(builder, graph, param_type, parent_types);
+                }
+
+                if let Some(ref elem_type) = constructor.rest_param {
+                    scan_type(builder, graph, elem_type, parent_types);
+                }
+            }
+
+            for member in members.values().chain(static_members.values()) {
+                scan_type(builder, graph, &member.typ, parent_types);
+            }
+            parent_types.pop();
+
+            if !type_params.is_empty() {
+                builder.generics(true);
+            }
+
+            if super_class.is_some() || !implements.is_empty() {
+                builder.interface_extension(true);
+            }
+
+            builder.class_type(true);
+        }
+
+        Type::TypeVar(..) => basic_scan!(builder => generics),
+
+        Type::TyApp { ref base, ref args } => {
+            scan_type(builder, graph, base, parent_types);
+            for arg in args {
+                scan_type(builder, graph, arg, parent_types);
+            }
+
+            builder.generics(true);
+        }
+
+        Type::Forall { ref body, .. } => {
+            scan_type(builder, graph, body, parent_types);
+            builder.generics(true);
+        }
+
         Type::Literal { ref fields } => {
             for field_type in fields.values() {
                 scan_type(builder, graph, field_type, parent_types);
@@ -86,11 +163,86 @@ fn scan_type<'a, 'b, 'c>(
             builder.type_literal(true);
         }
 
+        Type::Callable(CallableType {
+            ref call_signatures,
+            ref construct_signatures,
+            ref fields,
+        }) => {
+            for signature in call_signatures.iter().chain(construct_signatures.iter()) {
+                scan_type(builder, graph, signature, parent_types);
+            }
+
+            for field_type in fields.values() {
+                scan_type(builder, graph, field_type, parent_types);
+            }
+
+            if !construct_signatures.is_empty() {
+                builder.construct_type(true);
+            }
+
+            builder.callable_type(true);
+        }
+
+        Type::Tuple(ref elem_types) => {
+            for elem_type in elem_types {
+                scan_type(builder, graph, elem_type, parent_types);
+            }
+
+            builder.tuple_type(true);
+        }
+
+        Type::Optional(ref typ) => {
+            scan_type(builder, graph, typ, parent_types);
+            builder.optional_type(true);
+        }
+
+        Type::Dict(ref value_type) => {
+            scan_type(builder, graph, value_type, parent_types);
+            builder.dict_type(true);
+        }
+
+        Type::Union(ref members) => {
+            for member in members {
+                scan_type(builder, graph, member, parent_types);
+            }
+
+            builder.union_type(true);
+        }
+
+        Type::LitUnion(..) => {
+            builder.literal_type(true);
+            builder.union_type(true);
+        }
+
+        Type::Lit(..) => basic_scan!(builder => literal_type),
+
+        Type::Enum(..) => basic_scan!(builder => explicit_enum_type),
+
+        Type::DataUnion(DataUnion { ref variants, .. }) => {
+            for variant in variants {
+                for (_, field_type) in variant.fields.iter() {
+                    scan_type(builder, graph, field_type, parent_types);
+                }
+            }
+
+            // Discriminants are literal types
+            builder.literal_type(true);
+            builder.union_type(true);
+        }
+
         Type::UnsizedArray(ref elem_type) => {
             scan_type(builder, graph, elem_type, parent_types);
             builder.array_type(true);
         }
 
+        Type::Unevaluated { ref args, .. } => {
+            for arg in args {
+                scan_type(builder, graph, arg, parent_types);
+            }
+
+            builder.any_type(true);
+        }
+
         Type::Number => basic_scan!(builder => number_type),
         Type::Boolean => basic_scan!(builder => boolean_type),
         Type::String => basic_scan!(builder => string_type),
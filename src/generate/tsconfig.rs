@@ -0,0 +1,278 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde_json::{Map, Value};
+
+use super::bind_common::{is_path_specifier, NODE_MODULES};
+use super::error::TsConfigError;
+
+const TSCONFIG_FILE_NAME: &str = "tsconfig.json";
+
+/// Module resolution settings from a project's `tsconfig.json`
+///   https://www.typescriptlang.org/tsconfig#Module_Resolution_Options_6174
+///
+/// All paths are absolute
+#[derive(Debug, Clone, Default)]
+pub struct CompilerOptions {
+    pub base_url: Option<PathBuf>,
+
+    /// `paths` patterns sorted by pattern (`serde_json` does not keep the order of object keys)
+    ///   Substitutions are relative to `paths_base`
+    pub paths: Vec<(String, Vec<String>)>,
+
+    /// `baseUrl` if it is set. Otherwise, the directory of the `tsconfig.json` declaring `paths`
+    pub paths_base: Option<PathBuf>,
+
+    pub type_roots: Option<Vec<PathBuf>>,
+}
+
+impl CompilerOptions {
+    /// Load the compiler options of a `tsconfig.json` (following `extends`)
+    pub fn load(path: &Path) -> Result<CompilerOptions, TsConfigError> {
+        let mut options = CompilerOptions::default();
+        let mut visited = Vec::new();
+
+        options.load_file(path, &mut visited)?;
+
+        Ok(options)
+    }
+
+    /// Search for a `tsconfig.json` in the directory of the root module and its ancestors
+    ///   The root module is canonicalized first so relative input paths still reach the ancestors
+    pub fn discover(root_module: &Path) -> Option<PathBuf> {
+        root_module
+            .canonicalize()
+            .ok()?
+            .parent()?
+            .ancestors()
+            .map(|dir| dir.join(TSCONFIG_FILE_NAME))
+            .find(|candidate| candidate.is_file())
+    }
+
+    /// The substitutions of the most specific `paths` pattern matching the specifier
+    ///   Exact matches take precedence over wildcard patterns
+    ///   Of the wildcard patterns, the pattern with the longest prefix is chosen
+    ///   (patterns with prefixes of the same length are tried in sorted order)
+    pub fn path_candidates(&self, specifier: &str) -> Vec<PathBuf> {
+        let paths_base = match self.paths_base {
+            Some(ref base) => base,
+            None => return Vec::new(),
+        };
+
+        let mut best_match: Option<(usize, &str, &Vec<String>)> = None;
+        for (pattern, substitutions) in self.paths.iter() {
+            match pattern.find('*') {
+                None => {
+                    if pattern == specifier {
+                        best_match = Some((usize::MAX, "", substitutions));
+                        break;
+                    }
+                }
+
+                Some(star) => {
+                    let (prefix, suffix) = (&pattern[..star], &pattern[star + 1..]);
+
+                    let is_match = specifier.len() >= prefix.len() + suffix.len()
+                        && specifier.starts_with(prefix)
+                        && specifier.ends_with(suffix);
+
+                    let is_better = best_match
+                        .map(|(best_len, ..)| prefix.len() > best_len)
+                        .unwrap_or(true);
+
+                    if is_match && is_better {
+                        let matched = &specifier[prefix.len()..specifier.len() - suffix.len()];
+                        best_match = Some((prefix.len(), matched, substitutions));
+                    }
+                }
+            }
+        }
+
+        best_match
+            .map(|(_, matched, substitutions)| {
+                substitutions
+                    .iter()
+                    .map(|substitution| paths_base.join(substitution.replacen('*', matched, 1)))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn load_file(&mut self, path: &Path, visited: &mut Vec<PathBuf>) -> Result<(), TsConfigError> {
+        let path = path
+            .canonicalize()
+            .map_err(|e| TsConfigError::IoError(path.to_owned(), e))?;
+
+        if visited.contains(&path) {
+            return Err(TsConfigError::CircularExtends(path));
+        }
+        visited.push(path.clone());
+
+        let config_dir = path.parent().unwrap_or(Path::new("/")).to_owned();
+
+        let contents =
+            fs::read_to_string(&path).map_err(|e| TsConfigError::IoError(path.clone(), e))?;
+
+        let config: Value = serde_json::from_str(&strip_jsonc(&contents))
+            .map_err(|e| TsConfigError::JsonError(path.clone(), e))?;
+
+        // Options of the extended configuration are overridden by the extending configuration
+        if let Some(extends) = config.get("extends").and_then(|e| e.as_str()) {
+            self.load_file(&resolve_extends(&config_dir, extends), visited)?;
+        }
+
+        let empty = Map::new();
+        let compiler_options = config
+            .get("compilerOptions")
+            .and_then(|o| o.as_object())
+            .unwrap_or(&empty);
+
+        if let Some(base_url) = compiler_options.get("baseUrl").and_then(|b| b.as_str()) {
+            let base_url = config_dir.join(base_url);
+            self.base_url = Some(base_url.clone());
+            self.paths_base = Some(base_url);
+        }
+
+        if let Some(paths) = compiler_options.get("paths").and_then(|p| p.as_object()) {
+            self.paths = paths
+                .iter()
+                .map(|(pattern, substitutions)| {
+                    let substitutions = substitutions
+                        .as_array()
+                        .map(|subs| {
+                            subs.iter()
+                                .filter_map(|s| s.as_str().map(|s| s.to_string()))
+                                .collect()
+                        })
+                        .unwrap_or_default();
+
+                    (pattern.clone(), substitutions)
+                })
+                .collect();
+
+            if self.base_url.is_none() {
+                self.paths_base = Some(config_dir.clone());
+            }
+        }
+
+        if let Some(type_roots) = compiler_options.get("typeRoots").and_then(|t| t.as_array()) {
+            self.type_roots = Some(
+                type_roots
+                    .iter()
+                    .filter_map(|root| root.as_str().map(|root| config_dir.join(root)))
+                    .collect(),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Relative and absolute `extends` paths are resolved against the extending configuration's
+///   directory. Package specifiers (i.e. `"@tsconfig/node16"`) are looked up in each enclosing
+///   `node_modules` directory, as `<package>.json` or `<package>/tsconfig.json`
+fn resolve_extends(config_dir: &Path, extends: &str) -> PathBuf {
+    let with_json = |path: PathBuf| match path.extension() {
+        Some(_) => path,
+        None => path.with_extension("json"),
+    };
+
+    if is_path_specifier(Path::new(extends)) {
+        return with_json(config_dir.join(extends));
+    }
+
+    config_dir
+        .ancestors()
+        .map(|dir| dir.join(NODE_MODULES).join(extends))
+        .find_map(|package_path| {
+            if package_path.is_dir() {
+                Some(package_path.join(TSCONFIG_FILE_NAME)).filter(|path| path.is_file())
+            } else {
+                Some(with_json(package_path)).filter(|path| path.is_file())
+            }
+        })
+        // Reported as a missing file by `load_file`
+        .unwrap_or_else(|| config_dir.join(NODE_MODULES).join(extends))
+}
+
+/// `tsconfig.json` files allow comments and trailing commas
+fn strip_jsonc(contents: &str) -> String {
+    strip_trailing_commas(&strip_comments(contents))
+}
+
+fn strip_comments(contents: &str) -> String {
+    let mut output = String::with_capacity(contents.len());
+    let mut chars = contents.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => copy_string(&mut output, &mut chars),
+
+            '/' if chars.peek() == Some(&'/') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        output.push(c);
+                        break;
+                    }
+                }
+            }
+
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut previous = ' ';
+                for c in chars.by_ref() {
+                    if previous == '*' && c == '/' {
+                        break;
+                    }
+                    previous = c;
+                }
+            }
+
+            _ => output.push(c),
+        }
+    }
+
+    output
+}
+
+fn strip_trailing_commas(contents: &str) -> String {
+    let mut output = String::with_capacity(contents.len());
+    let mut chars = contents.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => copy_string(&mut output, &mut chars),
+
+            ',' => {
+                let next = chars.clone().find(|c| !c.is_whitespace());
+                match next {
+                    Some('}') | Some(']') => (),
+                    _ => output.push(c),
+                }
+            }
+
+            _ => output.push(c),
+        }
+    }
+
+    output
+}
+
+/// Copy a string literal (starting after the opening quote) into the output
+fn copy_string<I: Iterator<Item = char>>(output: &mut String, chars: &mut I) {
+    output.push('"');
+    while let Some(c) = chars.next() {
+        output.push(c);
+        match c {
+            '\\' => {
+                if let Some(escaped) = chars.next() {
+                    output.push(escaped);
+                }
+            }
+
+            '"' => break,
+
+            _ => (),
+        }
+    }
+}
@@ -1,23 +1,42 @@
 use std::collections::HashMap;
 
 use swc_atoms::JsWord;
+use swc_common::{Span, Spanned};
 use swc_ecma_ast::*;
 
+use super::bind_common;
+use super::bind_graph_init::ModuleGraph;
+use super::bind_init::ParsedModuleCache as ModuleCache;
 use super::error::*;
-use super::structures::{CanonPath, ItemState, Scope};
+use super::structures::{CanonPath, ItemState, ItemStateT, Scope, TypeScope};
 use super::type_structs::*;
 
 ///
 /// Assumes type_scope is fully initialized (via init_type_scop::init())
+///   as well as global_scope (via init_type_scope::init_globals())
+///   and the graph is reduced (see `graph_reduce::reduce()`)
 ///
 pub fn construct_variable_types(
+    cache: &ModuleCache,
     current_module: &CanonPath,
-    type_scope: &Scope<ItemState>,
+    type_scopes: &HashMap<CanonPath, TypeScope>,
+    value_scope: &Scope<ItemStateT>,
+    global_scope: &TypeScope,
+    graph: &ModuleGraph,
     decl: &VarDecl,
 ) -> Result<Vec<(JsWord, Type)>, BindGenError> {
     let session = Session {
         path: current_module,
-        scope: type_scope,
+        scope: &type_scopes[current_module],
+        type_scopes,
+        value_scope: Some(value_scope),
+        global_scope,
+        graph,
+        cache,
+        type_vars: Vec::new(),
+        type_args: HashMap::new(),
+        resolving: Vec::new(),
+        resolving_values: Vec::new(),
         self_id: None,
     };
 
@@ -28,7 +47,11 @@ pub fn construct_variable_types(
                 let typ = ident
                     .type_ann
                     .as_ref()
-                    .map(|ann| session.type_from_ann(ann))
+                    .map(|ann| {
+                        session
+                            .type_from_ann(ann)
+                            .and_then(|typ| session.value_type(typ, ann.span))
+                    })
                     .transpose()?
                     .unwrap_or(Type::Any);
                 map.push((ident.sym.clone(), typ));
@@ -38,289 +61,2257 @@ pub fn construct_variable_types(
         }
     }
 
-    Ok(map)
-}
+    Ok(map)
+}
+
+///
+/// Assumes type_scope is fully initialized (via init_type_scop::init())
+///   as well as global_scope (via init_type_scope::init_globals())
+///   and the graph is reduced (see `graph_reduce::reduce()`)
+///
+pub fn construct_fn_type(
+    cache: &ModuleCache,
+    current_module: &CanonPath,
+    type_scopes: &HashMap<CanonPath, TypeScope>,
+    value_scope: &Scope<ItemStateT>,
+    global_scope: &TypeScope,
+    graph: &ModuleGraph,
+    function: &Function,
+) -> Result<Type, BindGenError> {
+    let session = Session {
+        path: current_module,
+        scope: &type_scopes[current_module],
+        type_scopes,
+        value_scope: Some(value_scope),
+        global_scope,
+        graph,
+        cache,
+        type_vars: Vec::new(),
+        type_args: HashMap::new(),
+        resolving: Vec::new(),
+        resolving_values: Vec::new(),
+        self_id: None,
+    };
+
+    session.gen_fn_type(function)
+}
+
+///
+/// Assumes type_scope is fully initialized (via init_type_scop::init())
+///   as well as global_scope (via init_type_scope::init_globals())
+///   and the graph is reduced (see `graph_reduce::reduce()`)
+///
+pub fn construct_type(
+    cache: &ModuleCache,
+    current_module: &CanonPath,
+    type_scopes: &HashMap<CanonPath, TypeScope>,
+    value_scope: &Scope<ItemStateT>,
+    global_scope: &TypeScope,
+    graph: &ModuleGraph,
+    decl: &Decl,
+) -> Result<Type, BindGenError> {
+    let self_id = get_type_name(decl);
+
+    let session = Session {
+        path: current_module,
+        scope: &type_scopes[current_module],
+        type_scopes,
+        value_scope: Some(value_scope),
+        global_scope,
+        graph,
+        cache,
+        type_vars: Vec::new(),
+        type_args: HashMap::new(),
+        resolving: self_id
+            .map(|id| vec![(current_module.clone(), id.clone())])
+            .unwrap_or_default(),
+        resolving_values: Vec::new(),
+        self_id,
+    };
+
+    session.construct(decl)
+}
+
+fn get_type_name(decl: &Decl) -> Option<&JsWord> {
+    match decl {
+        Decl::Class(ref decl) => Some(&decl.ident.sym),
+        Decl::TsInterface(ref decl) => Some(&decl.id.sym),
+        Decl::TsTypeAlias(ref decl) => Some(&decl.id.sym),
+        Decl::TsEnum(ref decl) => Some(&decl.id.sym),
+
+        _ => None,
+    }
+}
+
+/// Type annotation of a parameter and how the parameter is passed
+struct ParamInfo<'a> {
+    ann: Option<&'a TsTypeAnn>,
+    optional: bool,
+    rest: bool,
+}
+
+impl<'a> ParamInfo<'a> {
+    fn new(ann: Option<&'a TsTypeAnn>) -> Self {
+        ParamInfo {
+            ann,
+            optional: false,
+            rest: false,
+        }
+    }
+}
+
+/// Parameters with default values are optional
+fn param_from_pat(p: &Pat) -> ParamInfo<'_> {
+    match p {
+        Pat::Ident(ref pat) => ParamInfo {
+            optional: pat.optional,
+            ..ParamInfo::new(pat.type_ann.as_ref())
+        },
+        Pat::Array(ref pat) => ParamInfo {
+            optional: pat.optional,
+            ..ParamInfo::new(pat.type_ann.as_ref())
+        },
+        Pat::Rest(ref pat) => ParamInfo {
+            rest: true,
+            ..ParamInfo::new(pat.type_ann.as_ref())
+        },
+        Pat::Object(ref pat) => ParamInfo {
+            optional: pat.optional,
+            ..ParamInfo::new(pat.type_ann.as_ref())
+        },
+        Pat::Assign(ref pat) => ParamInfo {
+            optional: true,
+            ..ParamInfo::new(pat.type_ann.as_ref())
+        },
+        Pat::Invalid(..) => ParamInfo::new(None),
+        Pat::Expr(..) => ParamInfo::new(None),
+    }
+}
+
+/// Identifier and parameter of a parameter property (i.e. `public x: number`)
+fn param_from_param_prop(p: &TsParamPropParam) -> (Option<&Ident>, ParamInfo<'_>) {
+    match p {
+        TsParamPropParam::Ident(ref ident) => {
+            let param = ParamInfo {
+                optional: ident.optional,
+                ..ParamInfo::new(ident.type_ann.as_ref())
+            };
+
+            (Some(ident), param)
+        }
+
+        TsParamPropParam::Assign(ref pat) => {
+            let ident = match *pat.left {
+                Pat::Ident(ref ident) => Some(ident),
+                _ => None,
+            };
+
+            let ann = pat
+                .type_ann
+                .as_ref()
+                .or(ident.and_then(|ident| ident.type_ann.as_ref()));
+            let param = ParamInfo {
+                optional: true,
+                ..ParamInfo::new(ann)
+            };
+
+            (ident, param)
+        }
+    }
+}
+
+fn param_from_fn_param(p: &TsFnParam) -> ParamInfo<'_> {
+    match p {
+        TsFnParam::Ident(ref pat) => ParamInfo {
+            optional: pat.optional,
+            ..ParamInfo::new(pat.type_ann.as_ref())
+        },
+        TsFnParam::Array(ref pat) => ParamInfo {
+            optional: pat.optional,
+            ..ParamInfo::new(pat.type_ann.as_ref())
+        },
+        TsFnParam::Object(ref pat) => ParamInfo {
+            optional: pat.optional,
+            ..ParamInfo::new(pat.type_ann.as_ref())
+        },
+        TsFnParam::Rest(ref pat) => ParamInfo {
+            rest: true,
+            ..ParamInfo::new(pat.type_ann.as_ref())
+        },
+    }
+}
+
+/// Signatures of methods declared several times are overloads (see `Type::overload()`)
+fn insert_member(members: &mut HashMap<JsWord, Type>, key: JsWord, typ: Type) {
+    let typ = match members.remove(&key) {
+        Some(previous) => previous.overload(typ),
+        None => typ,
+    };
+
+    members.insert(key, typ);
+}
+
+/// Methods declared several times are overloads (see `Type::overload()`)
+///   Getters and setters of the same property are merged into a single accessor
+///   (the property is readonly without a setter)
+fn insert_class_member(members: &mut HashMap<JsWord, Member>, key: JsWord, member: Member) {
+    let member = match (members.remove(&key), member.kind) {
+        (
+            Some(Member {
+                kind: MemberKind::Method,
+                typ: previous,
+                ..
+            }),
+            MemberKind::Method,
+        ) => Member {
+            typ: previous.overload(member.typ),
+            ..member
+        },
+
+        (
+            Some(Member {
+                kind: MemberKind::Accessor { getter, setter },
+                typ: previous,
+                ..
+            }),
+            MemberKind::Accessor {
+                getter: new_getter,
+                setter: new_setter,
+            },
+        ) => {
+            let getter = getter || new_getter;
+            let setter = setter || new_setter;
+
+            // The property has the type returned by the getter
+            let typ = if new_getter { member.typ } else { previous };
+
+            Member {
+                kind: MemberKind::Accessor { getter, setter },
+                typ,
+                readonly: !setter,
+                is_abstract: member.is_abstract,
+            }
+        }
+
+        (_, MemberKind::Accessor { setter, .. }) => Member {
+            readonly: !setter,
+            ..member
+        },
+
+        _ => member,
+    };
+
+    members.insert(key, member);
+}
+
+/// Type name of the class in a heritage clause (i.e. `ns.Base` of `extends ns.Base`)
+fn entity_name(expr: &Expr) -> Option<TsEntityName> {
+    match expr {
+        Expr::Ident(ref ident) => Some(TsEntityName::Ident(ident.clone())),
+
+        Expr::Member(MemberExpr {
+            obj: ExprOrSuper::Expr(ref obj),
+            ref prop,
+            computed: false,
+            ..
+        }) => match **prop {
+            Expr::Ident(ref right) => {
+                Some(TsEntityName::TsQualifiedName(Box::new(TsQualifiedName {
+                    left: entity_name(obj)?,
+                    right: right.clone(),
+                })))
+            }
+
+            _ => None,
+        },
+
+        Expr::Paren(ParenExpr { ref expr, .. }) => entity_name(expr),
+
+        _ => None,
+    }
+}
+
+fn is_hidden(accessibility: Option<Accessibility>) -> bool {
+    match accessibility {
+        Some(Accessibility::Private) | Some(Accessibility::Protected) => true,
+        Some(Accessibility::Public) | None => false,
+    }
+}
+
+/// Computed keys (i.e. `[Symbol.iterator]`) are not bound
+fn member_key(key: &PropName) -> Option<JsWord> {
+    match key {
+        PropName::Ident(ref ident) => Some(ident.sym.clone()),
+        PropName::Str(ref s) => Some(s.value.clone()),
+        PropName::Num(ref n) => Some(n.value.to_string().into()),
+        PropName::Computed(..) => None,
+    }
+}
+
+fn member_key_from_expr(key: &Expr, computed: bool) -> Option<JsWord> {
+    match key {
+        _ if computed => None,
+        Expr::Ident(ref ident) => Some(ident.sym.clone()),
+        Expr::Lit(Lit::Str(ref s)) => Some(s.value.clone()),
+        Expr::Lit(Lit::Num(ref n)) => Some(n.value.to_string().into()),
+        _ => None,
+    }
+}
+
+fn optional(is_optional: bool, typ: Type) -> Type {
+    if is_optional {
+        Type::Optional(Box::new(typ))
+    } else {
+        typ
+    }
+}
+
+/// Elements of a type literal (ignoring parentheses)
+fn type_lit_members(typ: &TsType) -> Option<&[TsTypeElement]> {
+    match typ {
+        TsType::TsTypeLit(ref lit) => Some(&lit.members),
+
+        TsType::TsParenthesizedType(ref paren) => type_lit_members(&paren.type_ann),
+
+        _ => None,
+    }
+}
+
+/// Distinct values of a union of literal types (in declaration order)
+fn lit_union(types: &[Box<TsType>]) -> Option<Vec<LitValue>> {
+    let mut lit_values = Vec::new();
+    for member in types.iter() {
+        let lit_value = lit_value(&lit_type(member)?.lit)?;
+
+        if !lit_values.contains(&lit_value) {
+            lit_values.push(lit_value);
+        }
+    }
+
+    Some(lit_values)
+}
+
+/// Literal type (ignoring parentheses)
+fn lit_type(typ: &TsType) -> Option<&TsLitType> {
+    match typ {
+        TsType::TsLitType(ref lit) => Some(lit),
+
+        TsType::TsParenthesizedType(ref paren) => lit_type(&paren.type_ann),
+
+        _ => None,
+    }
+}
+
+fn lit_value(lit: &TsLit) -> Option<LitValue> {
+    match lit {
+        TsLit::Str(ref s) => Some(LitValue::String(s.value.clone())),
+        TsLit::Number(ref n) => Some(LitValue::Number(n.value)),
+        TsLit::Bool(ref b) => Some(LitValue::Bool(b.value)),
+        TsLit::Tpl(..) => None,
+    }
+}
+
+/// Value of a constant enum member initializer
+///   Initializers may refer to previous members of the enum (i.e. `B = A << 1` or `B = E.A`)
+///
+/// Bitwise operators convert their operands to 32-bit integers (as in JS)
+fn enum_value(init: &Expr, enum_name: &JsWord, members: &[EnumMember]) -> Option<LitValue> {
+    let member_value = |name: &JsWord| {
+        members
+            .iter()
+            .find(|member| member.name == *name)
+            .map(|member| member.value.clone())
+    };
+
+    let number_value = |expr: &Expr| match enum_value(expr, enum_name, members)? {
+        LitValue::Number(n) => Some(n),
+        _ => None,
+    };
+
+    match init {
+        Expr::Lit(Lit::Num(ref n)) => Some(LitValue::Number(n.value)),
+
+        Expr::Lit(Lit::Str(ref s)) => Some(LitValue::String(s.value.clone())),
+
+        Expr::Paren(ParenExpr { ref expr, .. }) => enum_value(expr, enum_name, members),
+
+        Expr::Ident(ref ident) => member_value(&ident.sym),
+
+        Expr::Member(MemberExpr {
+            obj: ExprOrSuper::Expr(ref obj),
+            ref prop,
+            computed: false,
+            ..
+        }) => match (&**obj, &**prop) {
+            (Expr::Ident(ref obj), Expr::Ident(ref prop)) if obj.sym == *enum_name => {
+                member_value(&prop.sym)
+            }
+
+            _ => None,
+        },
+
+        Expr::Unary(UnaryExpr {
+            ref op, ref arg, ..
+        }) => {
+            let n = number_value(arg)?;
+            let value = match op {
+                UnaryOp::Minus => -n,
+                UnaryOp::Plus => n,
+                UnaryOp::Tilde => !(n as i32) as f64,
+                _ => return None,
+            };
+
+            Some(LitValue::Number(value))
+        }
+
+        Expr::Bin(BinExpr {
+            ref op,
+            ref left,
+            ref right,
+            ..
+        }) => {
+            let left = enum_value(left, enum_name, members)?;
+            let right = enum_value(right, enum_name, members)?;
+
+            let (l, r) = match (left, right) {
+                (LitValue::Number(l), LitValue::Number(r)) => (l, r),
+
+                (LitValue::String(l), LitValue::String(r)) if *op == BinaryOp::Add => {
+                    return Some(LitValue::String(JsWord::from(format!("{}{}", l, r))));
+                }
+
+                _ => return None,
+            };
+
+            let (li, ri) = (l as i32, (r as u32) & 0x1f);
+            let value = match op {
+                BinaryOp::Add => l + r,
+                BinaryOp::Sub => l - r,
+                BinaryOp::Mul => l * r,
+                BinaryOp::Div => l / r,
+                BinaryOp::Mod => l % r,
+                BinaryOp::Exp => l.powf(r),
+                BinaryOp::LShift => li.wrapping_shl(ri) as f64,
+                BinaryOp::RShift => li.wrapping_shr(ri) as f64,
+                BinaryOp::ZeroFillRShift => (li as u32).wrapping_shr(ri) as f64,
+                BinaryOp::BitOr => (li | r as i32) as f64,
+                BinaryOp::BitXor => (li ^ r as i32) as f64,
+                BinaryOp::BitAnd => (li & r as i32) as f64,
+                _ => return None,
+            };
+
+            Some(LitValue::Number(value))
+        }
+
+        _ => None,
+    }
+}
+
+/// Keys of a record type evaluated from a key type (see `Session::property_keys()`)
+enum PropertyKeys {
+    /// Literal keys (i.e. `"a" | "b"`)
+    Fields(Vec<JsWord>),
+
+    /// Any string key (i.e. `string`)
+    Dict,
+}
+
+/// String and number literals are property keys (numbers are strings at runtime)
+fn lit_key(lit_value: &LitValue) -> Option<JsWord> {
+    match lit_value {
+        LitValue::String(ref s) => Some(s.clone()),
+        LitValue::Number(n) => Some(JsWord::from(n.to_string())),
+        LitValue::Bool(..) => None,
+    }
+}
+
+fn strip_optional(typ: Type) -> Type {
+    match typ {
+        Type::Optional(typ) => *typ,
+        typ => typ,
+    }
+}
+
+/// Union of the distinct types (the type itself if there is only one)
+fn union_of(types: Vec<Type>) -> Type {
+    let mut members: Vec<Type> = Vec::new();
+    for typ in types.into_iter() {
+        if !members.contains(&typ) {
+            members.push(typ);
+        }
+    }
+
+    match members.len() {
+        0 => Type::Never,
+        1 => members.remove(0),
+        _ => Type::Union(members),
+    }
+}
+
+fn is_primitive(typ: &Type) -> bool {
+    matches!(
+        typ,
+        Type::Number | Type::String | Type::Boolean | Type::Void | Type::Undefined | Type::Null
+    )
+}
+
+/// Number of leading arguments of a deferred operator which are evaluated
+///   (i.e. `T` of `Partial<T>` but not `T` of `Record<K, T>`, see `Type::Unevaluated`)
+fn deferred_operands(operator: &str) -> usize {
+    match operator {
+        "Readonly" => 0,
+        "Record" | KEYOF_OPERATOR => 1,
+        _ => 2,
+    }
+}
+
+fn is_type_var(typ: &Type) -> bool {
+    matches!(typ, Type::TypeVar(..))
+}
+
+/// Dotted name of the entity (i.e. `A.B.Foo`)
+fn dotted_name(entity: &TsEntityName) -> String {
+    match entity {
+        TsEntityName::Ident(ref i) => i.sym.to_string(),
+        TsEntityName::TsQualifiedName(ref qualified) => {
+            format!("{}.{}", dotted_name(&qualified.left), qualified.right.sym)
+        }
+    }
+}
+
+/// Type of instances of a class (instantiated with its own type parameters, i.e. `Foo<T>`)
+pub(super) fn instance_type(class_type: &ClassType) -> Type {
+    let instance = Type::Named {
+        name: class_type.name.clone(),
+        source: class_type.origin.clone(),
+    };
+
+    if class_type.type_params.is_empty() {
+        instance
+    } else {
+        Type::TyApp {
+            base: Box::new(instance),
+            args: class_type
+                .type_params
+                .iter()
+                .cloned()
+                .map(Type::TypeVar)
+                .collect(),
+        }
+    }
+}
+
+/// Type of a class as a value (i.e. `typeof Foo`): its constructors and static members
+///   Constructors construct instances of the class (abstract classes cannot be constructed)
+pub(super) fn class_value_type(class_type: &ClassType) -> Type {
+    let instance = instance_type(class_type);
+
+    // Classes without constructors have the default constructor
+    let default_constructor = FnType {
+        params: Vec::new(),
+        optional_params: 0,
+        rest_param: None,
+        return_type: Box::new(Type::Any),
+    };
+
+    let constructors = if class_type.constructors.is_empty() {
+        vec![default_constructor]
+    } else {
+        class_type.constructors.clone()
+    };
+
+    let construct_signatures = if class_type.is_abstract {
+        Vec::new()
+    } else {
+        constructors
+            .into_iter()
+            .map(|constructor| {
+                let constructor = FnType {
+                    return_type: Box::new(instance.clone()),
+                    ..constructor
+                };
+
+                Type::forall(class_type.type_params.clone(), Type::Fn(constructor))
+            })
+            .collect()
+    };
+
+    let fields = class_type
+        .static_members
+        .iter()
+        .map(|(key, member)| (key.clone(), member.typ.clone()))
+        .collect();
+
+    Type::Callable(CallableType {
+        call_signatures: Vec::new(),
+        construct_signatures,
+        fields,
+    })
+}
+
+/// Generic types which are arrays (if they are not shadowed)
+const BUILTIN_ARRAY_TYPES: &[&str] = &["Array", "ReadonlyArray"];
+
+/// Operator of deferred `keyof` types (see `Type::Unevaluated`)
+const KEYOF_OPERATOR: &str = "keyof";
+
+/// Generic utility types evaluated on their arguments (if they are not shadowed)
+///   (see `Session::eval_utility()`)
+const BUILTIN_UTILITY_TYPES: &[&str] =
+    &["Partial", "Required", "Readonly", "Pick", "Omit", "Record"];
+
+#[derive(Clone)]
+struct Session<'a> {
+    path: &'a CanonPath,
+    self_id: Option<&'a JsWord>,
+    scope: &'a TypeScope,
+
+    /// Scopes of every module (for resolving the declarations of named types)
+    type_scopes: &'a HashMap<CanonPath, TypeScope>,
+
+    /// Values bound so far in the module (see `resolve_query()`)
+    ///   Sessions resolving declarations of other modules have none
+    value_scope: Option<&'a Scope<ItemStateT>>,
+
+    /// Consulted for names not in scope of the module
+    global_scope: &'a TypeScope,
+    graph: &'a ModuleGraph,
+
+    /// Parsed modules (for resolving the declarations of named types)
+    cache: &'a ModuleCache,
+
+    /// Type parameters in scope (innermost last)
+    type_vars: Vec<JsWord>,
+
+    /// Type parameters bound to types (i.e. the key `K` of a mapped type `{ [K in Keys]: T }`)
+    type_args: HashMap<JsWord, Type>,
+
+    /// Named types whose declarations are being bound (see `resolve_named()`)
+    resolving: Vec<(CanonPath, JsWord)>,
+
+    /// Values whose declarations are being bound (see `resolve_value()`)
+    resolving_values: Vec<(CanonPath, JsWord)>,
+}
+
+impl<'a> Session<'a> {
+    fn construct(&self, decl: &Decl) -> Result<Type, BindGenError> {
+        match decl {
+            Decl::Class(ref decl) => self.gen_class_type(decl),
+
+            Decl::TsInterface(ref decl) => self.gen_interface_type(decl),
+
+            Decl::TsTypeAlias(ref alias) => {
+                let (session, type_params) = self.with_type_params(alias.type_params.as_ref());
+                let typ = session.bind_type(&alias.type_ann)?;
+
+                Ok(Type::forall(type_params, typ))
+            }
+
+            Decl::TsEnum(ref decl) => self.gen_enum_type(decl),
+
+            _ => unreachable!(),
+        }
+    }
+
+    fn type_error(&self, error: TypeError, span: Span) -> BindGenError {
+        BindGenError {
+            kind: BindGenErrorKind::TypeError(error),
+            module_path: self.path.as_path().to_owned(),
+            span,
+        }
+    }
+
+    /// Session with the declared type parameters in scope
+    ///   Constraints and defaults of the type parameters are ignored
+    fn with_type_params(&self, decl: Option<&TsTypeParamDecl>) -> (Session<'a>, Vec<JsWord>) {
+        let type_params: Vec<JsWord> = decl
+            .map(|decl| {
+                decl.params
+                    .iter()
+                    .map(|param| param.name.sym.clone())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut session = self.clone();
+        session.type_vars.extend(type_params.iter().cloned());
+        for type_param in type_params.iter() {
+            session.type_args.remove(type_param);
+        }
+
+        (session, type_params)
+    }
+
+    fn is_type_var(&self, key: &JsWord) -> bool {
+        self.type_vars.contains(key)
+    }
+
+    fn is_in_scope(&self, key: &JsWord) -> bool {
+        self.scope.types.get(key).is_some() || self.global_scope.types.get(key).is_some()
+    }
+
+    fn get_item_state(&self, key: &JsWord, span: Span) -> Result<ItemState, BindGenError> {
+        self.scope
+            .types
+            .get(key)
+            .cloned()
+            .or_else(|| self.global_scope.types.get(key).cloned())
+            .or(self.self_id.map(|_| ItemState::Rooted))
+            .ok_or_else(|| self.type_error(TypeError::UnknownType(key.to_string()), span))
+    }
+
+    /// Module of the namespace referred to by the entity name (i.e. `A.B` of `A.B.Foo`)
+    ///   Follows re-exported namespaces and module-valued items (i.e. `import * as A`)
+    fn get_namespace(&self, entity: &TsEntityName, span: Span) -> Result<CanonPath, BindGenError> {
+        self.query_namespace(entity)
+            .ok_or_else(|| self.type_error(TypeError::UnknownNamespace(dotted_name(entity)), span))
+    }
+
+    fn gen_interface_type(&self, decl: &TsInterfaceDecl) -> Result<Type, BindGenError> {
+        let (session, type_params) = self.with_type_params(decl.type_params.as_ref());
+        let mut fields: HashMap<JsWord, Type> = HashMap::new();
+
+        for ts_type_element in decl.body.body.iter() {
+            session.gen_type_element(ts_type_element, |sym, typ| {
+                insert_member(&mut fields, sym, typ);
+            })?;
+        }
+
+        let callable = session.callable_type(&decl.body.body)?;
+
+        if fields.is_empty() && decl.extends.is_empty() && callable.is_none() {
+            if let Some(value_type) = session.index_signature(&decl.body.body)? {
+                return Ok(Type::forall(type_params, Type::Dict(Box::new(value_type))));
+            }
+        }
+
+        let mut extends = Vec::new();
+        for heritage in decl.extends.iter() {
+            let base = session.bind_heritage(heritage)?;
+
+            // Inherited fields are overridden by redeclared fields
+            for (field, typ) in session.record_fields(&base, heritage.span)? {
+                fields.entry(field).or_insert(typ);
+            }
+
+            extends.push(base);
+        }
+
+        // Interfaces with call or construct signatures are not records
+        if let Some(callable) = callable {
+            let callable = CallableType { fields, ..callable };
+            return Ok(Type::forall(type_params, Type::Callable(callable)));
+        }
+
+        Ok(Type::Interface {
+            name: decl.id.sym.clone(),
+            origin: self.path.clone(),
+            type_params,
+            fields,
+            extends,
+        })
+    }
+
+    /// Private and protected members are not accessible to bindings
+    fn gen_class_type(&self, decl: &ClassDecl) -> Result<Type, BindGenError> {
+        let (session, type_params) = self.with_type_params(decl.class.type_params.as_ref());
+        let mut members: HashMap<JsWord, Member> = HashMap::new();
+        let mut static_members: HashMap<JsWord, Member> = HashMap::new();
+        let mut constructors: Vec<FnType> = Vec::new();
+
+        for class_member in decl.class.body.iter() {
+            match class_member {
+                ClassMember::ClassProp(ref prop) => {
+                    let key = match member_key_from_expr(&prop.key, prop.computed) {
+                        Some(key) if !is_hidden(prop.accessibility) => key,
+                        _ => continue,
+                    };
+
+                    let typ = prop
+                        .type_ann
+                        .as_ref()
+                        .map(|ann| session.type_from_ann(ann))
+                        .transpose()?
+                        .unwrap_or(Type::Any);
+
+                    let member = Member {
+                        kind: MemberKind::Property,
+                        typ: optional(prop.is_optional, typ),
+                        readonly: prop.readonly,
+                        is_abstract: prop.is_abstract,
+                    };
+
+                    let members = if prop.is_static {
+                        &mut static_members
+                    } else {
+                        &mut members
+                    };
+                    insert_class_member(members, key, member);
+                }
+
+                ClassMember::Constructor(ref constructor) => {
+                    if is_hidden(constructor.accessibility) {
+                        continue;
+                    }
+
+                    let mut params = Vec::new();
+                    for param in constructor.params.iter() {
+                        let param = match param {
+                            ParamOrTsParamProp::Param(ref param) => param_from_pat(&param.pat),
+
+                            // Parameter properties are also instance properties
+                            //   (i.e. `x` of `constructor(public x: number)`)
+                            ParamOrTsParamProp::TsParamProp(ref prop) => {
+                                let (ident, param) = param_from_param_prop(&prop.param);
+
+                                if let Some(ident) =
+                                    ident.filter(|_| !is_hidden(prop.accessibility))
+                                {
+                                    let typ = param
+                                        .ann
+                                        .map(|ann| session.type_from_ann(ann))
+                                        .transpose()?
+                                        .unwrap_or(Type::Any);
+
+                                    let member = Member {
+                                        kind: MemberKind::Property,
+                                        typ: optional(param.optional, typ),
+                                        readonly: prop.readonly,
+                                        is_abstract: false,
+                                    };
+                                    insert_class_member(&mut members, ident.sym.clone(), member);
+                                }
+
+                                param
+                            }
+                        };
+
+                        params.push(param);
+                    }
+
+                    constructors.push(session.gen_fn(params.into_iter(), Type::Any)?);
+                }
+
+                ClassMember::Method(ref method) => {
+                    let key = match member_key(&method.key) {
+                        Some(key) if !is_hidden(method.accessibility) => key,
+                        _ => continue,
+                    };
+
+                    let (kind, typ) = match method.kind {
+                        // TODO: Self parameter
+                        MethodKind::Method => {
+                            let typ = session.gen_fn_type(&method.function)?;
+                            (MemberKind::Method, optional(method.is_optional, typ))
+                        }
+
+                        MethodKind::Getter => {
+                            let typ = method
+                                .function
+                                .return_type
+                                .as_ref()
+                                .map(|ann| session.type_from_ann(ann))
+                                .transpose()?
+                                .unwrap_or(Type::Any);
+                            let kind = MemberKind::Accessor {
+                                getter: true,
+                                setter: false,
+                            };
+
+                            (kind, typ)
+                        }
+
+                        MethodKind::Setter => {
+                            let typ = method
+                                .function
+                                .params
+                                .first()
+                                .and_then(|param| param_from_pat(&param.pat).ann)
+                                .map(|ann| session.type_from_ann(ann))
+                                .transpose()?
+                                .unwrap_or(Type::Any);
+                            let kind = MemberKind::Accessor {
+                                getter: false,
+                                setter: true,
+                            };
+
+                            (kind, typ)
+                        }
+                    };
+
+                    let member = Member {
+                        kind,
+                        typ,
+                        readonly: false,
+                        is_abstract: method.is_abstract,
+                    };
+
+                    let members = if method.is_static {
+                        &mut static_members
+                    } else {
+                        &mut members
+                    };
+                    insert_class_member(members, key, member);
+                }
+
+                // Private names (i.e. `#x`) are not accessible outside of the class
+                ClassMember::PrivateMethod(..) | ClassMember::PrivateProp(..) => (),
+
+                // TODO: Index signatures
+                ClassMember::TsIndexSignature(..) => (),
+
+                ClassMember::Empty(..) => (),
+            }
+        }
+
+        // Mixins (i.e. `extends Mixin(Base)`) are not resolved
+        let super_class = match decl.class.super_class.as_ref().and_then(|e| entity_name(e)) {
+            Some(type_name) => {
+                let span = decl.class.super_class.as_ref().unwrap().span();
+                let super_class = session.bind_type(&TsType::TsTypeRef(TsTypeRef {
+                    span,
+                    type_name,
+                    type_params: decl.class.super_type_params.clone(),
+                }))?;
+
+                // Inherited members are overridden by redeclared members
+                let inherited = session.class_heritage(&super_class, span)?;
+                for (key, member) in inherited.members.into_iter() {
+                    members.entry(key).or_insert(member);
+                }
+                for (key, member) in inherited.static_members.into_iter() {
+                    static_members.entry(key).or_insert(member);
+                }
+
+                // Constructors are inherited if none are declared
+                if constructors.is_empty() {
+                    constructors = inherited.constructors;
+                }
+
+                Some(Box::new(super_class))
+            }
+
+            None => None,
+        };
+
+        let implements = decl
+            .class
+            .implements
+            .iter()
+            .map(|heritage| session.bind_heritage(heritage))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Type::Class(ClassType {
+            name: decl.ident.sym.clone(),
+            origin: self.path.clone(),
+            type_params,
+            constructors,
+            members,
+            static_members,
+            is_abstract: decl.class.is_abstract,
+            super_class,
+            implements,
+        }))
+    }
+
+    /// `const enum`s have no runtime object but their members have the same values
+    fn gen_enum_type(&self, decl: &TsEnumDecl) -> Result<Type, BindGenError> {
+        let mut members: Vec<EnumMember> = Vec::new();
+
+        for member in decl.members.iter() {
+            let name = match member.id {
+                TsEnumMemberId::Ident(ref ident) => ident.sym.clone(),
+                TsEnumMemberId::Str(ref s) => s.value.clone(),
+            };
+
+            let value = match member.init {
+                Some(ref init) => enum_value(init, &decl.id.sym, &members),
+
+                // Members without initializers follow the previous (numeric) member
+                None => match members.last() {
+                    None => Some(LitValue::Number(0.0)),
+
+                    Some(EnumMember {
+                        value: LitValue::Number(n),
+                        ..
+                    }) => Some(LitValue::Number(n + 1.0)),
+
+                    Some(..) => None,
+                },
+            };
+
+            let value = value.ok_or_else(|| {
+                let error = TypeError::NonConstantEnumMember(name.to_string());
+                self.type_error(error, member.span)
+            })?;
+
+            members.push(EnumMember { name, value });
+        }
+
+        Ok(Type::Enum(EnumType {
+            name: decl.id.sym.clone(),
+            origin: self.path.clone(),
+            members,
+        }))
+    }
+
+    fn gen_fn_type(&self, function: &Function) -> Result<Type, BindGenError> {
+        let (session, type_params) = self.with_type_params(function.type_params.as_ref());
+
+        let return_type = function
+            .return_type
+            .as_ref()
+            .map(|ann| session.type_from_ann(ann))
+            .transpose()?
+            .unwrap_or(Type::Any);
+
+        let params = function
+            .params
+            .iter()
+            .map(|param| param_from_pat(&param.pat));
+        let fn_type = Type::Fn(session.gen_fn(params, return_type)?);
+
+        Ok(Type::forall(type_params, fn_type))
+    }
+
+    /// Only trailing optional parameters are optional
+    ///   (TS does not allow required parameters after optional parameters)
+    fn gen_fn<'p, I>(&self, params: I, return_type: Type) -> Result<FnType, BindGenError>
+    where
+        I: Iterator<Item = ParamInfo<'p>>,
+    {
+        let mut param_types = Vec::new();
+        let mut optional_params = 0;
+        let mut rest_param = None;
+
+        for param in params {
+            let typ = param
+                .ann
+                .map(|ann| self.type_from_ann(ann))
+                .transpose()?
+                .unwrap_or(Type::Any);
+
+            if param.rest {
+                // TODO: Rest parameters of tuple or generic types are untyped
+                let elem_type = match typ {
+                    Type::UnsizedArray(elem_type) => elem_type,
+                    _ => Box::new(Type::Any),
+                };
+
+                rest_param = Some(elem_type);
+                break;
+            }
+
+            if param.optional {
+                optional_params += 1;
+            } else {
+                optional_params = 0;
+            }
+
+            param_types.push(typ);
+        }
+
+        Ok(FnType {
+            params: param_types,
+            optional_params,
+            rest_param,
+            return_type: Box::new(return_type),
+        })
+    }
+
+    fn gen_type_element<F>(&self, element: &TsTypeElement, mut f: F) -> Result<(), BindGenError>
+    where
+        F: FnMut(JsWord, Type) -> (),
+    {
+        match element {
+            TsTypeElement::TsPropertySignature(ref signature) => {
+                let key = self.signature_key(&signature.key, signature.computed, signature.span)?;
+                let typ = signature
+                    .type_ann
+                    .as_ref()
+                    .map(|ann| self.type_from_ann(ann))
+                    .transpose()?
+                    .unwrap_or(Type::Any);
+
+                f(key, optional(signature.optional, typ));
+                Ok(())
+            }
+
+            // See `index_signature()`
+            TsTypeElement::TsIndexSignature(..) => Ok(()),
+
+            TsTypeElement::TsMethodSignature(ref signature) => {
+                let key = self.signature_key(&signature.key, signature.computed, signature.span)?;
+                let typ = self.gen_signature(
+                    &signature.params,
+                    signature.type_params.as_ref(),
+                    signature.type_ann.as_ref(),
+                )?;
+
+                f(key, optional(signature.optional, typ));
+
+                Ok(())
+            }
+
+            // See `callable_type()`
+            TsTypeElement::TsCallSignatureDecl(..)
+            | TsTypeElement::TsConstructSignatureDecl(..) => Ok(()),
+        }
+    }
+
+    /// Key of a property or method signature (quoted keys are bound like class members)
+    ///   Computed keys (i.e. `[Symbol.iterator]`) cannot be bound
+    fn signature_key(&self, key: &Expr, computed: bool, span: Span) -> Result<JsWord, BindGenError> {
+        member_key_from_expr(key, computed).ok_or_else(|| BindGenError {
+            kind: BindGenErrorKind::UnsupportedFeature(UnsupportedFeature::ComputedKey),
+            module_path: self.path.as_path().to_owned(),
+            span,
+        })
+    }
+
+    /// Function type of a signature (generic if the signature has type parameters)
+    ///   The return type defaults to `any`
+    fn gen_signature(
+        &self,
+        params: &[TsFnParam],
+        type_params: Option<&TsTypeParamDecl>,
+        return_ann: Option<&TsTypeAnn>,
+    ) -> Result<Type, BindGenError> {
+        let (session, type_params) = self.with_type_params(type_params);
+        let return_type = return_ann
+            .map(|ann| session.type_from_ann(ann))
+            .transpose()?
+            .unwrap_or(Type::Any);
+
+        let params = params.iter().map(param_from_fn_param);
+        let fn_type = Type::Fn(session.gen_fn(params, return_type)?);
+
+        Ok(Type::forall(type_params, fn_type))
+    }
+
+    /// Call and construct signatures of an object type (i.e. `(x: number): number` and `new (): Foo`)
+    ///   None if the object type has neither (the fields are filled in by the caller)
+    fn callable_type(
+        &self,
+        elements: &[TsTypeElement],
+    ) -> Result<Option<CallableType>, BindGenError> {
+        let mut callable = CallableType::default();
+
+        for element in elements.iter() {
+            match element {
+                TsTypeElement::TsCallSignatureDecl(ref signature) => {
+                    let typ = self.gen_signature(
+                        &signature.params,
+                        signature.type_params.as_ref(),
+                        signature.type_ann.as_ref(),
+                    )?;
+
+                    callable.call_signatures.push(typ);
+                }
+
+                TsTypeElement::TsConstructSignatureDecl(ref signature) => {
+                    let typ = self.gen_signature(
+                        &signature.params,
+                        signature.type_params.as_ref(),
+                        signature.type_ann.as_ref(),
+                    )?;
+
+                    callable.construct_signatures.push(typ);
+                }
+
+                _ => (),
+            }
+        }
+
+        if callable.call_signatures.is_empty() && callable.construct_signatures.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(callable))
+        }
+    }
+
+    /// Value type of the index signature of a record type (i.e. `number` of `[key: string]: number`)
+    ///   Records with both fields and an index signature are bound as records
+    ///   (the index signature is ignored)
+    fn index_signature(&self, elements: &[TsTypeElement]) -> Result<Option<Type>, BindGenError> {
+        let signature = elements.iter().find_map(|element| match element {
+            TsTypeElement::TsIndexSignature(ref signature) => Some(signature),
+            _ => None,
+        });
+
+        signature
+            .map(|signature| {
+                signature
+                    .type_ann
+                    .as_ref()
+                    .map(|ann| self.type_from_ann(ann))
+                    .transpose()
+                    .map(|value_type| value_type.unwrap_or(Type::Any))
+            })
+            .transpose()
+    }
+
+    /// Bind a union of object types sharing a field of distinct literal types
+    ///   (the discriminant) as a data union
+    ///
+    /// The first field of the first member which discriminates all members is chosen
+    fn bind_data_union(&self, types: &[Box<TsType>]) -> Result<Option<DataUnion>, BindGenError> {
+        let mut members = Vec::new();
+        for member in types.iter() {
+            match self.union_member_fields(member)? {
+                Some(fields) => members.push(fields),
+                None => return Ok(None),
+            }
+        }
+
+        let lit_field = |fields: &[(JsWord, Type)], tag: &JsWord| {
+            fields.iter().find_map(|(field, typ)| match typ {
+                Type::Lit(ref lit_value) if field == tag => Some(lit_value.clone()),
+                _ => None,
+            })
+        };
+
+        let discriminates = |tag: &JsWord| {
+            let mut tag_values: Vec<LitValue> = Vec::new();
+            for member in members.iter() {
+                match lit_field(member, tag) {
+                    Some(tag_value) if !tag_values.contains(&tag_value) => {
+                        tag_values.push(tag_value)
+                    }
+
+                    _ => return false,
+                }
+            }
+
+            true
+        };
+
+        let tag = match members.first().and_then(|first| {
+            first
+                .iter()
+                .map(|(field, _)| field)
+                .find(|field| discriminates(field))
+        }) {
+            Some(tag) => tag.clone(),
+            None => return Ok(None),
+        };
+
+        let variants = members
+            .into_iter()
+            .map(|member| {
+                let tag_value = lit_field(&member, &tag).expect("Checked by discriminates");
+                let fields = member
+                    .into_iter()
+                    .filter(|(field, _)| *field != tag)
+                    .collect();
+
+                Variant { tag_value, fields }
+            })
+            .collect();
+
+        Ok(Some(DataUnion { tag, variants }))
+    }
+
+    /// Fields of an object type in a union (`None` if the member is not an object type)
+    ///   Type literals keep their declaration order
+    ///   Named members are resolved to their declarations (i.e. `Circle` of `type Shape = Circle | Square`)
+    ///   and their fields are ordered by name
+    fn union_member_fields(
+        &self,
+        member: &TsType,
+    ) -> Result<Option<Vec<(JsWord, Type)>>, BindGenError> {
+        if let Some(elements) = type_lit_members(member) {
+            let mut fields = Vec::new();
+            for element in elements.iter() {
+                self.gen_type_element(element, |sym, typ| {
+                    fields.push((sym, typ));
+                })?;
+            }
+
+            return Ok(Some(fields));
+        }
+
+        let named = match member {
+            TsType::TsTypeRef(..) => self.bind_type(member)?,
+
+            TsType::TsParenthesizedType(ref paren) => {
+                return self.union_member_fields(&paren.type_ann)
+            }
+
+            _ => return Ok(None),
+        };
+
+        // Members which cannot be resolved (i.e. type parameters) are not object types
+        let declared = match named {
+            Type::Named {
+                ref name,
+                ref source,
+            } => match self.resolve_named(name, source, member.span()) {
+                Ok(declared) => declared,
+                Err(_) => return Ok(None),
+            },
+
+            _ => return Ok(None),
+        };
+
+        let mut fields: Vec<(JsWord, Type)> = match declared {
+            Type::Interface { fields, .. } | Type::Literal { fields } => fields.into_iter().collect(),
+
+            _ => return Ok(None),
+        };
+        fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        Ok(Some(fields))
+    }
+
+    /// Type declared as `name` in the module `source`
+    ///   Assumes `source` is the module the type is rooted in (see `graph_reduce::reduce()`)
+    fn resolve_named(
+        &self,
+        name: &JsWord,
+        source: &CanonPath,
+        span: Span,
+    ) -> Result<Type, BindGenError> {
+        let key = (source.clone(), name.clone());
+        if self.resolving.contains(&key) {
+            return Err(self.type_error(TypeError::CircularType(name.to_string()), span));
+        }
+
+        let data = self.cache.get(source);
+        let decls = bind_common::rooted_type_decls(&data.module_ast, name);
+        let first_decl = decls
+            .first()
+            .ok_or_else(|| self.type_error(TypeError::NonRecordType, span))?;
+
+        let mut resolving = self.resolving.clone();
+        resolving.push(key);
+
+        let session = Session {
+            path: source,
+            self_id: get_type_name(first_decl),
+            scope: &self.type_scopes[source],
+            type_scopes: self.type_scopes,
+            value_scope: None,
+            global_scope: self.global_scope,
+            graph: self.graph,
+            cache: self.cache,
+            type_vars: Vec::new(),
+            type_args: HashMap::new(),
+            resolving,
+            resolving_values: self.resolving_values.clone(),
+        };
+
+        let mut declared = session.construct(first_decl)?;
+        for decl in decls[1..].iter() {
+            declared = declared
+                .merge(session.construct(decl)?)
+                .ok_or_else(|| self.type_error(TypeError::ConflictingDeclaration(name.to_string()), span))?;
+        }
+
+        Ok(declared)
+    }
+
+    /// Values of callable object types are bound through their signatures and fields
+    ///   Named callable types (and instantiations of generic ones) are resolved to their declarations
+    fn value_type(&self, typ: Type, span: Span) -> Result<Type, BindGenError> {
+        let (name, source, args) = match typ {
+            Type::Named {
+                ref name,
+                ref source,
+            } => (name, source, &[][..]),
+
+            Type::TyApp { ref base, ref args } => match **base {
+                Type::Named {
+                    ref name,
+                    ref source,
+                } => (name, source, &args[..]),
+
+                _ => return Ok(typ),
+            },
+
+            _ => return Ok(typ),
+        };
+
+        match self.resolve_named(name, source, span)? {
+            callable @ Type::Callable(..) => Ok(callable),
+
+            Type::Forall { type_params, body } => match *body {
+                callable @ Type::Callable(..) => {
+                    let args: HashMap<JsWord, Type> =
+                        type_params.into_iter().zip(args.iter().cloned()).collect();
+
+                    Ok(callable.substitute(&args))
+                }
+
+                _ => Ok(typ),
+            },
+
+            _ => Ok(typ),
+        }
+    }
+
+    /// Type of the value queried by `typeof` (i.e. `x` of `typeof x` or `ns.x` of `typeof ns.x`)
+    ///   Values are looked up in the value scope, then the declarations of the module
+    ///   (i.e. values declared after the query), imports and enclosing and global declarations
+    fn resolve_query(&self, entity: &TsEntityName, span: Span) -> Result<Type, BindGenError> {
+        let name = match entity {
+            TsEntityName::Ident(ref i) => &i.sym,
+
+            // Namespace members are rooted in the namespace, otherwise the member is a field of
+            //   the queried value (i.e. `typeof options.width`)
+            TsEntityName::TsQualifiedName(ref qualified) => {
+                let member = &qualified.right.sym;
+                return match self.query_namespace(&qualified.left) {
+                    Some(namespace) => self.resolve_exported_value(&namespace, member, span),
+
+                    None => {
+                        let value_type = self.resolve_query(&qualified.left, span)?;
+                        let key = Type::Lit(LitValue::String(member.clone()));
+                        self.eval_indexed_access(&value_type, &key, span)
+                    }
+                };
+            }
+        };
+
+        match self.value_scope.and_then(|scope| scope.get(name)) {
+            Some(ItemStateT::Rooted(ref typ)) => return Ok(typ.clone()),
+
+            Some(ItemStateT::Imported {
+                ref source,
+                ref src_key,
+                ..
+            }) => return self.resolve_exported_value(source, src_key, span),
+
+            None => (),
+        }
+
+        let is_declared = |path: &CanonPath| {
+            // Cycles of aliases are reported by `resolve_value()`
+            bind_common::rooted_value_decls(&self.cache.get(path).module_ast, name)
+                .map_or(true, |decls| !decls.is_empty())
+        };
+
+        if is_declared(self.path) {
+            return self.resolve_value(name, self.path, span);
+        }
+
+        // Imports are in the type scope whether they are types or values
+        if let Some(ItemState::Imported {
+            source, src_key, ..
+        }) = self.scope.types.get(name)
+        {
+            if self.graph.exported_value(source, src_key).is_some() {
+                return self.resolve_exported_value(source, src_key, span);
+            }
+        }
+
+        let mut enclosing = self.path.enclosing();
+        while let Some(path) = enclosing {
+            if is_declared(&path) {
+                return self.resolve_value(name, &path, span);
+            }
+
+            enclosing = path.enclosing();
+        }
+
+        for global in self.cache.globals() {
+            if is_declared(&global.path) {
+                return self.resolve_value(name, &global.path, span);
+            }
+        }
+
+        Err(self.type_error(TypeError::UnknownValue(name.to_string()), span))
+    }
+
+    /// Module of the namespace referred to by the entity name, if it is one (see `get_namespace()`)
+    fn query_namespace(&self, entity: &TsEntityName) -> Option<CanonPath> {
+        match entity {
+            TsEntityName::Ident(ref i) => self
+                .scope
+                .namespaces
+                .get(&i.sym)
+                .or_else(|| self.global_scope.namespaces.get(&i.sym))
+                .cloned(),
+
+            TsEntityName::TsQualifiedName(ref qualified) => {
+                let namespace = self.query_namespace(&qualified.left)?;
+                self.graph
+                    .exported_namespace(&namespace, &qualified.right.sym)
+            }
+        }
+    }
+
+    /// Type of the value exported from `source` as `key` (following re-exports)
+    fn resolve_exported_value(
+        &self,
+        source: &CanonPath,
+        key: &JsWord,
+        span: Span,
+    ) -> Result<Type, BindGenError> {
+        let (source, key) = self
+            .graph
+            .exported_value(source, key)
+            .ok_or_else(|| self.type_error(TypeError::UnknownValue(key.to_string()), span))?;
+
+        self.resolve_value(&key, &source, span)
+    }
+
+    /// Type of the value declared in `source` as `name` (see `bind_common::rooted_value_decls()`)
+    fn resolve_value(
+        &self,
+        name: &JsWord,
+        source: &CanonPath,
+        span: Span,
+    ) -> Result<Type, BindGenError> {
+        let key = (source.clone(), name.clone());
+        if self.resolving_values.contains(&key) {
+            return Err(self.type_error(TypeError::CircularType(name.to_string()), span));
+        }
+
+        let data = self.cache.get(source);
+        let decls = bind_common::rooted_value_decls(&data.module_ast, name)
+            .map_err(|error| self.type_error(error, span))?;
+
+        let mut resolving_values = self.resolving_values.clone();
+        resolving_values.push(key);
+
+        let session = Session {
+            path: source,
+            self_id: None,
+            scope: &self.type_scopes[source],
+            type_scopes: self.type_scopes,
+            value_scope: None,
+            global_scope: self.global_scope,
+            graph: self.graph,
+            cache: self.cache,
+            type_vars: Vec::new(),
+            type_args: HashMap::new(),
+            resolving: self.resolving.clone(),
+            resolving_values,
+        };
+
+        let mut value_type: Option<Type> = None;
+        for (local, decl) in decls.iter() {
+            let decl_type = session.value_decl_type(decl, local)?;
+            value_type = Some(match value_type {
+                Some(previous) => previous.overload(decl_type),
+                None => decl_type,
+            });
+        }
+
+        value_type.ok_or_else(|| self.type_error(TypeError::UnknownValue(name.to_string()), span))
+    }
+
+    /// Type of the value `name` declared by `decl`
+    ///   Classes and enums are the objects they declare (see `class_value_type()`)
+    fn value_decl_type(&self, decl: &Decl, name: &JsWord) -> Result<Type, BindGenError> {
+        match decl {
+            Decl::Var(ref var) => {
+                let ann = var
+                    .decls
+                    .iter()
+                    .find_map(|declarator| match declarator.name {
+                        Pat::Ident(ref ident) if ident.sym == *name => {
+                            Some(ident.type_ann.as_ref())
+                        }
+                        _ => None,
+                    });
+
+                match ann.and_then(|ann| ann) {
+                    Some(ann) => {
+                        let typ = self.type_from_ann(ann)?;
+                        self.value_type(typ, ann.span)
+                    }
+
+                    None => Ok(Type::Any),
+                }
+            }
+
+            Decl::Fn(ref decl) => self.gen_fn_type(&decl.function),
+
+            Decl::Class(ref decl) => match self.gen_class_type(decl)? {
+                Type::Class(ref class_type) => Ok(class_value_type(class_type)),
+                typ => Ok(typ),
+            },
+
+            Decl::TsEnum(ref decl) => match self.gen_enum_type(decl)? {
+                Type::Enum(enum_type) => {
+                    let fields = enum_type
+                        .members
+                        .into_iter()
+                        .map(|member| (member.name, Type::Lit(member.value)))
+                        .collect();
+
+                    Ok(Type::Literal { fields })
+                }
+
+                typ => Ok(typ),
+            },
+
+            _ => unreachable!("Not a value declaration"),
+        }
+    }
+
+    /// Type named by a heritage clause (i.e. `Base<number>` of `extends Base<number>`)
+    fn bind_heritage(&self, heritage: &TsExprWithTypeArgs) -> Result<Type, BindGenError> {
+        self.bind_type(&TsType::TsTypeRef(TsTypeRef {
+            span: heritage.span,
+            type_name: heritage.expr.clone(),
+            type_params: heritage.type_args.clone(),
+        }))
+    }
+
+    /// Declaration of an extended class
+    ///   Generic superclasses are instantiated with the type arguments
+    fn class_heritage(&self, typ: &Type, span: Span) -> Result<ClassType, BindGenError> {
+        let (base, args): (&Type, &[Type]) = match typ {
+            Type::TyApp { ref base, ref args } => (base, args),
+            typ => (typ, &[]),
+        };
+
+        let declared = match base {
+            Type::Named {
+                ref name,
+                ref source,
+            } => self.resolve_named(name, source, span)?,
+
+            _ => return Err(self.type_error(TypeError::NonClassHeritage, span)),
+        };
+
+        match declared {
+            Type::Class(ref class_type) => Ok(class_type.instantiate(args)),
+
+            _ => Err(self.type_error(TypeError::NonClassHeritage, span)),
+        }
+    }
+
+    /// Fields of a record type (interface, type literal or class instance)
+    ///   Named types and instantiations of generic types are resolved to their declarations
+    fn record_fields(&self, typ: &Type, span: Span) -> Result<HashMap<JsWord, Type>, BindGenError> {
+        match typ {
+            Type::Literal { ref fields }
+            | Type::Interface { ref fields, .. }
+            | Type::Callable(CallableType { ref fields, .. }) => Ok(fields.clone()),
+
+            Type::Class(ref class_type) => Ok(class_type.instance_fields()),
+
+            Type::Named {
+                ref name,
+                ref source,
+            } => {
+                let declared = self.resolve_named(name, source, span)?;
+                self.record_fields(&declared, span)
+            }
+
+            Type::Unevaluated {
+                ref operator,
+                ref args,
+            } => {
+                let evaluated = self.eval_deferred(operator, args, span)?;
+                self.record_fields(&evaluated, span)
+            }
+
+            Type::TyApp { ref base, ref args } => {
+                let declared = match **base {
+                    Type::Named {
+                        ref name,
+                        ref source,
+                    } => self.resolve_named(name, source, span)?,
+
+                    _ => return Err(self.type_error(TypeError::NonRecordType, span)),
+                };
+
+                let (type_params, declared) = match declared {
+                    // Generic aliases are instantiated before evaluating (i.e. `type P<T> = Partial<T>`)
+                    Type::Forall { type_params, body } => {
+                        let args: HashMap<JsWord, Type> =
+                            type_params.into_iter().zip(args.iter().cloned()).collect();
+                        return self.record_fields(&body.substitute(&args), span);
+                    }
+
+                    Type::Interface {
+                        ref type_params, ..
+                    }
+                    | Type::Class(ClassType {
+                        ref type_params, ..
+                    }) => (type_params.clone(), declared.clone()),
+
+                    declared => (Vec::new(), declared),
+                };
+
+                let args: HashMap<JsWord, Type> =
+                    type_params.into_iter().zip(args.iter().cloned()).collect();
+
+                let fields = self
+                    .record_fields(&declared, span)?
+                    .into_iter()
+                    .map(|(field, t)| (field, t.substitute(&args)))
+                    .collect();
+
+                Ok(fields)
+            }
+
+            _ => Err(self.type_error(TypeError::NonRecordType, span)),
+        }
+    }
+
+    /// Merge the fields of intersected record types
+    ///   Fields declared by several members must have the same type
+    fn bind_intersection(&self, types: &[Box<TsType>], span: Span) -> Result<Type, BindGenError> {
+        let mut fields: HashMap<JsWord, Type> = HashMap::new();
+
+        for member in types.iter() {
+            let member_type = self.bind_type(member)?;
+
+            for (field, typ) in self.record_fields(&member_type, member.span())? {
+                match fields.get(&field) {
+                    Some(merged) if *merged != typ => {
+                        let error = TypeError::ConflictingIntersectionField(field.to_string());
+                        return Err(self.type_error(error, span));
+                    }
+
+                    _ => {
+                        fields.insert(field, typ);
+                    }
+                }
+            }
+        }
+
+        Ok(Type::Literal { fields })
+    }
+
+    /// Fields of an evaluated operand (see `record_fields()`)
+    ///   Type parameters are not known until instantiation
+    fn operand_fields(
+        &self,
+        typ: &Type,
+        span: Span,
+    ) -> Result<HashMap<JsWord, Type>, BindGenError> {
+        match typ {
+            Type::TypeVar(..) => Err(self.type_error(TypeError::UnevaluableType, span)),
+
+            typ => self.record_fields(typ, span),
+        }
+    }
+
+    /// Value type of an operand which is a dictionary (i.e. `number` of `{ [key: string]: number }`)
+    fn operand_dict_value(&self, typ: &Type, span: Span) -> Result<Option<Type>, BindGenError> {
+        match typ {
+            Type::Dict(ref value_type) => Ok(Some((**value_type).clone())),
+
+            Type::Named {
+                ref name,
+                ref source,
+            } => {
+                let declared = self.resolve_named(name, source, span)?;
+                self.operand_dict_value(&declared, span)
+            }
+
+            Type::Unevaluated {
+                ref operator,
+                ref args,
+            } => {
+                let evaluated = self.eval_deferred(operator, args, span)?;
+                self.operand_dict_value(&evaluated, span)
+            }
+
+            _ => Ok(None),
+        }
+    }
+
+    /// Keys named by a key type (i.e. `"a" | "b"` of `Pick<T, "a" | "b">`)
+    fn property_keys(&self, keys: &Type, span: Span) -> Result<PropertyKeys, BindGenError> {
+        match keys {
+            Type::String | Type::Number | Type::Any => Ok(PropertyKeys::Dict),
+
+            Type::Lit(ref lit_value) => {
+                let key = lit_key(lit_value)
+                    .ok_or_else(|| self.type_error(TypeError::NonKeyType, span))?;
+                Ok(PropertyKeys::Fields(vec![key]))
+            }
+
+            Type::LitUnion(ref lit_values) => {
+                let keys = lit_values
+                    .iter()
+                    .map(lit_key)
+                    .collect::<Option<Vec<_>>>()
+                    .ok_or_else(|| self.type_error(TypeError::NonKeyType, span))?;
+                Ok(PropertyKeys::Fields(keys))
+            }
+
+            // Unions with `string` or `number` are dictionary keys
+            Type::Union(ref members) => {
+                let mut keys = Vec::new();
+                for member in members.iter() {
+                    match self.property_keys(member, span)? {
+                        PropertyKeys::Dict => return Ok(PropertyKeys::Dict),
+                        PropertyKeys::Fields(member_keys) => keys.extend(member_keys),
+                    }
+                }
+
+                Ok(PropertyKeys::Fields(keys))
+            }
+
+            Type::Never => Ok(PropertyKeys::Fields(Vec::new())),
+
+            Type::Named {
+                ref name,
+                ref source,
+            } => {
+                let declared = self.resolve_named(name, source, span)?;
+                self.property_keys(&declared, span)
+            }
+
+            Type::Unevaluated {
+                ref operator,
+                ref args,
+            } => {
+                let evaluated = self.eval_deferred(operator, args, span)?;
+                self.property_keys(&evaluated, span)
+            }
+
+            Type::TypeVar(..) => Err(self.type_error(TypeError::UnevaluableType, span)),
+
+            _ => Err(self.type_error(TypeError::NonKeyType, span)),
+        }
+    }
+
+    /// Utility type, `keyof` or conditional type whose operands are instantiated
+    ///   (see `Type::Unevaluated`)
+    fn eval_deferred(&self, operator: &str, args: &[Type], span: Span) -> Result<Type, BindGenError> {
+        if args.iter().take(deferred_operands(operator)).any(is_type_var) {
+            return Err(self.type_error(TypeError::UnevaluableType, span));
+        }
+
+        match (operator, args) {
+            (KEYOF_OPERATOR, [typ]) => self.eval_keyof(typ, span),
+
+            (CONDITIONAL_OPERATOR, [check_type, extends_type, true_type, false_type]) => {
+                match self.is_assignable(check_type, extends_type, span)? {
+                    Some(true) => Ok(true_type.clone()),
+                    Some(false) => Ok(false_type.clone()),
+                    None => Err(self.type_error(TypeError::UnevaluableType, span)),
+                }
+            }
+
+            (KEYOF_OPERATOR, _) | (CONDITIONAL_OPERATOR, _) => {
+                Err(self.type_error(TypeError::UnevaluableType, span))
+            }
+
+            (utility, args) => self.eval_utility(utility, args, span),
+        }
+    }
+
+    /// Built-in utility types (see `BUILTIN_UTILITY_TYPES`)
+    ///   Records are evaluated to type literals and `Record<string, T>` to a dictionary
+    fn eval_utility(&self, name: &str, args: &[Type], span: Span) -> Result<Type, BindGenError> {
+        let arg = |index: usize| {
+            args.get(index)
+                .ok_or_else(|| self.type_error(TypeError::UnevaluableType, span))
+        };
+
+        // Operands which are type parameters are not known until instantiation
+        if args.iter().take(deferred_operands(name)).any(is_type_var) {
+            return Ok(Type::Unevaluated {
+                operator: name.into(),
+                args: args.to_vec(),
+            });
+        }
+
+        // Dictionaries map their value type (i.e. `Partial<Record<string, number>>`)
+        let dict_value = match name {
+            "Readonly" | "Record" => None,
+            _ => self.operand_dict_value(arg(0)?, span)?,
+        };
+
+        if let Some(value_type) = dict_value {
+            let value_type = match name {
+                "Partial" => optional(true, strip_optional(value_type)),
+                "Required" => strip_optional(value_type),
+
+                "Pick" => match self.property_keys(arg(1)?, span)? {
+                    PropertyKeys::Fields(keys) => {
+                        let fields = keys
+                            .into_iter()
+                            .map(|key| (key, value_type.clone()))
+                            .collect();
+
+                        return Ok(Type::Literal { fields });
+                    }
+
+                    PropertyKeys::Dict => value_type,
+                },
+
+                // Omitted keys are still keys of the dictionary
+                _ => value_type,
+            };
+
+            return Ok(Type::Dict(Box::new(value_type)));
+        }
+
+        match name {
+            "Partial" => {
+                let fields = self
+                    .operand_fields(arg(0)?, span)?
+                    .into_iter()
+                    .map(|(field, typ)| (field, optional(true, strip_optional(typ))))
+                    .collect();
+
+                Ok(Type::Literal { fields })
+            }
+
+            "Required" => {
+                let fields = self
+                    .operand_fields(arg(0)?, span)?
+                    .into_iter()
+                    .map(|(field, typ)| (field, strip_optional(typ)))
+                    .collect();
+
+                Ok(Type::Literal { fields })
+            }
+
+            // Pyret values are immutable
+            "Readonly" => arg(0).cloned(),
+
+            "Pick" => {
+                let mut fields = self.operand_fields(arg(0)?, span)?;
+                let keys = match self.property_keys(arg(1)?, span)? {
+                    PropertyKeys::Fields(keys) => keys,
+                    PropertyKeys::Dict => return Ok(Type::Literal { fields }),
+                };
 
-///
-/// Assumes type_scope is fully initialized (via init_type_scop::init())
-///
-pub fn construct_fn_type(
-    current_module: &CanonPath,
-    type_scope: &Scope<ItemState>,
-    function: &Function,
-) -> Result<Type, BindGenError> {
-    let session = Session {
-        path: current_module,
-        scope: type_scope,
-        self_id: None,
-    };
+                let mut picked = HashMap::new();
+                for key in keys.into_iter() {
+                    let typ = fields.remove(&key).ok_or_else(|| {
+                        self.type_error(TypeError::UnknownField(key.to_string()), span)
+                    })?;
+                    picked.insert(key, typ);
+                }
 
-    session.gen_fn_type(function)
-}
+                Ok(Type::Literal { fields: picked })
+            }
 
-///
-/// Assumes type_scope is fully initialized (via init_type_scop::init())
-///
-pub fn construct_type(
-    current_module: &CanonPath,
-    type_scope: &Scope<ItemState>,
-    decl: &Decl,
-) -> Result<Type, BindGenError> {
-    let self_id = get_type_name(decl);
+            "Omit" => {
+                let mut fields = self.operand_fields(arg(0)?, span)?;
+                match self.property_keys(arg(1)?, span)? {
+                    PropertyKeys::Fields(keys) => {
+                        for key in keys.iter() {
+                            fields.remove(key);
+                        }
+                    }
 
-    let session = Session {
-        path: current_module,
-        scope: type_scope,
-        self_id,
-    };
+                    PropertyKeys::Dict => fields.clear(),
+                }
 
-    match decl {
-        Decl::Class(ref decl) => session.gen_class_type(decl),
+                Ok(Type::Literal { fields })
+            }
 
-        Decl::TsInterface(ref decl) => session.gen_interface_type(decl),
+            "Record" => {
+                let value_type = arg(1)?;
+                match self.property_keys(arg(0)?, span)? {
+                    PropertyKeys::Fields(keys) => {
+                        let fields = keys
+                            .into_iter()
+                            .map(|key| (key, value_type.clone()))
+                            .collect();
 
-        Decl::TsTypeAlias(ref alias) => session.bind_type(&*alias.type_ann),
+                        Ok(Type::Literal { fields })
+                    }
 
-        Decl::TsEnum(TsEnumDecl { id, .. }) => {
-            let typ = Type::Opaque {
-                name: id.sym.clone(),
-                origin: session.path.clone(),
-            };
+                    PropertyKeys::Dict => Ok(Type::Dict(Box::new(value_type.clone()))),
+                }
+            }
 
-            Ok(typ)
+            _ => unreachable!("Not a utility type: {}", name),
         }
-
-        _ => unreachable!(),
     }
-}
 
-fn get_type_name(decl: &Decl) -> Option<&JsWord> {
-    match decl {
-        Decl::Class(ref decl) => Some(&decl.ident.sym),
-        Decl::TsInterface(ref decl) => Some(&decl.id.sym),
-        Decl::TsTypeAlias(ref decl) => Some(&decl.id.sym),
-        Decl::TsEnum(ref decl) => Some(&decl.id.sym),
+    /// `keyof T` is the union of the field names of `T` (`string` for dictionaries)
+    fn eval_keyof(&self, typ: &Type, span: Span) -> Result<Type, BindGenError> {
+        match typ {
+            Type::Dict(..) => return Ok(Type::String),
 
-        _ => None,
-    }
-}
+            Type::TypeVar(..) => {
+                return Ok(Type::Unevaluated {
+                    operator: KEYOF_OPERATOR.into(),
+                    args: vec![typ.clone()],
+                });
+            }
 
-fn ann_from_pat(p: &Pat) -> Option<&TsTypeAnn> {
-    match p {
-        Pat::Ident(ref pat) => pat.type_ann.as_ref(),
-        Pat::Array(ref pat) => pat.type_ann.as_ref(),
-        Pat::Rest(ref pat) => pat.type_ann.as_ref(),
-        Pat::Object(ref pat) => pat.type_ann.as_ref(),
-        Pat::Assign(ref pat) => pat.type_ann.as_ref(),
-        Pat::Invalid(..) => None,
-        Pat::Expr(..) => None,
-    }
-}
+            _ => (),
+        }
 
-fn ann_from_fn_param(p: &TsFnParam) -> Option<&TsTypeAnn> {
-    match p {
-        TsFnParam::Ident(ref pat) => pat.type_ann.as_ref(),
-        TsFnParam::Array(ref pat) => pat.type_ann.as_ref(),
-        TsFnParam::Object(ref pat) => pat.type_ann.as_ref(),
-        TsFnParam::Rest(ref pat) => pat.type_ann.as_ref(),
-    }
-}
+        let mut keys: Vec<JsWord> = self.operand_fields(typ, span)?.into_keys().collect();
+        keys.sort_by_key(|key| key.to_string());
 
-fn ident_from_key(key: &Expr) -> &Ident {
-    match key {
-        Expr::Ident(ref ident) => ident,
+        let mut lit_values: Vec<LitValue> = keys.into_iter().map(LitValue::String).collect();
+        let keyof = match lit_values.len() {
+            0 => Type::Never,
+            1 => Type::Lit(lit_values.remove(0)),
+            _ => Type::LitUnion(lit_values),
+        };
 
-        _ => todo!("Unsupported key expr (not ident)"),
+        Ok(keyof)
     }
-}
 
-struct Session<'a> {
-    path: &'a CanonPath,
-    self_id: Option<&'a JsWord>,
-    scope: &'a Scope<ItemState>,
-}
+    /// Type of the indexed fields (i.e. `T["a"]`) or elements (i.e. `T[number]`)
+    ///   Several indexed fields are a union of their types
+    fn eval_indexed_access(
+        &self,
+        obj_type: &Type,
+        index_type: &Type,
+        span: Span,
+    ) -> Result<Type, BindGenError> {
+        // Named arrays, tuples and dictionaries are indexed through their declarations
+        if let Type::Named {
+            ref name,
+            ref source,
+        } = obj_type
+        {
+            let declared = self.resolve_named(name, source, span)?;
+            return self.eval_indexed_access(&declared, index_type, span);
+        }
 
-impl<'a> Session<'a> {
-    fn get_item_state(&self, key: &JsWord) -> ItemState {
-        self.scope
-            .get(key)
-            .cloned()
-            .or(self.self_id.map(|_| ItemState::Rooted))
-            .expect(&format!(
-                "[{}] Type '{}' not in scope",
-                self.path.as_path().display(),
-                key
-            ))
-    }
+        match (obj_type, index_type) {
+            (Type::UnsizedArray(ref elem_type), Type::Number)
+            | (Type::UnsizedArray(ref elem_type), Type::Lit(LitValue::Number(..))) => {
+                return Ok((**elem_type).clone());
+            }
 
-    fn gen_interface_type(&self, decl: &TsInterfaceDecl) -> Result<Type, BindGenError> {
-        // TODO: Type parameters
-        let mut fields: HashMap<JsWord, Type> = HashMap::new();
+            // Only non-negative integer indices are elements (i.e. not `T[-1]` or `T[1.5]`)
+            (Type::Tuple(ref elem_types), Type::Lit(LitValue::Number(index))) => {
+                let elem_type = if *index >= 0.0 && index.fract() == 0.0 {
+                    elem_types.get(*index as usize).cloned()
+                } else {
+                    None
+                };
 
-        for ts_type_element in decl.body.body.iter() {
-            self.gen_type_element(ts_type_element, |sym, typ| {
-                fields.insert(sym, typ);
-            })?;
-        }
+                return elem_type.ok_or_else(|| {
+                    self.type_error(TypeError::UnknownField(index.to_string()), span)
+                });
+            }
 
-        Ok(Type::Interface {
-            name: decl.id.sym.clone(),
-            origin: self.path.clone(),
-            fields,
-        })
-    }
+            (Type::Tuple(ref elem_types), Type::Number) => return Ok(union_of(elem_types.clone())),
 
-    fn gen_class_type(&self, decl: &ClassDecl) -> Result<Type, BindGenError> {
-        // TODO: Type parameters
-        let mut members: HashMap<JsWord, Type> = HashMap::new();
-        let mut constructors: Vec<FnType> = Vec::new();
+            // Keys of a dictionary are all of the same type (i.e. `Dict["a"]` is the value type)
+            (Type::Dict(ref value_type), _) => {
+                self.property_keys(index_type, span)?;
+                return Ok((**value_type).clone());
+            }
 
-        for class_member in decl.class.body.iter() {
-            match class_member {
-                ClassMember::ClassProp(ref prop) => match *prop.key {
-                    Expr::Ident(ref ident) => {
-                        let typ = ident
-                            .type_ann
-                            .as_ref()
-                            .map(|ann| self.type_from_ann(ann))
-                            .transpose()?
-                            .unwrap_or(Type::Any);
-
-                        members.insert(ident.sym.clone(), typ);
-                    }
+            _ => (),
+        }
 
-                    _ => todo!("Unsupported key expr (not ident)"),
-                },
+        let keys = match self.property_keys(index_type, span)? {
+            PropertyKeys::Fields(keys) => keys,
+            PropertyKeys::Dict => return Err(self.type_error(TypeError::UnevaluableType, span)),
+        };
+
+        let fields = self.operand_fields(obj_type, span)?;
+        let types =
+            keys.into_iter()
+                .map(|key| {
+                    fields.get(&key).cloned().ok_or_else(|| {
+                        self.type_error(TypeError::UnknownField(key.to_string()), span)
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
 
-                ClassMember::Constructor(ref constructor) => {
-                    let mut params = Vec::new();
-                    for param in constructor.params.iter() {
-                        let ann = match param {
-                            ParamOrTsParamProp::Param(ref param) => ann_from_pat(&param.pat),
-                            ParamOrTsParamProp::TsParamProp(..) => todo!("TsParamProp"),
-                        };
+        Ok(union_of(types))
+    }
 
-                        let param_typ = ann
-                            .map(|ann| self.type_from_ann(ann))
-                            .transpose()?
-                            .unwrap_or(Type::Any);
-                        params.push(param_typ);
-                    }
-                    constructors.push(FnType {
-                        params,
-                        return_type: Box::new(Type::Any),
-                    });
-                }
+    /// `{ [K in Keys]: T }` binds `T` once per key (with `K` bound to the key literal)
+    ///   Optional fields of `T[K]` stay optional unless the mapped type removes the modifier (`-?`)
+    fn eval_mapped(&self, mapped: &TsMappedType) -> Result<Type, BindGenError> {
+        let span = mapped.span;
+        let key_param = mapped.type_param.name.sym.clone();
+        let keys = mapped
+            .type_param
+            .constraint
+            .as_ref()
+            .ok_or_else(|| self.type_error(TypeError::UnevaluableType, span))?;
+        let keys = self.bind_type(keys)?;
+
+        let bind_value = |key: Type| {
+            let mut session = self.clone();
+            session.type_vars.retain(|type_var| *type_var != key_param);
+            session.type_args.insert(key_param.clone(), key);
+
+            let typ = mapped
+                .type_ann
+                .as_ref()
+                .map(|type_ann| session.bind_type(type_ann))
+                .transpose()?
+                .unwrap_or(Type::Any);
 
-                ClassMember::Method(ref method) => {
-                    // TODO: Self parameter
-                    let typ = self.gen_fn_type(&method.function)?;
-                    let key = match method.key {
-                        PropName::Ident(ref ident) => ident.sym.clone(),
+            let typ = match mapped.optional {
+                Some(TruePlusMinus::True) | Some(TruePlusMinus::Plus) => {
+                    optional(true, strip_optional(typ))
+                }
+                Some(TruePlusMinus::Minus) => strip_optional(typ),
+                None => typ,
+            };
 
-                        ref x => todo!("Unsupported prop name kind: {:?}", x),
-                    };
+            Ok(typ)
+        };
 
-                    members.insert(key, typ);
+        match self.property_keys(&keys, span)? {
+            PropertyKeys::Fields(keys) => {
+                let mut fields = HashMap::new();
+                for key in keys.into_iter() {
+                    let typ = bind_value(Type::Lit(LitValue::String(key.clone())))?;
+                    fields.insert(key, typ);
                 }
 
-                x => todo!("Handle ClassMember: {:?}", x),
+                Ok(Type::Literal { fields })
             }
-        }
 
-        Ok(Type::Class(ClassType {
-            name: decl.ident.sym.clone(),
-            origin: self.path.clone(),
-            constructors,
-            members,
-        }))
+            PropertyKeys::Dict => {
+                let value_type = bind_value(Type::String)?;
+                Ok(Type::Dict(Box::new(strip_optional(value_type))))
+            }
+        }
     }
 
-    fn gen_fn_type(&self, function: &Function) -> Result<Type, BindGenError> {
-        let mut params: Vec<Type> = Vec::new();
+    /// `C extends E ? T : F` is `T` or `F` if `C` is known to be assignable to `E` (or not)
+    ///   Conditions on type parameters are deferred until instantiation (see `CONDITIONAL_OPERATOR`)
+    fn eval_conditional(&self, conditional: &TsConditionalType) -> Result<Type, BindGenError> {
+        let span = conditional.span;
+        let check_type = self.bind_type(&conditional.check_type)?;
+        let extends_type = self.bind_type(&conditional.extends_type)?;
+
+        match self.is_assignable(&check_type, &extends_type, span)? {
+            Some(true) => self.bind_type(&conditional.true_type),
+            Some(false) => self.bind_type(&conditional.false_type),
+
+            None if self.mentions_type_var(&check_type) || self.mentions_type_var(&extends_type) => {
+                Ok(Type::Unevaluated {
+                    operator: CONDITIONAL_OPERATOR.into(),
+                    args: vec![
+                        check_type,
+                        extends_type,
+                        self.bind_type(&conditional.true_type)?,
+                        self.bind_type(&conditional.false_type)?,
+                    ],
+                })
+            }
 
-        let return_type = function
-            .return_type
-            .as_ref()
-            .map(|ann| self.type_from_ann(ann))
-            .transpose()?
-            .unwrap_or(Type::Any);
+            None => Err(self.type_error(TypeError::UnevaluableType, span)),
+        }
+    }
 
-        for param in function.params.iter() {
-            let ann = ann_from_pat(&param.pat);
+    /// Whether a type parameter in scope occurs in the type (i.e. `T[]`)
+    fn mentions_type_var(&self, typ: &Type) -> bool {
+        let erased: HashMap<JsWord, Type> = self
+            .type_vars
+            .iter()
+            .map(|type_var| (type_var.clone(), Type::Never))
+            .collect();
 
-            let typ = ann
-                .map(|ann| self.type_from_ann(ann))
-                .transpose()?
-                .unwrap_or(Type::Any);
+        typ.substitute(&erased) != *typ
+    }
 
-            params.push(typ);
+    /// Whether values of `source` are values of `target` (None if it cannot be decided)
+    ///   Records are compared structurally by their fields
+    fn is_assignable(
+        &self,
+        source: &Type,
+        target: &Type,
+        span: Span,
+    ) -> Result<Option<bool>, BindGenError> {
+        if source == target {
+            return Ok(Some(true));
         }
 
-        Ok(Type::Fn(FnType {
-            params,
-            return_type: Box::new(return_type),
-        }))
-    }
+        let all = |results: Vec<Option<bool>>| {
+            if results.iter().all(|result| *result == Some(true)) {
+                Some(true)
+            } else if results.contains(&Some(false)) {
+                Some(false)
+            } else {
+                None
+            }
+        };
+
+        let any = |results: Vec<Option<bool>>| {
+            if results.contains(&Some(true)) {
+                Some(true)
+            } else if results.iter().all(|result| *result == Some(false)) {
+                Some(false)
+            } else {
+                None
+            }
+        };
 
-    fn gen_type_element<F>(&self, element: &TsTypeElement, mut f: F) -> Result<(), BindGenError>
-    where
-        F: FnMut(JsWord, Type) -> (),
-    {
-        match element {
-            TsTypeElement::TsPropertySignature(ref signature) => {
-                let ident = ident_from_key(&*signature.key);
-                let typ = ident
-                    .type_ann
-                    .as_ref()
-                    .map(|ann| self.type_from_ann(ann))
-                    .transpose()?
-                    .unwrap_or(Type::Any);
+        let result = match (source, target) {
+            (_, Type::Any) | (Type::Never, _) => Some(true),
 
-                f(ident.sym.clone(), typ);
-                Ok(())
+            (Type::Any, _) | (Type::TypeVar(..), _) | (_, Type::TypeVar(..)) => None,
+
+            (Type::Union(ref members), target) => all(members
+                .iter()
+                .map(|member| self.is_assignable(member, target, span))
+                .collect::<Result<_, _>>()?),
+
+            (Type::Optional(ref typ), target) => all(vec![
+                self.is_assignable(typ, target, span)?,
+                self.is_assignable(&Type::Undefined, target, span)?,
+            ]),
+
+            (Type::LitUnion(ref lit_values), target) => all(lit_values
+                .iter()
+                .map(|lit_value| self.is_assignable(&Type::Lit(lit_value.clone()), target, span))
+                .collect::<Result<_, _>>()?),
+
+            (source, Type::Union(ref members)) => any(members
+                .iter()
+                .map(|member| self.is_assignable(source, member, span))
+                .collect::<Result<_, _>>()?),
+
+            (Type::Lit(ref lit_value), Type::LitUnion(ref lit_values)) => {
+                Some(lit_values.contains(lit_value))
             }
 
-            // TODO: Log that TsIndexSignature was skipped
-            TsTypeElement::TsIndexSignature(..) => Ok(()),
+            (Type::Lit(ref lit_value), target) if is_primitive(target) => {
+                Some(lit_value.base_type() == *target)
+            }
 
-            TsTypeElement::TsMethodSignature(ref signature) => {
-                let ident = ident_from_key(&*signature.key);
-                let return_type = signature
-                    .type_ann
-                    .as_ref()
-                    .map(|ann| self.type_from_ann(ann))
-                    .transpose()?
-                    .unwrap_or(Type::Any);
+            (Type::UnsizedArray(ref source), Type::UnsizedArray(ref target)) => {
+                self.is_assignable(source, target, span)?
+            }
 
-                let params = signature
-                    .params
-                    .iter()
-                    .map(|fn_param| {
-                        let ann = ann_from_fn_param(fn_param);
-                        Ok(ann
-                            .map(|ann| self.type_from_ann(ann))
-                            .transpose()?
-                            .unwrap_or(Type::Any))
-                    })
-                    .collect::<Result<Vec<Type>, _>>()?;
+            (source, Type::Object) => Some(!is_primitive(source)),
 
-                let typ = Type::Fn(FnType {
-                    params,
-                    return_type: Box::new(return_type),
-                });
+            (source, target) if is_primitive(source) || is_primitive(target) => Some(false),
 
-                f(ident.sym.clone(), typ);
+            // Every required field of the target is a field of the source
+            (source, target) => {
+                let (source_fields, target_fields) = match (
+                    self.record_fields(source, span),
+                    self.record_fields(target, span),
+                ) {
+                    (Ok(source_fields), Ok(target_fields)) => (source_fields, target_fields),
+                    _ => return Ok(None),
+                };
 
-                Ok(())
+                let mut results = Vec::new();
+                for (field, target_type) in target_fields.iter() {
+                    let result = match (source_fields.get(field), target_type) {
+                        (Some(source_type), target_type) => {
+                            self.is_assignable(source_type, target_type, span)?
+                        }
+                        (None, Type::Optional(..)) => Some(true),
+                        (None, _) => Some(false),
+                    };
+
+                    results.push(result);
+                }
+
+                all(results)
             }
+        };
 
-            ref x => todo!(
-                "[{:?}]Handle TsTypeElement: {:?}",
-                self.path.as_path().display(),
-                x
-            ),
-        }
+        Ok(result)
     }
 
     fn type_from_ann(&self, ann: &TsTypeAnn) -> Result<Type, BindGenError> {
@@ -362,38 +2353,24 @@ impl<'a> Session<'a> {
                 ref params,
                 ref type_params,
                 type_ann: ref return_ann,
-            })) => {
-                let mut new_params = Vec::new();
-                for param in params {
-                    let ann = ann_from_fn_param(param);
-
-                    let typ = ann
-                        .map(|ann| self.type_from_ann(ann))
-                        .transpose()?
-                        .unwrap_or(Type::Any);
-
-                    new_params.push(typ);
-                }
-
-                let return_type = self.type_from_ann(return_ann)?;
-
-                Ok(Type::Fn(FnType {
-                    params: new_params,
-                    return_type: Box::new(return_type),
-                }))
-            }
+            })) => self.gen_signature(params, type_params.as_ref(), Some(return_ann)),
 
+            // `new (s: string) => Foo` is the object type `{ new (s: string): Foo }`
             TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsConstructorType(
                 TsConstructorType {
                     ref span,
                     ref params,
                     ref type_params,
-                    ref type_ann,
+                    type_ann: ref return_ann,
                 },
             )) => {
-                // What is type_ann
-                // Is type_ann the return type?
-                todo!("ts constructor");
+                let signature =
+                    self.gen_signature(params, type_params.as_ref(), Some(return_ann))?;
+
+                Ok(Type::Callable(CallableType {
+                    construct_signatures: vec![signature],
+                    ..CallableType::default()
+                }))
             }
 
             TsType::TsTypeRef(TsTypeRef {
@@ -403,13 +2380,63 @@ impl<'a> Session<'a> {
             }) => {
                 // Can assume that all possible types are in scope or is self_id
 
+                let type_args = type_params
+                    .as_ref()
+                    .map(|instantiation| {
+                        instantiation
+                            .params
+                            .iter()
+                            .map(|arg| self.bind_type(arg))
+                            .collect::<Result<Vec<_>, _>>()
+                    })
+                    .transpose()?
+                    .unwrap_or(Vec::new());
+
+                let apply = |base: Type, args: Vec<Type>| {
+                    if args.is_empty() {
+                        base
+                    } else {
+                        Type::TyApp {
+                            base: Box::new(base),
+                            args,
+                        }
+                    }
+                };
+
                 let name = match type_name {
                     TsEntityName::Ident(ref i) => &i.sym,
 
-                    TsEntityName::TsQualifiedName(..) => todo!("TsQualifiedName"),
+                    // Namespace members are rooted in the namespace
+                    TsEntityName::TsQualifiedName(ref qualified) => {
+                        let typ = Type::Named {
+                            name: qualified.right.sym.clone(),
+                            source: self.get_namespace(&qualified.left, *span)?,
+                        };
+
+                        return Ok(apply(typ, type_args));
+                    }
                 };
 
-                let typ = match self.get_item_state(name) {
+                if let Some(typ) = self.type_args.get(name) {
+                    return Ok(typ.clone());
+                }
+
+                if self.is_type_var(name) {
+                    return Ok(Type::TypeVar(name.clone()));
+                }
+
+                // `Array<T>` is `T[]` unless `Array` is declared
+                if !self.is_in_scope(name) && BUILTIN_ARRAY_TYPES.contains(&&**name) {
+                    if let [elem_type] = &type_args[..] {
+                        return Ok(Type::UnsizedArray(Box::new(elem_type.clone())));
+                    }
+                }
+
+                if !self.is_in_scope(name) && BUILTIN_UTILITY_TYPES.contains(&&**name) {
+                    return self.eval_utility(name, &type_args, *span);
+                }
+
+                let typ = match self.get_item_state(name, *span)? {
                     ItemState::Rooted => Type::Named {
                         name: name.clone(),
                         source: self.path.clone(),
@@ -423,21 +2450,39 @@ impl<'a> Session<'a> {
                     },
                 };
 
-                Ok(typ)
+                Ok(apply(typ, type_args))
             }
 
-            TsType::TsTypeQuery(..) => {
-                todo!("ts type query");
-            }
+            TsType::TsTypeQuery(TsTypeQuery {
+                ref span,
+                ref expr_name,
+            }) => match expr_name {
+                TsTypeQueryExpr::TsEntityName(ref entity) => self.resolve_query(entity, *span),
+
+                // `typeof import("./m")`
+                TsTypeQueryExpr::Import(..) => {
+                    Err(self.type_error(TypeError::UnevaluableType, *span))
+                }
+            },
 
             TsType::TsTypeLit(ref lit) => {
                 let mut fields = HashMap::new();
                 for type_element in lit.members.iter() {
                     self.gen_type_element(type_element, |sym, typ| {
-                        fields.insert(sym, typ);
+                        insert_member(&mut fields, sym, typ);
                     })?;
                 }
 
+                if let Some(callable) = self.callable_type(&lit.members)? {
+                    return Ok(Type::Callable(CallableType { fields, ..callable }));
+                }
+
+                if fields.is_empty() {
+                    if let Some(value_type) = self.index_signature(&lit.members)? {
+                        return Ok(Type::Dict(Box::new(value_type)));
+                    }
+                }
+
                 Ok(Type::Literal { fields })
             }
 
@@ -453,64 +2498,124 @@ impl<'a> Session<'a> {
                 ref span,
                 ref elem_types,
             }) => {
-                // Tuple types are fixed-length arrays (at init)
-                todo!("ts tuple type");
-            }
+                let rest_index = elem_types
+                    .iter()
+                    .position(|elem| matches!(elem.ty, TsType::TsRestType(..)));
+
+                let elem_types = elem_types
+                    .iter()
+                    .map(|elem| self.bind_type(&elem.ty))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                let rest_index = match rest_index {
+                    Some(rest_index) => rest_index,
+                    None => return Ok(Type::Tuple(elem_types)),
+                };
+
+                // Pyret tuples are fixed-length, tuples with a rest element are arrays
+                //   of its element type (i.e. `[number, ...number[]]` is `number[]`)
+                let rest_elem_type = match elem_types[rest_index] {
+                    Type::UnsizedArray(ref elem_type) => elem_type,
+                    _ => return Err(self.type_error(TypeError::UnevaluableType, *span)),
+                };
+
+                for (index, elem_type) in elem_types.iter().enumerate() {
+                    let elem_type = match elem_type {
+                        _ if index == rest_index => continue,
+                        Type::Optional(ref typ) => typ,
+                        typ => typ,
+                    };
+
+                    if self.is_assignable(elem_type, rest_elem_type, *span)? != Some(true) {
+                        return Err(self.type_error(TypeError::UnevaluableType, *span));
+                    }
+                }
 
-            TsType::TsOptionalType(..) => {
-                todo!("ts optional types not supported");
+                Ok(elem_types[rest_index].clone())
             }
 
-            TsType::TsRestType(..) => {
-                todo!("ts rest types not supported");
+            // Optional tuple element
+            TsType::TsOptionalType(TsOptionalType { ref type_ann, .. }) => {
+                Ok(Type::Optional(Box::new(self.bind_type(type_ann)?)))
             }
 
+            // Rest tuple element (i.e. `...number[]`)
+            TsType::TsRestType(TsRestType { ref type_ann, .. }) => self.bind_type(type_ann),
+
             TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(
                 TsUnionType {
                     ref span,
                     ref types,
                 },
             )) => {
-                // TODO: How to bind union types?
-                // Keep opaque for now
-                Ok(Type::Union)
+                if let Some(data_union) = self.bind_data_union(types)? {
+                    return Ok(Type::DataUnion(data_union));
+                }
+
+                if let Some(lit_values) = lit_union(types) {
+                    return Ok(Type::LitUnion(lit_values));
+                }
+
+                let members = types
+                    .iter()
+                    .map(|member| self.bind_type(member))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok(Type::Union(members))
             }
 
             TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsIntersectionType(
-                ..,
-            )) => {
-                todo!("ts intersection types not supported");
-            }
+                TsIntersectionType {
+                    ref span,
+                    ref types,
+                },
+            )) => self.bind_intersection(types, *span),
 
-            TsType::TsConditionalType(..) => {
-                todo!("ts conditional types not supported");
-            }
+            TsType::TsConditionalType(ref conditional) => self.eval_conditional(conditional),
 
-            TsType::TsInferType(..) => {
-                todo!("ts infer type not supported");
+            // Only bound by conditional types (which are evaluated without inference)
+            TsType::TsInferType(TsInferType { ref span, .. }) => {
+                Err(self.type_error(TypeError::UnevaluableType, *span))
             }
 
             TsType::TsParenthesizedType(TsParenthesizedType {
                 ref span,
                 ref type_ann,
-            }) => {
-                todo!("parenthesized type");
-            }
+            }) => self.bind_type(type_ann),
 
-            TsType::TsTypeOperator(..) => {
-                todo!("type operators not supported");
-            }
+            TsType::TsTypeOperator(TsTypeOperator {
+                ref span,
+                ref op,
+                ref type_ann,
+            }) => match op {
+                TsTypeOperatorOp::KeyOf => {
+                    let typ = self.bind_type(type_ann)?;
+                    self.eval_keyof(&typ, *span)
+                }
 
-            TsType::TsIndexedAccessType(..) => {
-                todo!("ts indexed access type not supported");
-            }
+                // Pyret values are immutable
+                TsTypeOperatorOp::ReadOnly => self.bind_type(type_ann),
 
-            TsType::TsMappedType(..) => {
-                todo!("ts mapped type not supported");
+                // `unique symbol`
+                TsTypeOperatorOp::Unique => Err(self.type_error(TypeError::UnevaluableType, *span)),
+            },
+
+            TsType::TsIndexedAccessType(TsIndexedAccessType {
+                ref span,
+                ref obj_type,
+                ref index_type,
+                ..
+            }) => {
+                let obj_type = self.bind_type(obj_type)?;
+                let index_type = self.bind_type(index_type)?;
+                self.eval_indexed_access(&obj_type, &index_type, *span)
             }
 
+            TsType::TsMappedType(ref mapped) => self.eval_mapped(mapped),
+
+            // Template literal types without substitutions are strings
             TsType::TsLitType(TsLitType { ref span, ref lit }) => {
-                todo!("ts lit type");
+                Ok(lit_value(lit).map(Type::Lit).unwrap_or(Type::String))
             }
 
             TsType::TsTypePredicate(..) => {
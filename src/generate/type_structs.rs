@@ -4,22 +4,38 @@ use swc_atoms::JsWord;
 
 use super::structures::CanonPath;
 
-#[derive(Debug, Clone)]
+/// Operator of deferred conditional types (see `Type::Unevaluated`)
+///   The arguments of `C extends E ? T : F` are `[C, E, T, F]`
+pub const CONDITIONAL_OPERATOR: &str = "extends";
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum Type {
     Named {
         name: JsWord,
         source: CanonPath,
     },
     Fn(FnType),
+    /// Function declared with several signatures (in declaration order)
+    ///   Each signature is a function type (which may be generic)
+    Overloaded(Vec<Type>),
     Class(ClassType),
     Interface {
         name: JsWord,
         origin: CanonPath,
+        type_params: Vec<JsWord>,
+
+        /// Fields including those of the extended interfaces
         fields: HashMap<JsWord, Type>,
+
+        /// Extended interfaces (i.e. `Base` of `interface Derived extends Base`)
+        extends: Vec<Type>,
     },
     Literal {
         fields: HashMap<JsWord, Type>,
     },
+    /// Object type with call or construct signatures
+    ///   (i.e. `{ (x: number): number; new (s: string): Foo; prop: T }` or `new (s: string) => Foo`)
+    Callable(CallableType),
     Alias {
         name: JsWord,
         aliasing_type: Box<Type>,
@@ -28,9 +44,43 @@ pub enum Type {
         name: JsWord,
         origin: CanonPath,
     },
+    /// Type parameter in scope (i.e. `T` of `function foo<T>(x: T): T`)
+    TypeVar(JsWord),
+    /// Instantiation of a generic type (i.e. `Promise<number>`)
+    TyApp {
+        base: Box<Type>,
+        args: Vec<Type>,
+    },
+    /// Generic function or type alias (i.e. `function foo<T>(x: T): T`)
+    ///   Generic interfaces and classes carry their own type parameters
+    Forall {
+        type_params: Vec<JsWord>,
+        body: Box<Type>,
+    },
     UnsizedArray(Box<Type>),
-    Array(Box<Type>, usize),
-    Union,
+    /// Fixed-length array with per-element types (i.e. `[number, string]`)
+    Tuple(Vec<Type>),
+    /// Object used as a dictionary (i.e. `{ [key: string]: number }`)
+    ///   Numeric keys (i.e. `[index: number]`) are strings at runtime
+    Dict(Box<Type>),
+    /// Optional property or tuple element (i.e. `x?: number`)
+    Optional(Box<Type>),
+    /// Union of types (i.e. `number | string`)
+    Union(Vec<Type>),
+    /// Union of literal types (i.e. `"left" | "right" | "center"`)
+    LitUnion(Vec<LitValue>),
+    /// Union of object types tagged by a shared literal field
+    ///   (i.e. `{ kind: "circle", radius: number } | { kind: "square", size: number }`)
+    DataUnion(DataUnion),
+    /// Enum declaration (including `const enum`)
+    Enum(EnumType),
+    /// Utility type, `keyof` or conditional type applied to a type parameter
+    ///   (i.e. `Partial<T>` of `function f<T>(x: Partial<T>)`, see `CONDITIONAL_OPERATOR`)
+    ///   Evaluated once the type parameter is instantiated (bound as `any` until then)
+    Unevaluated {
+        operator: JsWord,
+        args: Vec<Type>,
+    },
     Boolean,
     Number,
     String,
@@ -40,18 +90,694 @@ pub enum Type {
     Never,
     Undefined,
     Null,
+    /// Literal type (i.e. `"FooBrand"`, `0`, `true`)
+    Lit(LitValue),
+}
+
+impl Type {
+    /// Quantify the type over the type parameters (if there are any)
+    pub fn forall(type_params: Vec<JsWord>, body: Type) -> Type {
+        if type_params.is_empty() {
+            body
+        } else {
+            Type::Forall {
+                type_params,
+                body: Box::new(body),
+            }
+        }
+    }
+
+    /// Add a signature to a function declared several times (see `Type::Overloaded`)
+    ///   Declarations which are not functions replace the previous declaration
+    pub fn overload(self, signature: Type) -> Type {
+        if !signature.is_fn() {
+            return signature;
+        }
+
+        match self {
+            Type::Overloaded(mut signatures) => {
+                signatures.push(signature);
+                Type::Overloaded(signatures)
+            }
+
+            previous if previous.is_fn() => Type::Overloaded(vec![previous, signature]),
+
+            _ => signature,
+        }
+    }
+
+    /// Merge a declaration with a previous declaration of the same name
+    ///   (i.e. repeated `interface Foo` blocks or `class Foo` and `interface Foo`)
+    ///   Declarations which cannot be merged (i.e. `type Foo` and `interface Foo`) conflict
+    pub fn merge(self, declaration: Type) -> Option<Type> {
+        match (self, declaration) {
+            (
+                Type::Interface {
+                    name,
+                    origin,
+                    type_params,
+                    mut fields,
+                    mut extends,
+                },
+                Type::Interface {
+                    fields: merged_fields,
+                    extends: merged_extends,
+                    ..
+                },
+            ) => {
+                merge_fields(&mut fields, merged_fields);
+                extends.extend(merged_extends);
+
+                Some(Type::Interface {
+                    name,
+                    origin,
+                    type_params,
+                    fields,
+                    extends,
+                })
+            }
+
+            // Interface fields are instance members not declared by the class
+            (
+                Type::Class(mut class),
+                Type::Interface {
+                    fields, extends, ..
+                },
+            )
+            | (
+                Type::Interface {
+                    fields, extends, ..
+                },
+                Type::Class(mut class),
+            ) => {
+                for (key, typ) in fields.into_iter() {
+                    class
+                        .members
+                        .entry(key)
+                        .or_insert_with(|| Member::declared(typ));
+                }
+                class.implements.extend(extends);
+
+                Some(Type::Class(class))
+            }
+
+            (Type::Callable(mut callable), Type::Callable(merged)) => {
+                callable.merge(merged);
+                Some(Type::Callable(callable))
+            }
+
+            (Type::Callable(mut callable), Type::Interface { fields, .. })
+            | (Type::Interface { fields, .. }, Type::Callable(mut callable)) => {
+                callable.merge(CallableType {
+                    fields,
+                    ..CallableType::default()
+                });
+                Some(Type::Callable(callable))
+            }
+
+            (Type::Enum(mut enum_type), Type::Enum(merged)) => {
+                enum_type.members.extend(merged.members);
+                Some(Type::Enum(enum_type))
+            }
+
+            _ => None,
+        }
+    }
+
+    /// Signatures of a (possibly overloaded) function type
+    fn signatures(self) -> Vec<Type> {
+        match self {
+            Type::Overloaded(signatures) => signatures,
+            signature => vec![signature],
+        }
+    }
+
+    /// The type is a (possibly generic) function type
+    pub fn is_fn(&self) -> bool {
+        match self {
+            Type::Fn(..) => true,
+            Type::Forall { ref body, .. } => body.is_fn(),
+            _ => false,
+        }
+    }
+
+    /// The type is a (possibly generic or overloaded) function type
+    pub fn is_callable(&self) -> bool {
+        match self {
+            Type::Overloaded(..) => true,
+            typ => typ.is_fn(),
+        }
+    }
+
+    /// Declaration of a generic type alias or interface instantiated by `args`
+    ///   (i.e. the fields of `Box<number>` for `interface Box<T> { value: T }`)
+    ///   Other declarations are returned as is
+    pub fn instantiate(&self, args: &[Type]) -> Type {
+        let type_params = match self {
+            Type::Interface {
+                ref type_params, ..
+            }
+            | Type::Forall {
+                ref type_params, ..
+            } => type_params,
+
+            _ => return self.clone(),
+        };
+
+        let args: HashMap<JsWord, Type> = type_params
+            .iter()
+            .cloned()
+            .zip(args.iter().cloned())
+            .collect();
+
+        match self {
+            Type::Forall { ref body, .. } => body.substitute(&args),
+
+            Type::Interface {
+                ref name,
+                ref origin,
+                ref fields,
+                ref extends,
+                ..
+            } => Type::Interface {
+                name: name.clone(),
+                origin: origin.clone(),
+                type_params: Vec::new(),
+                fields: fields.clone(),
+                extends: extends.clone(),
+            }
+            .substitute(&args),
+
+            _ => unreachable!(),
+        }
+    }
+
+    /// Replace the type variables bound by `args` (i.e. instantiate a generic type)
+    ///   Type parameters of nested generic types shadow `args`
+    pub fn substitute(&self, args: &HashMap<JsWord, Type>) -> Type {
+        let substitute_all = |types: &[Type]| types.iter().map(|t| t.substitute(args)).collect();
+        let substitute_fields = |fields: &HashMap<JsWord, Type>, args: &HashMap<JsWord, Type>| {
+            fields
+                .iter()
+                .map(|(field, t)| (field.clone(), t.substitute(args)))
+                .collect()
+        };
+
+        match self {
+            Type::TypeVar(ref name) => args.get(name).cloned().unwrap_or(self.clone()),
+
+            Type::Fn(ref fn_type) => Type::Fn(fn_type.substitute(args)),
+
+            Type::Overloaded(ref signatures) => Type::Overloaded(substitute_all(signatures)),
+
+            Type::Class(ref class) => {
+                Type::Class(class.substitute(&shadow(args, &class.type_params)))
+            }
+
+            Type::Interface {
+                ref name,
+                ref origin,
+                ref type_params,
+                ref fields,
+                ref extends,
+            } => {
+                let args = shadow(args, type_params);
+                Type::Interface {
+                    name: name.clone(),
+                    origin: origin.clone(),
+                    type_params: type_params.clone(),
+                    fields: substitute_fields(fields, &args),
+                    extends: extends.iter().map(|t| t.substitute(&args)).collect(),
+                }
+            }
+
+            Type::Literal { ref fields } => Type::Literal {
+                fields: substitute_fields(fields, args),
+            },
+
+            Type::Callable(ref callable) => Type::Callable(CallableType {
+                call_signatures: substitute_all(&callable.call_signatures),
+                construct_signatures: substitute_all(&callable.construct_signatures),
+                fields: substitute_fields(&callable.fields, args),
+            }),
+
+            Type::Alias {
+                ref name,
+                ref aliasing_type,
+            } => Type::Alias {
+                name: name.clone(),
+                aliasing_type: Box::new(aliasing_type.substitute(args)),
+            },
+
+            Type::TyApp {
+                ref base,
+                args: ref ty_args,
+            } => Type::TyApp {
+                base: Box::new(base.substitute(args)),
+                args: substitute_all(ty_args),
+            },
+
+            Type::Forall {
+                ref type_params,
+                ref body,
+            } => Type::Forall {
+                type_params: type_params.clone(),
+                body: Box::new(body.substitute(&shadow(args, type_params))),
+            },
+
+            Type::UnsizedArray(ref elem_type) => {
+                Type::UnsizedArray(Box::new(elem_type.substitute(args)))
+            }
+
+            Type::Tuple(ref elem_types) => Type::Tuple(substitute_all(elem_types)),
+
+            Type::Optional(ref typ) => Type::Optional(Box::new(typ.substitute(args))),
+
+            Type::Dict(ref value_type) => Type::Dict(Box::new(value_type.substitute(args))),
+
+            Type::Union(ref members) => Type::Union(substitute_all(members)),
+
+            Type::Unevaluated {
+                ref operator,
+                args: ref operands,
+            } => {
+                // Conditions on a type parameter distribute over the members of a union
+                //   (i.e. `T extends null ? never : T` is evaluated per member of `string | null`)
+                if let (CONDITIONAL_OPERATOR, Some(Type::TypeVar(ref name))) =
+                    (&**operator, operands.first())
+                {
+                    let members: Vec<Type> = match args.get(name) {
+                        Some(Type::Union(ref members)) => members.clone(),
+                        Some(Type::LitUnion(ref lit_values)) => {
+                            lit_values.iter().cloned().map(Type::Lit).collect()
+                        }
+                        _ => Vec::new(),
+                    };
+
+                    if !members.is_empty() {
+                        let distributed = members
+                            .into_iter()
+                            .map(|member| {
+                                let mut member_args = args.clone();
+                                member_args.insert(name.clone(), member);
+                                self.substitute(&member_args)
+                            })
+                            .collect();
+
+                        return Type::Union(distributed);
+                    }
+                }
+
+                Type::Unevaluated {
+                    operator: operator.clone(),
+                    args: substitute_all(operands),
+                }
+            }
+
+            Type::DataUnion(ref data_union) => Type::DataUnion(DataUnion {
+                tag: data_union.tag.clone(),
+                variants: data_union
+                    .variants
+                    .iter()
+                    .map(|variant| Variant {
+                        tag_value: variant.tag_value.clone(),
+                        fields: variant
+                            .fields
+                            .iter()
+                            .map(|(field, t)| (field.clone(), t.substitute(args)))
+                            .collect(),
+                    })
+                    .collect(),
+            }),
+
+            _ => self.clone(),
+        }
+    }
+}
+
+/// Methods of merged interfaces are overloaded (the signatures of later declarations come first)
+///   Other fields are replaced by later declarations
+fn merge_fields(fields: &mut HashMap<JsWord, Type>, merged_fields: HashMap<JsWord, Type>) {
+    for (key, typ) in merged_fields.into_iter() {
+        let typ = match fields.remove(&key) {
+            Some(previous) if previous.is_callable() && typ.is_callable() => {
+                let mut signatures = typ.signatures();
+                signatures.extend(previous.signatures());
+                Type::Overloaded(signatures)
+            }
+
+            _ => typ,
+        };
+
+        fields.insert(key, typ);
+    }
+}
+
+fn substitute_members(
+    members: &HashMap<JsWord, Member>,
+    args: &HashMap<JsWord, Type>,
+) -> HashMap<JsWord, Member> {
+    members
+        .iter()
+        .map(|(key, member)| {
+            let member = Member {
+                typ: member.typ.substitute(args),
+                ..member.clone()
+            };
+
+            (key.clone(), member)
+        })
+        .collect()
 }
 
-#[derive(Debug, Clone)]
+/// Arguments not bound by the type parameters
+fn shadow(args: &HashMap<JsWord, Type>, type_params: &[JsWord]) -> HashMap<JsWord, Type> {
+    args.iter()
+        .filter(|(name, _)| !type_params.contains(name))
+        .map(|(name, t)| (name.clone(), t.clone()))
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct FnType {
     pub params: Vec<Type>,
+
+    /// Number of trailing parameters which are optional
+    ///   (i.e. `y` of `function foo(x: number, y?: number)`)
+    pub optional_params: usize,
+
+    /// Element type of the rest parameter (i.e. `number` of `function foo(...xs: number[])`)
+    pub rest_param: Option<Box<Type>>,
+
     pub return_type: Box<Type>,
 }
 
-#[derive(Debug, Clone)]
+impl FnType {
+    pub fn required_params(&self) -> usize {
+        self.params.len() - self.optional_params
+    }
+
+    /// Number of arguments passed from Pyret (rest arguments are passed as a single list)
+    pub fn pyret_arity(&self) -> usize {
+        self.params.len() + self.rest_param.iter().count()
+    }
+
+    pub fn substitute(&self, args: &HashMap<JsWord, Type>) -> FnType {
+        FnType {
+            params: self.params.iter().map(|t| t.substitute(args)).collect(),
+            optional_params: self.optional_params,
+            rest_param: self
+                .rest_param
+                .as_ref()
+                .map(|elem_type| Box::new(elem_type.substitute(args))),
+            return_type: Box::new(self.return_type.substitute(args)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CallableType {
+    /// Signatures of calling the object (i.e. `(x: number): number`)
+    ///   Each signature is a function type (which may be generic)
+    pub call_signatures: Vec<Type>,
+
+    /// Signatures of constructing the object with `new` (i.e. `new (s: string): Foo`)
+    ///   The return type of each signature is the constructed type
+    pub construct_signatures: Vec<Type>,
+
+    /// Other properties and methods of the object
+    pub fields: HashMap<JsWord, Type>,
+}
+
+impl CallableType {
+    /// Type of calling the object (overloaded if there are several call signatures)
+    pub fn call_type(&self) -> Option<Type> {
+        signatures_type(&self.call_signatures)
+    }
+
+    /// Type of constructing the object (overloaded if there are several construct signatures)
+    pub fn construct_type(&self) -> Option<Type> {
+        signatures_type(&self.construct_signatures)
+    }
+
+    /// Merge a later declaration (the signatures of later declarations come first)
+    fn merge(&mut self, merged: CallableType) {
+        let mut call_signatures = merged.call_signatures;
+        call_signatures.append(&mut self.call_signatures);
+        self.call_signatures = call_signatures;
+
+        let mut construct_signatures = merged.construct_signatures;
+        construct_signatures.append(&mut self.construct_signatures);
+        self.construct_signatures = construct_signatures;
+
+        merge_fields(&mut self.fields, merged.fields);
+    }
+}
+
+fn signatures_type(signatures: &[Type]) -> Option<Type> {
+    match signatures {
+        [] => None,
+        [signature] => Some(signature.clone()),
+        signatures => Some(Type::Overloaded(signatures.to_vec())),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct ClassType {
     pub name: JsWord,
     pub origin: CanonPath,
+    pub type_params: Vec<JsWord>,
     pub constructors: Vec<FnType>,
-    pub members: HashMap<JsWord, Type>,
+
+    /// Members of instances (i.e. `x` of `class Foo { x: number }`)
+    pub members: HashMap<JsWord, Member>,
+
+    /// Members of the class itself (i.e. `create` of `class Foo { static create(): Foo }`)
+    pub static_members: HashMap<JsWord, Member>,
+
+    /// Abstract classes cannot be instantiated (their constructors are not bound)
+    pub is_abstract: bool,
+
+    /// Extended class (i.e. `Base` of `class Derived extends Base`)
+    ///   Members include those inherited from the superclass
+    pub super_class: Option<Box<Type>>,
+
+    /// Implemented interfaces (i.e. `Shape` of `class Circle implements Shape`)
+    pub implements: Vec<Type>,
+}
+
+impl ClassType {
+    /// Members of an instantiation of the generic class (i.e. `Base<number>`)
+    pub fn instantiate(&self, args: &[Type]) -> ClassType {
+        let args: HashMap<JsWord, Type> = self
+            .type_params
+            .iter()
+            .cloned()
+            .zip(args.iter().cloned())
+            .collect();
+
+        ClassType {
+            type_params: Vec::new(),
+            ..self.substitute(&args)
+        }
+    }
+
+    fn substitute(&self, args: &HashMap<JsWord, Type>) -> ClassType {
+        ClassType {
+            constructors: self
+                .constructors
+                .iter()
+                .map(|constructor| constructor.substitute(args))
+                .collect(),
+            members: substitute_members(&self.members, args),
+            static_members: substitute_members(&self.static_members, args),
+            super_class: self
+                .super_class
+                .as_ref()
+                .map(|super_class| Box::new(super_class.substitute(args))),
+            implements: self.implements.iter().map(|t| t.substitute(args)).collect(),
+            ..self.clone()
+        }
+    }
+
+    /// Types of the instance members (i.e. the class as a record type)
+    pub fn instance_fields(&self) -> HashMap<JsWord, Type> {
+        self.members
+            .iter()
+            .map(|(key, member)| (key.clone(), member.typ.clone()))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Member {
+    pub kind: MemberKind,
+
+    /// Type of the property or method (or the accessed property of an accessor)
+    pub typ: Type,
+
+    pub readonly: bool,
+    pub is_abstract: bool,
+}
+
+impl Member {
+    /// Public member declared without modifiers (i.e. by a merged interface)
+    pub fn declared(typ: Type) -> Member {
+        let kind = if typ.is_callable() {
+            MemberKind::Method
+        } else {
+            MemberKind::Property
+        };
+
+        Member {
+            kind,
+            typ,
+            readonly: false,
+            is_abstract: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MemberKind {
+    Property,
+    Method,
+    /// Property defined by a getter and/or a setter (i.e. `get x(): number`)
+    Accessor {
+        getter: bool,
+        setter: bool,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataUnion {
+    /// Name of the discriminant field
+    pub tag: JsWord,
+    pub variants: Vec<Variant>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Variant {
+    /// Value of the discriminant field
+    pub tag_value: LitValue,
+
+    /// Fields other than the discriminant (in declaration order)
+    pub fields: Vec<(JsWord, Type)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumType {
+    pub name: JsWord,
+    pub origin: CanonPath,
+    pub members: Vec<EnumMember>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumMember {
+    pub name: JsWord,
+
+    /// Runtime value of the member (i.e. `1` of `A = 1`)
+    pub value: LitValue,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LitValue {
+    String(JsWord),
+    Number(f64),
+    Bool(bool),
+}
+
+impl DataUnion {
+    /// Names of the Pyret variants (in order, see `lit_variant_names()`)
+    pub fn variant_names(&self) -> Vec<String> {
+        lit_variant_names(self.variants.iter().map(|variant| &variant.tag_value))
+    }
+}
+
+impl EnumType {
+    /// Names of the Pyret variants representing the members (in order)
+    ///   Colliding member names (i.e. `A_B` and `"A-B"`) are suffixed as literals are
+    ///   (see `lit_variant_names()`)
+    pub fn variant_names(&self) -> Vec<String> {
+        unique_variant_names(
+            self.members
+                .iter()
+                .map(|member| pyret_variant_name(&member.name))
+                .collect(),
+        )
+    }
+}
+
+impl LitValue {
+    /// Name of the Pyret variant representing the literal (before deduplication)
+    fn variant_name(&self) -> String {
+        let name = match self {
+            LitValue::String(ref s) => s.to_string(),
+            LitValue::Number(n) => format!("n{}", n),
+            LitValue::Bool(b) => b.to_string(),
+        };
+
+        pyret_variant_name(&name)
+    }
+
+    /// The primitive type of the literal
+    pub fn base_type(&self) -> Type {
+        match self {
+            LitValue::String(..) => Type::String,
+            LitValue::Number(..) => Type::Number,
+            LitValue::Bool(..) => Type::Boolean,
+        }
+    }
+
+    /// The literal as a JS expression
+    pub fn to_js(&self) -> String {
+        match self {
+            LitValue::String(ref s) => format!("{:?}", &**s),
+            LitValue::Number(n) if n.is_nan() => "NaN".to_string(),
+            LitValue::Number(n) if n.is_infinite() && *n > 0.0 => "Infinity".to_string(),
+            LitValue::Number(n) if n.is_infinite() => "-Infinity".to_string(),
+            LitValue::Number(n) => n.to_string(),
+            LitValue::Bool(b) => b.to_string(),
+        }
+    }
+}
+
+/// Names of the Pyret variants representing the literals (in order)
+///   Literals whose names collide (i.e. `"a-b"` and `"a.b"`, or `"true"` and `true`)
+///   are told apart by a numeric suffix (i.e. `a-b` and `a-b1`)
+pub fn lit_variant_names<'a>(lit_values: impl Iterator<Item = &'a LitValue>) -> Vec<String> {
+    unique_variant_names(lit_values.map(LitValue::variant_name).collect())
+}
+
+/// Suffixes names already taken by a previous name (or by another base name)
+fn unique_variant_names(names: Vec<String>) -> Vec<String> {
+    let mut unique: Vec<String> = Vec::new();
+    for name in names.iter() {
+        let mut candidate = name.clone();
+        let mut suffix = 1;
+        while unique.contains(&candidate) || (candidate != *name && names.contains(&candidate)) {
+            candidate = format!("{}{}", name, suffix);
+            suffix += 1;
+        }
+
+        unique.push(candidate);
+    }
+
+    unique
+}
+
+/// Characters not allowed in Pyret identifiers are replaced by '-'
+///   Hyphens may only separate alphanumerics and names must start with a letter
+///   (i.e. `""` and `"1st"` are `v` and `v1st`)
+fn pyret_variant_name(name: &str) -> String {
+    let name: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+    let name = name.trim_matches('-');
+
+    match name.chars().next() {
+        Some(c) if c.is_ascii_alphabetic() => name.to_string(),
+        _ => format!("v{}", name),
+    }
 }
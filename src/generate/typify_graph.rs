@@ -1,36 +1,135 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use swc_atoms::JsWord;
-use swc_common::Span;
+use swc_common::{BytePos, Span, SyntaxContext};
 use swc_ecma_ast::*;
 
 use super::bind_common;
 use super::bind_graph_init::{Export, Import, ModuleGraph as UTModuleGraph};
-use super::bind_init::{ModuleData, ParsedModuleCache as ModuleCache};
+use super::bind_init::{ModuleData, ModuleKind, ParsedModuleCache as ModuleCache};
 use super::error::*;
-use super::structures::{CanonPath, ItemState, ItemStateT, Scope};
+use super::structures::{CanonPath, ItemState, ItemStateT, Scope, TypeScope};
 use super::type_construction as type_cons;
 use super::type_structs::*;
 
 pub fn typify(cache: &ModuleCache, ut_graph: UTModuleGraph) -> Result<ModuleGraph, BindGenError> {
     let mut graph = ModuleGraph {
         nodes: HashMap::new(),
-        export_edges: ut_graph.export_edges,
-        import_edges: ut_graph.import_edges,
+        export_edges: HashMap::new(),
+        import_edges: HashMap::new(),
+        globals: cache.globals().map(|global| global.path.clone()).collect(),
     };
 
+    let global_scope = super::init_type_scope::init_globals(cache);
+    let type_scopes = super::init_type_scope::init_all(cache, &ut_graph)?;
+
     for (_, module_data) in cache.iter() {
-        NodeInitSession::init(&mut graph, module_data)?;
+        NodeInitSession::init(
+            &mut graph,
+            cache,
+            &ut_graph,
+            &type_scopes,
+            &global_scope,
+            module_data,
+        )?;
     }
 
+    merge_globals(&mut graph)?;
+
+    graph.export_edges = ut_graph.export_edges;
+    graph.import_edges = ut_graph.import_edges;
+
     Ok(graph)
 }
 
+/// Global types declared by several modules are merged into the first declaration
+///   (i.e. interfaces augmented by several scripts, see `init_type_scope::init_globals()`)
+///
+/// The later declarations are replaced by the merged type
+///   (named types are resolved against the declarations of their source, see `Type::Named`)
+fn merge_globals(graph: &mut ModuleGraph) -> Result<(), BindGenError> {
+    let mut declared: HashMap<JsWord, CanonPath> = HashMap::new();
+    let mut augmented: Vec<(CanonPath, JsWord)> = Vec::new();
+
+    for global in graph.globals.iter() {
+        let keys: Vec<JsWord> = graph.nodes[global].declared_types.keys().cloned().collect();
+
+        for key in keys {
+            let first = match declared.get(&key) {
+                Some(first) => first,
+
+                None => {
+                    declared.insert(key, global.clone());
+                    continue;
+                }
+            };
+
+            let node = graph.nodes.get_mut(global).unwrap();
+            let typ = node.declared_types[&key].clone();
+            let rooted = node.rooted_export_types.remove(&key).is_some();
+
+            let first_node = graph.nodes.get_mut(first).unwrap();
+            let merged = first_node
+                .declared_types
+                .remove(&key)
+                .unwrap()
+                .merge(typ)
+                .ok_or_else(|| BindGenError {
+                    kind: BindGenErrorKind::TypeError(TypeError::ConflictingDeclaration(
+                        key.to_string(),
+                    )),
+                    module_path: global.as_path().to_owned(),
+                    span: Span::new(BytePos(0), BytePos(0), SyntaxContext::empty()),
+                })?;
+
+            if rooted || first_node.rooted_export_types.contains_key(&key) {
+                first_node
+                    .rooted_export_types
+                    .insert(key.clone(), merged.clone());
+            }
+            first_node.declared_types.insert(key.clone(), merged);
+            augmented.push((global.clone(), key));
+        }
+    }
+
+    for (global, key) in augmented {
+        let merged = graph.nodes[&declared[&key]].declared_types[&key].clone();
+        graph
+            .nodes
+            .get_mut(&global)
+            .unwrap()
+            .declared_types
+            .insert(key, merged);
+    }
+
+    Ok(())
+}
+
 #[derive(Debug)]
 pub struct ModuleNode {
     pub path: CanonPath,
     pub rooted_export_types: HashMap<JsWord, Type>,
     pub rooted_export_values: HashMap<JsWord, Type>,
+
+    /// Types declared in this module by their identifiers (exported or not)
+    ///   Named types are resolved against the declarations of their source (see `Type::Named`)
+    pub declared_types: HashMap<JsWord, Type>,
+
+    /// Namespaces declared in this module (see `CanonPath::nested()`)
+    pub rooted_export_namespaces: HashSet<JsWord>,
+
+    /// Modules exported as namespaces (i.e. `export * as ns from "./m"`)
+    pub rooted_export_modules: HashMap<JsWord, CanonPath>,
+
+    /// Identifier of the declaration rooted under the default export key (if it has one)
+    pub default_export_ident: Option<JsWord>,
+
+    /// The module value is its default export (i.e. `export = foo`)
+    pub export_assignment: bool,
+
+    /// Global name of the module when loaded outside of a module system
+    ///   (i.e. `export as namespace X`)
+    pub umd_global: Option<JsWord>,
 }
 
 /// ORDER OF EXPORTS MATTERS
@@ -41,6 +140,20 @@ pub struct ModuleGraph {
     pub nodes: HashMap<CanonPath, ModuleNode>,
     pub export_edges: HashMap<CanonPath, Vec<Export>>,
     pub import_edges: HashMap<CanonPath, Vec<Import>>,
+
+    /// Modules declaring globals (see `ParsedModuleCache::globals()`)
+    pub globals: Vec<CanonPath>,
+}
+
+impl ModuleNode {
+    /// Module of the namespace rooted in this module as `key`
+    pub fn namespace_module(&self, key: &JsWord) -> Option<CanonPath> {
+        if self.rooted_export_namespaces.contains(key) {
+            return Some(self.path.nested(key.clone()));
+        }
+
+        self.rooted_export_modules.get(key).cloned()
+    }
 }
 
 impl ModuleGraph {
@@ -51,13 +164,21 @@ impl ModuleGraph {
 
 struct NodeInitSession<'a, 'b> {
     path: &'a CanonPath,
+    kind: ModuleKind,
     dependency_map: &'a HashMap<String, CanonPath>,
-    type_scope: &'b Scope<ItemState>,
+    type_scope: &'b TypeScope,
+    type_scopes: &'a HashMap<CanonPath, TypeScope>,
+    global_scope: &'a TypeScope,
+    ut_graph: &'a UTModuleGraph,
+    cache: &'a ModuleCache,
     value_scope: Scope<ItemStateT>,
 
     rooted_values: HashMap<JsWord, Type>,
     rooted_types: HashMap<JsWord, Type>,
     generated_types: HashMap<JsWord, Type>,
+    default_export_ident: Option<JsWord>,
+    export_assignment: bool,
+    umd_global: Option<JsWord>,
 }
 
 macro_rules! get_dep_src {
@@ -70,18 +191,32 @@ macro_rules! get_dep_src {
 }
 
 impl<'a, 'b> NodeInitSession<'a, 'b> {
-    fn init(g: &mut ModuleGraph, module_data: &ModuleData) -> Result<(), BindGenError> {
-        let type_scope = super::init_type_scope::init(module_data)?;
+    fn init(
+        g: &mut ModuleGraph,
+        cache: &ModuleCache,
+        ut_graph: &UTModuleGraph,
+        type_scopes: &HashMap<CanonPath, TypeScope>,
+        global_scope: &TypeScope,
+        module_data: &ModuleData,
+    ) -> Result<(), BindGenError> {
         let mut session = NodeInitSession {
             path: &module_data.path,
+            kind: module_data.kind,
             dependency_map: &module_data.dependencies,
 
             generated_types: HashMap::new(),
             rooted_values: HashMap::new(),
             rooted_types: HashMap::new(),
+            default_export_ident: None,
+            export_assignment: false,
+            umd_global: None,
 
             value_scope: Scope::new(),
-            type_scope: &type_scope,
+            type_scope: &type_scopes[&module_data.path],
+            type_scopes,
+            global_scope,
+            ut_graph,
+            cache,
         };
 
         for item in module_data.module_ast.body.iter() {
@@ -90,11 +225,23 @@ impl<'a, 'b> NodeInitSession<'a, 'b> {
 
         let rooted_export_types = session.rooted_types;
         let rooted_export_values = session.rooted_values;
+        let ut_node = ut_graph
+            .nodes
+            .get(&module_data.path)
+            .expect("Missing untyped module node");
+        let rooted_export_namespaces = ut_node.rooted_export_namespaces.clone();
+        let rooted_export_modules = ut_node.rooted_export_modules.clone();
 
         let module_node = ModuleNode {
             path: module_data.path.clone(),
             rooted_export_types,
             rooted_export_values,
+            declared_types: session.generated_types,
+            rooted_export_namespaces,
+            rooted_export_modules,
+            default_export_ident: session.default_export_ident,
+            export_assignment: session.export_assignment,
+            umd_global: session.umd_global,
         };
 
         g.nodes.insert(module_data.path.clone(), module_node);
@@ -116,7 +263,7 @@ impl<'a, 'b> NodeInitSession<'a, 'b> {
 
     fn process_stmt(&mut self, stmt: &Stmt) -> Result<(), BindGenError> {
         if let Stmt::Decl(ref decl) = stmt {
-            self.process_decl(decl, false)?;
+            self.process_decl(decl, self.kind.is_export_context())?;
         }
 
         Ok(())
@@ -140,22 +287,54 @@ impl<'a, 'b> NodeInitSession<'a, 'b> {
 
             ModuleDecl::ExportAll(ExportAll { ref src, .. }) => Ok(()),
 
-            ModuleDecl::ExportDefaultDecl(..) => unreachable!("Caught in bind init"),
+            ModuleDecl::ExportDefaultDecl(ExportDefaultDecl { ref decl, .. }) => {
+                self.process_default_decl(decl)
+            }
 
-            ModuleDecl::ExportDefaultExpr(..) => unreachable!("Caught in bind init"),
+            ModuleDecl::ExportDefaultExpr(ref export) => {
+                let ident = bind_common::default_export_ident(export).expect("Caught in bind init");
+                self.export_local(ident.sym.clone(), bind_common::default_export_key());
+                Ok(())
+            }
 
-            ModuleDecl::TsImportEquals(..) => unreachable!("Caught in bind init"),
+            ModuleDecl::TsImportEquals(ref import) => {
+                let src =
+                    bind_common::import_equals_specifier(import).expect("Caught in bind init");
+                let src_canon_path: &CanonPath = get_dep_src!(self, src);
+                let local = import.id.sym.clone();
 
-            ModuleDecl::TsExportAssignment(..) => unreachable!("Caught in bind init"),
+                let state = ItemStateT::Imported {
+                    source: src_canon_path.clone(),
+                    src_key: bind_common::default_export_key(),
+                    as_key: local.clone(),
+                };
+                self.scope_value(local.clone(), state);
 
-            ModuleDecl::TsNamespaceExport(..) => unreachable!("Caught in bind init"),
+                if import.is_export {
+                    self.export_local(local.clone(), local);
+                }
+
+                Ok(())
+            }
+
+            ModuleDecl::TsExportAssignment(ref export) => {
+                let ident =
+                    bind_common::export_assignment_ident(export).expect("Caught in bind init");
+                self.export_local(ident.sym.clone(), bind_common::default_export_key());
+                self.export_assignment = true;
+                Ok(())
+            }
+
+            ModuleDecl::TsNamespaceExport(TsNamespaceExportDecl { ref id, .. }) => {
+                self.umd_global = Some(id.sym.clone());
+                Ok(())
+            }
         }
     }
 
     fn prune_export_specifiers<'c, T>(
         &self,
         specifiers: T,
-        exp_span: &Span,
     ) -> Result<Vec<&'c ExportSpecifier>, BindGenError>
     where
         T: Iterator<Item = &'c ExportSpecifier>,
@@ -163,29 +342,11 @@ impl<'a, 'b> NodeInitSession<'a, 'b> {
         let mut buff = Vec::new();
         for spec in specifiers {
             match spec {
-                ExportSpecifier::Named(..) => {
+                ExportSpecifier::Named(..)
+                | ExportSpecifier::Default(..)
+                | ExportSpecifier::Namespace(..) => {
                     buff.push(spec);
                 }
-
-                ExportSpecifier::Namespace(ExportNamespaceSpecifier { ref span, .. }) => {
-                    return Err(BindGenError {
-                        module_path: self.path.as_path().to_owned(),
-                        kind: BindGenErrorKind::UnsupportedFeature(
-                            UnsupportedFeature::NamespaceExport,
-                        ),
-                        span: span.clone(),
-                    });
-                }
-
-                ExportSpecifier::Default(..) => {
-                    return Err(BindGenError {
-                        module_path: self.path.as_path().to_owned(),
-                        kind: BindGenErrorKind::UnsupportedFeature(
-                            UnsupportedFeature::DefaultExport,
-                        ),
-                        span: exp_span.clone(),
-                    });
-                }
             }
         }
 
@@ -193,7 +354,7 @@ impl<'a, 'b> NodeInitSession<'a, 'b> {
     }
 
     fn process_named_export(&mut self, exp: &NamedExport) -> Result<(), BindGenError> {
-        let specifiers = self.prune_export_specifiers(exp.specifiers.iter(), &exp.span)?;
+        let specifiers = self.prune_export_specifiers(exp.specifiers.iter())?;
 
         match exp.src {
             Some(ref src) => Ok(()),
@@ -212,25 +373,7 @@ impl<'a, 'b> NodeInitSession<'a, 'b> {
                                 .map(|x| x.sym.clone())
                                 .unwrap_or(orig_key.clone());
 
-                            // Handle the named export if it refers to a rooted item or imported
-                            //   item by adding an edge if it is an imported item
-                            //   or by marking the item as rooted (under its export key)
-
-                            // Handle types
-                            if let Some(ref state) = self.type_scope.get(&orig_key) {
-                                if let ItemState::Rooted = state {
-                                    let rooted_type = self.generated_types.get(&orig_key).unwrap();
-                                    self.rooted_values
-                                        .insert(export_key.clone(), rooted_type.clone());
-                                }
-                            }
-
-                            // Handle values
-                            if let Some(ref state) = self.value_scope.get(&orig_key) {
-                                if let ItemStateT::Rooted(ref typ) = state {
-                                    self.rooted_values.insert(export_key, typ.clone());
-                                }
-                            }
+                            self.export_local(orig_key, export_key);
                         }
 
                         _ => unreachable!("Invalid specifier should be pruned"),
@@ -242,10 +385,123 @@ impl<'a, 'b> NodeInitSession<'a, 'b> {
         }
     }
 
+    /// Handle the export of a local name if it refers to a rooted item
+    ///   by marking the item as rooted (under its export key)
+    fn export_local(&mut self, orig_key: JsWord, export_key: JsWord) {
+        let mut rooted = false;
+
+        // Handle types
+        if let Some(ItemState::Rooted) = self.type_scope.types.get(&orig_key) {
+            let rooted_type = self.generated_types.get(&orig_key).unwrap();
+            self.rooted_types
+                .insert(export_key.clone(), rooted_type.clone());
+            rooted = true;
+        }
+
+        // Handle values
+        if let Some(ItemStateT::Rooted(typ)) = self.value_scope.get(&orig_key) {
+            self.rooted_values.insert(export_key.clone(), typ.clone());
+            rooted = true;
+        }
+
+        if rooted && export_key == bind_common::default_export_key() {
+            self.default_export_ident = Some(orig_key);
+        }
+    }
+
+    /// `export default function foo() { }`, `export default class Foo { }`, etc.
+    ///   The declaration is rooted under the default export key
+    ///   Its identifier (if any) is only in scope of this module
+    fn process_default_decl(&mut self, decl: &DefaultDecl) -> Result<(), BindGenError> {
+        let default_key = bind_common::default_export_key();
+
+        match decl {
+            DefaultDecl::Fn(FnExpr {
+                ref ident,
+                ref function,
+            }) => {
+                let typ = type_cons::construct_fn_type(
+                    self.cache,
+                    self.path,
+                    self.type_scopes,
+                    &self.value_scope,
+                    self.global_scope,
+                    self.ut_graph,
+                    function,
+                )?;
+
+                self.rooted_values.insert(default_key, typ.clone());
+
+                if let Some(ident) = ident {
+                    self.default_export_ident = Some(ident.sym.clone());
+                    self.scope_value(ident.sym.clone(), ItemStateT::Rooted(typ));
+                }
+            }
+
+            DefaultDecl::Class(ClassExpr {
+                ref ident,
+                ref class,
+            }) => {
+                // Anonymous classes are named by the default export key
+                let class_decl = Decl::Class(ClassDecl {
+                    ident: ident
+                        .clone()
+                        .unwrap_or(Ident::new(default_key.clone(), class.span)),
+                    declare: false,
+                    class: class.clone(),
+                });
+                let typ = type_cons::construct_type(
+                    self.cache,
+                    self.path,
+                    self.type_scopes,
+                    &self.value_scope,
+                    self.global_scope,
+                    self.ut_graph,
+                    &class_decl,
+                )?;
+
+                self.rooted_types.insert(default_key, typ.clone());
+
+                if let Some(ident) = ident {
+                    self.default_export_ident = Some(ident.sym.clone());
+                    self.generated_types.insert(ident.sym.clone(), typ);
+                }
+            }
+
+            DefaultDecl::TsInterfaceDecl(ref decl) => {
+                let interface_decl = Decl::TsInterface(decl.clone());
+                let typ = type_cons::construct_type(
+                    self.cache,
+                    self.path,
+                    self.type_scopes,
+                    &self.value_scope,
+                    self.global_scope,
+                    self.ut_graph,
+                    &interface_decl,
+                )?;
+
+                self.rooted_types.insert(default_key, typ.clone());
+
+                self.default_export_ident = Some(decl.id.sym.clone());
+                self.generated_types.insert(decl.id.sym.clone(), typ);
+            }
+        }
+
+        Ok(())
+    }
+
     fn process_decl(&mut self, decl: &Decl, export: bool) -> Result<(), BindGenError> {
         match decl {
             Decl::Var(ref decl) => {
-                let vars = type_cons::construct_variable_types(self.path, self.type_scope, decl)?;
+                let vars = type_cons::construct_variable_types(
+                    self.cache,
+                    self.path,
+                    self.type_scopes,
+                    &self.value_scope,
+                    self.global_scope,
+                    self.ut_graph,
+                    decl,
+                )?;
 
                 for (symbol, typ) in vars.into_iter() {
                     if export {
@@ -257,10 +513,24 @@ impl<'a, 'b> NodeInitSession<'a, 'b> {
             }
 
             Decl::Fn(ref decl) => {
-                let typ = type_cons::construct_fn_type(self.path, self.type_scope, &decl.function)?;
+                let typ = type_cons::construct_fn_type(
+                    self.cache,
+                    self.path,
+                    self.type_scopes,
+                    &self.value_scope,
+                    self.global_scope,
+                    self.ut_graph,
+                    &decl.function,
+                )?;
 
                 let symbol = decl.ident.sym.clone();
 
+                // Functions declared several times are overloaded
+                let typ = match self.value_scope.get(&symbol) {
+                    Some(ItemStateT::Rooted(ref previous)) => previous.clone().overload(typ),
+                    _ => typ,
+                };
+
                 if export {
                     self.rooted_values.insert(symbol.clone(), typ.clone());
                 }
@@ -272,9 +542,29 @@ impl<'a, 'b> NodeInitSession<'a, 'b> {
             | decl @ Decl::TsInterface(..)
             | decl @ Decl::TsTypeAlias(..)
             | decl @ Decl::TsEnum(..) => {
-                let typ = type_cons::construct_type(self.path, self.type_scope, decl)?;
+                let typ = type_cons::construct_type(
+                    self.cache,
+                    self.path,
+                    self.type_scopes,
+                    &self.value_scope,
+                    self.global_scope,
+                    self.ut_graph,
+                    decl,
+                )?;
                 let ident = bind_common::get_decl_ident(decl);
 
+                // Declarations of the same name are merged (i.e. repeated `interface Foo` blocks)
+                let typ = match self.generated_types.remove(&ident.sym) {
+                    Some(previous) => previous.merge(typ).ok_or_else(|| BindGenError {
+                        kind: BindGenErrorKind::TypeError(TypeError::ConflictingDeclaration(
+                            ident.sym.to_string(),
+                        )),
+                        module_path: self.path.as_path().to_owned(),
+                        span: ident.span,
+                    })?,
+                    None => typ,
+                };
+
                 self.generated_types.insert(ident.sym.clone(), typ.clone());
 
                 if export {
@@ -293,7 +583,7 @@ impl<'a, 'b> NodeInitSession<'a, 'b> {
         source: &CanonPath,
         spec: &ImportSpecifier,
     ) -> Result<(), BindGenError> {
-        match spec {
+        let (src_key, local) = match spec {
             ImportSpecifier::Named(ref named) => {
                 let src_key = named
                     .imported
@@ -301,31 +591,26 @@ impl<'a, 'b> NodeInitSession<'a, 'b> {
                     .map(|export_key| export_key.sym.clone())
                     .unwrap_or(named.local.sym.clone());
 
-                let as_key = named.local.sym.clone();
+                (src_key, &named.local)
+            }
 
-                let state = ItemStateT::Imported {
-                    source: source.clone(),
-                    src_key,
-                    as_key,
-                };
+            ImportSpecifier::Default(ref def) => (bind_common::default_export_key(), &def.local),
 
-                let import_key = named.local.sym.clone();
-                self.scope_value(import_key, state);
+            // Module-valued items are resolved through the graph
+            ImportSpecifier::Namespace(..) => return Ok(()),
+        };
 
-                Ok(())
-            }
+        let as_key = local.sym.clone();
 
-            ImportSpecifier::Default(def) => Err(BindGenError {
-                module_path: self.path.as_path().to_owned(),
-                kind: BindGenErrorKind::UnsupportedFeature(UnsupportedFeature::DefaultImport),
-                span: def.span,
-            }),
-
-            ImportSpecifier::Namespace(namespace) => Err(BindGenError {
-                module_path: self.path.as_path().to_owned(),
-                kind: BindGenErrorKind::UnsupportedFeature(UnsupportedFeature::DefaultImport),
-                span: namespace.span,
-            }),
-        }
+        let state = ItemStateT::Imported {
+            source: source.clone(),
+            src_key,
+            as_key,
+        };
+
+        let import_key = local.sym.clone();
+        self.scope_value(import_key, state);
+
+        Ok(())
     }
 }
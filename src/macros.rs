@@ -1,12 +1,12 @@
 macro_rules! const_str {
     ($name: ident => $val: expr) => {
-        pub const $name: &'static str = $val;
+        pub const $name: &str = $val;
     };
 }
 
 macro_rules! gen_help_str {
     ($name: ident => $val: expr) => {
-        pub const $name: &'static str = concat!("[GEN] ", $val);
+        pub const $name: &str = concat!("[GEN] ", $val);
     };
 }
 
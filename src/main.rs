@@ -29,6 +29,26 @@ fn bool_validator(arg: String) -> Result<(), String> {
         .map(|_| ())
 }
 
+fn default_export_name_validator(arg: String) -> Result<(), String> {
+    arg.parse::<generate::DefaultExportName>().map(|_| ())
+}
+
+fn optional_params_validator(arg: String) -> Result<(), String> {
+    arg.parse::<generate::OptionalParams>().map(|_| ())
+}
+
+fn overloads_validator(arg: String) -> Result<(), String> {
+    arg.parse::<generate::Overloads>().map(|_| ())
+}
+
+fn string_dicts_validator(arg: String) -> Result<(), String> {
+    arg.parse::<generate::StringDicts>().map(|_| ())
+}
+
+fn root_binding_validator(arg: String) -> Result<(), String> {
+    arg.parse::<generate::RootBinding>().map(|_| ())
+}
+
 fn construct_ts_flavor(arg: Option<&str>) -> Result<TsFlavor, String> {
     arg.map(|s| {
         if common::TS_NUM_STRINGS.contains(&s) {
@@ -70,6 +90,41 @@ fn main() {
                     .help(common::OPTION_REQUIRE_PATH_HELP)
                     .required(false),
             )
+            .arg(
+                Arg::with_name(common::OPTION_ROOT_BINDING)
+                    .long(common::OPTION_ROOT_BINDING)
+                    .value_name("root binding")
+                    .takes_value(true)
+                    .validator(root_binding_validator)
+                    .help(common::OPTION_ROOT_BINDING_HELP)
+                    .required(false),
+            )
+            .arg(
+                Arg::with_name(common::OPTION_BIND_GLOBALS)
+                    .long(common::OPTION_BIND_GLOBALS)
+                    .value_name("global names")
+                    .takes_value(true)
+                    .min_values(0)
+                    .use_delimiter(true)
+                    .help(common::OPTION_BIND_GLOBALS_HELP)
+                    .required(false),
+            )
+            .arg(
+                Arg::with_name(common::OPTION_TSCONFIG)
+                    .long(common::OPTION_TSCONFIG)
+                    .value_name("tsconfig path")
+                    .takes_value(true)
+                    .help(common::OPTION_TSCONFIG_HELP)
+                    .required(false),
+            )
+            .arg(
+                Arg::with_name(common::OPTION_MODULE)
+                    .long(common::OPTION_MODULE)
+                    .value_name("module specifier")
+                    .takes_value(true)
+                    .help(common::OPTION_MODULE_HELP)
+                    .required(false),
+            )
             .arg(
                 Arg::with_name("OUTPUT FILE STEM")
                     .long("output-file-stem")
@@ -125,6 +180,51 @@ fn main() {
 
         );
 
+        opt_arg!(app =>
+            key: common::OPTION_DEFAULT_EXPORT_NAME;
+            long: common::OPTION_DEFAULT_EXPORT_NAME;
+            values: common::DEFAULT_EXPORT_NAME_STRINGS;
+            validator: default_export_name_validator;
+            help: common::OPTION_DEFAULT_EXPORT_NAME_HELP;
+            help-long: common::OPTION_DEFAULT_EXPORT_NAME_HELP_LONG
+        );
+
+        opt_arg!(app =>
+            key: common::OPTION_OPTIONAL_PARAMS;
+            long: common::OPTION_OPTIONAL_PARAMS;
+            values: common::OPTIONAL_PARAMS_STRINGS;
+            validator: optional_params_validator;
+            help: common::OPTION_OPTIONAL_PARAMS_HELP;
+            help-long: common::OPTION_OPTIONAL_PARAMS_HELP_LONG
+        );
+
+        opt_arg!(app =>
+            key: common::OPTION_OVERLOADS;
+            long: common::OPTION_OVERLOADS;
+            values: common::OVERLOADS_STRINGS;
+            validator: overloads_validator;
+            help: common::OPTION_OVERLOADS_HELP;
+            help-long: common::OPTION_OVERLOADS_HELP_LONG
+        );
+
+        opt_arg!(app =>
+            key: common::OPTION_STRING_DICTS;
+            long: common::OPTION_STRING_DICTS;
+            values: common::STRING_DICTS_STRINGS;
+            validator: string_dicts_validator;
+            help: common::OPTION_STRING_DICTS_HELP;
+            help-long: common::OPTION_STRING_DICTS_HELP_LONG
+        );
+
+        opt_arg!(app =>
+            key: common::OPTION_LAZY_DICTS;
+            long: common::OPTION_LAZY_DICTS;
+            values: bool_values!();
+            validator: bool_validator;
+            help: common::OPTION_LAZY_DICTS_HELP;
+            help-long: common::OPTION_LAZY_DICTS_HELP_LONG
+        );
+
         app
     }
     .get_matches();
@@ -144,8 +244,32 @@ fn main() {
 
     let require_path = matches.value_of("REQUIRE PATH");
 
+    let root_binding = matches
+        .value_of(common::OPTION_ROOT_BINDING)
+        .map(|b| b.parse::<generate::RootBinding>().expect("Validated root binding"))
+        .unwrap_or(generate::RootBinding::Require);
+
+    let bind_globals = if matches.is_present(common::OPTION_BIND_GLOBALS) {
+        let names: Vec<String> = matches
+            .values_of(common::OPTION_BIND_GLOBALS)
+            .map(|names| names.map(|name| name.to_string()).collect())
+            .unwrap_or(Vec::new());
+
+        if names.is_empty() {
+            Some(generate::GlobalSelection::All)
+        } else {
+            Some(generate::GlobalSelection::Only(names))
+        }
+    } else {
+        None
+    };
+
     let file_stem = matches.value_of("OUTPUT FILE STEM");
 
+    let tsconfig_path = matches.value_of(common::OPTION_TSCONFIG).map(PathBuf::from);
+
+    let root_module = matches.value_of(common::OPTION_MODULE);
+
     let mut gen_config = match matches
         .value_of(common::OPTIONS_GEN_CONFIG)
         .map(load_config)
@@ -184,6 +308,46 @@ fn main() {
         gen_key: wrap_top_level_vars
     );
 
+    let _ = extract_opt_arg!(matches =>
+        key: common::OPTION_DEFAULT_EXPORT_NAME;
+        converter: str::parse::<generate::DefaultExportName>;
+        =>
+        gen_config: &mut gen_config;
+        gen_key: default_export_name
+    );
+
+    let _ = extract_opt_arg!(matches =>
+        key: common::OPTION_OPTIONAL_PARAMS;
+        converter: str::parse::<generate::OptionalParams>;
+        =>
+        gen_config: &mut gen_config;
+        gen_key: optional_params
+    );
+
+    let _ = extract_opt_arg!(matches =>
+        key: common::OPTION_OVERLOADS;
+        converter: str::parse::<generate::Overloads>;
+        =>
+        gen_config: &mut gen_config;
+        gen_key: overloads
+    );
+
+    let _ = extract_opt_arg!(matches =>
+        key: common::OPTION_STRING_DICTS;
+        converter: str::parse::<generate::StringDicts>;
+        =>
+        gen_config: &mut gen_config;
+        gen_key: string_dicts
+    );
+
+    let _ = extract_opt_arg!(matches =>
+        key: common::OPTION_LAZY_DICTS;
+        converter: str::parse::<bool>;
+        =>
+        gen_config: &mut gen_config;
+        gen_key: lazy_dicts
+    );
+
     let output_dir = PathBuf::from(output_dir);
     let input_path = PathBuf::from(input_path);
 
@@ -192,20 +356,28 @@ fn main() {
         js: true,
     };
 
-    let require_path = require_path.map(|p| p.to_string()).unwrap_or({
-        let mut buff = PathBuf::new();
-        buff.push("./");
-        buff.push(input_path.file_stem().unwrap());
-        buff.set_extension("js");
+    // An ambient module is implemented by the module of the same specifier
+    let require_path = require_path
+        .or(root_module)
+        .map(|p| p.to_string())
+        .unwrap_or({
+            let mut buff = PathBuf::new();
+            buff.push("./");
+            buff.push(input_path.file_stem().unwrap());
+            buff.set_extension("js");
 
-        buff.display().to_string()
-    });
+            buff.display().to_string()
+        });
 
     let options = compile_opt::CompileOpt {
         input_path,
         require_path,
+        root_binding,
+        bind_globals,
         file_stem,
         output_dir,
+        tsconfig_path,
+        root_module,
         gen_config,
         emit_config,
         ts_flavor: target_ts_flavor,
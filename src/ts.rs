@@ -55,6 +55,31 @@ pub struct TsFeatures {
     #[builder(default = "false")]
     pub never_type: bool,
 
+    #[builder(default = "false")]
+    /// Ex: function foo (x: number | string) { .. }
+    /// => includes discriminated unions of type literals
+    pub union_type: bool,
+
+    #[builder(default = "false")]
+    /// Ex: interface Foo { x?: number }
+    /// => optional properties and tuple elements are bound as `Option`
+    pub optional_type: bool,
+
+    #[builder(default = "false")]
+    /// Ex: interface Scores { [name: string]: number }
+    /// => records with only an index signature are bound as string dicts
+    pub dict_type: bool,
+
+    #[builder(default = "false")]
+    /// Ex: interface Counter { (start: number): number; step: number }
+    /// => objects with call signatures are bound as functions (other properties as members)
+    pub callable_type: bool,
+
+    #[builder(default = "false")]
+    /// Ex: interface FooConstructor { new (x: number): Foo }
+    /// => construct signatures and constructor types (i.e. `new (x: number) => Foo`)
+    pub construct_type: bool,
+
     #[builder(default = "false")]
     pub interfaces: bool,
 
@@ -78,6 +103,11 @@ pub struct TsFeatures {
     #[builder(default = "false")]
     pub class_type: bool,
 
+    #[builder(default = "false")]
+    /// Ex: function id<T>(x: T): T { .. }
+    /// => type parameters and instantiations of generic types (i.e. `Promise<number>`)
+    pub generics: bool,
+
     #[builder(default = "false")]
     pub type_alias: bool,
 }
@@ -99,12 +129,18 @@ impl TsFeatures {
             .null_type(true)
             .undefined_type(true)
             .never_type(true)
+            .union_type(true)
+            .optional_type(true)
+            .dict_type(true)
+            .callable_type(true)
+            .construct_type(true)
             .interfaces(true)
             .interface_extension(true)
             .recursive_type(true)
             .type_literal(true)
             .literal_type(true)
             .class_type(true)
+            .generics(true)
             .type_alias(true)
             .build()
             .expect("empty failed")
@@ -118,6 +154,7 @@ impl TsFeatures {
         TsFeaturesBuilder::default()
             .number_type(true)
             .fn_type(true)
+            .callable_type(true)
             .allow_simple_records()
             .fn_type(true)
             .build()
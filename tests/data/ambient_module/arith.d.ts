@@ -0,0 +1,6 @@
+declare module "arith" {
+    export function add(x: number, y: number): number;
+
+    // Exported without a modifier (no export declarations in the module body)
+    function neg(x: number): number;
+}
@@ -0,0 +1,2 @@
+/// <reference path="./ambient_module/arith.d.ts" />
+export { add, neg } from "arith";
@@ -0,0 +1,9 @@
+module.exports = {
+    "add": function add(x, y) {
+        return x + y;
+    },
+
+    "neg": function neg(x) {
+        return -x;
+    },
+};
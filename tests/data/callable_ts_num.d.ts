@@ -0,0 +1,13 @@
+interface Counter {
+    (start: number): number;
+    step: number;
+    twice(x: number): number;
+}
+
+export declare const counter: Counter;
+
+export declare const scale: {
+    (x: number): number;
+    (x: number, factor: number): number;
+    factor: number;
+};
@@ -0,0 +1,17 @@
+function counter(start) {
+    return start + counter.step;
+}
+
+counter.step = 10;
+counter.twice = function twice(x) { return x * 2; };
+
+function scale(x, factor) {
+    return x * (factor === undefined ? scale.factor : factor);
+}
+
+scale.factor = 3;
+
+module.exports = {
+    "counter": counter,
+    "scale": scale,
+};
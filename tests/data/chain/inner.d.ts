@@ -0,0 +1,3 @@
+export * from "./leaf";
+
+export declare function inner(): number;
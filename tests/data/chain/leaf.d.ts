@@ -0,0 +1 @@
+export declare function leaf(): number;
@@ -0,0 +1,3 @@
+export * from "./inner";
+
+export declare function middle(): number;
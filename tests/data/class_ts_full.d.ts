@@ -0,0 +1,13 @@
+export declare class Counter {
+    static readonly limit: number;
+    static zero(): Counter;
+
+    constructor(readonly start: number, step?: number);
+
+    get value(): number;
+    set value(v: number);
+
+    increment(): number;
+}
+
+export declare function read(counter: Counter): number;
@@ -0,0 +1,35 @@
+class Counter {
+    constructor(start, step) {
+        this.start = start;
+        this.step = step === undefined ? 1 : step;
+        this.current = start;
+    }
+
+    static zero() {
+        return new Counter(0);
+    }
+
+    get value() {
+        return this.current;
+    }
+
+    set value(v) {
+        this.current = Math.min(v, Counter.limit);
+    }
+
+    increment() {
+        this.current += this.step;
+        return this.current;
+    }
+}
+
+Counter.limit = 100;
+
+function read(counter) {
+    return counter.value;
+}
+
+module.exports = {
+    "Counter": Counter,
+    "read": read,
+};
@@ -0,0 +1,6 @@
+export interface Sequence {
+    length: number;
+    [Symbol.iterator](): Iterator<number>;
+}
+
+export declare function sequence(): Sequence;
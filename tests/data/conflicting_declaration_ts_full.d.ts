@@ -0,0 +1,7 @@
+export type Size = { width: number };
+
+export interface Size {
+    height: number;
+}
+
+export declare function area(size: Size): number;
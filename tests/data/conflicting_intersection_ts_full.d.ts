@@ -0,0 +1,3 @@
+export type Conflict = { size: number } & { size: string };
+
+export declare function measure(c: Conflict): number;
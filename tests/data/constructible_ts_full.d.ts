@@ -0,0 +1,16 @@
+export interface Vec {
+    x: number;
+    y: number;
+}
+
+export interface VecConstructor {
+    new (x: number, y: number): Vec;
+    (x: number): Vec;
+    dims: number;
+}
+
+export declare const Vector: VecConstructor;
+
+export declare function build(make: new (x: number, y: number) => Vec, x: number): number;
+
+export declare function factory(): new (x: number, y: number) => Vec;
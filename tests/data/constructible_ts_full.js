@@ -0,0 +1,28 @@
+function Vector(x, y) {
+    if (!new.target) {
+        return new Vector(x, x);
+    }
+
+    this.x = x;
+    this.y = y;
+}
+
+Vector.dims = 2;
+
+function build(make, x) {
+    var v = new make(x, x * 2);
+    return v.x + v.y;
+}
+
+function factory() {
+    return function (x, y) {
+        this.x = x * 10;
+        this.y = y * 10;
+    };
+}
+
+module.exports = {
+    "Vector": Vector,
+    "build": build,
+    "factory": factory,
+};
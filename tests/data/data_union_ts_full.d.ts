@@ -0,0 +1,21 @@
+export interface Circle {
+    kind: "circle";
+    radius: number;
+}
+
+export interface Square {
+    kind: "square";
+    size: number;
+}
+
+export type Shape = Circle | Square;
+
+export type Result = { status: "ok"; value: number } | { status: "error"; code: number };
+
+export declare function area(shape: Shape): number;
+
+export declare function grow(shape: Shape, by: number): Shape;
+
+export declare function check(x: number): Result;
+
+export declare function unwrap(result: Result): number;
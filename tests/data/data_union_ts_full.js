@@ -0,0 +1,28 @@
+function area(shape) {
+    switch (shape.kind) {
+        case "circle": return 3 * shape.radius * shape.radius;
+        case "square": return shape.size * shape.size;
+    }
+}
+
+function grow(shape, by) {
+    switch (shape.kind) {
+        case "circle": return { kind: "circle", radius: shape.radius + by };
+        case "square": return { kind: "square", size: shape.size + by };
+    }
+}
+
+function check(x) {
+    return x >= 0 ? { status: "ok", value: x } : { status: "error", code: -x };
+}
+
+function unwrap(result) {
+    return result.status === "ok" ? result.value : -result.code;
+}
+
+module.exports = {
+    "area": area,
+    "grow": grow,
+    "check": check,
+    "unwrap": unwrap,
+};
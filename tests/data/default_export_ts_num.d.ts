@@ -0,0 +1,2 @@
+export default function triple(x: number): number;
+export declare function helper(x: number): number;
@@ -0,0 +1,11 @@
+module.exports = {
+    "__esModule": true,
+
+    "default": function triple(x) {
+        return x * 3;
+    },
+
+    "helper": function helper(x) {
+        return x + 1;
+    },
+};
@@ -0,0 +1,20 @@
+export interface Opts {
+    width: number;
+    height: number;
+}
+
+type Counts = Record<string, number>;
+
+// Utility types of dictionaries map the value type
+export declare function fill(counts: Partial<Counts>): number;
+
+export declare function pair(counts: Pick<Counts, "a" | "b">): number;
+
+// Conditions on type parameters are evaluated once instantiated
+type Boxed<T> = T extends object ? { value: T } : never;
+
+type NonNull<T> = T extends null ? never : T;
+
+export declare function area(opts: Boxed<Opts>["value"]): number;
+
+export declare function double(x: NonNull<number>): number;
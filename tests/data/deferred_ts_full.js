@@ -0,0 +1,22 @@
+function fill(counts) {
+    return Object.values(counts).reduce((acc, x) => acc + (x === undefined ? 0 : x), 0);
+}
+
+function pair(counts) {
+    return counts.a * counts.b;
+}
+
+function area(opts) {
+    return opts.width * opts.height;
+}
+
+function double(x) {
+    return x * 2;
+}
+
+module.exports = {
+    "fill": fill,
+    "pair": pair,
+    "area": area,
+    "double": double,
+};
@@ -0,0 +1,7 @@
+export type Scores = { [name: string]: number };
+
+export declare function scores(): Scores;
+
+export declare function total(scores: Scores): number;
+
+export declare function bump(scores: { [name: string]: number }, name: string): { [name: string]: number };
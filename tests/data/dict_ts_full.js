@@ -0,0 +1,20 @@
+function scores() {
+    return { "a": 1, "b": 2 };
+}
+
+function total(scores) {
+    return Object.keys(scores).reduce(function (sum, name) { return sum + scores[name]; }, 0);
+}
+
+function bump(scores, name) {
+    var bumped = {};
+    Object.keys(scores).forEach(function (key) { bumped[key] = scores[key]; });
+    bumped[name] = (bumped[name] || 0) + 1;
+    return bumped;
+}
+
+module.exports = {
+    "scores": scores,
+    "total": total,
+    "bump": bump,
+};
@@ -0,0 +1,35 @@
+export declare enum Direction {
+    Up,
+    Down,
+    Left = 10,
+    Right,
+}
+
+export declare enum Access {
+    Read = 1 << 0,
+    Write = 1 << 1,
+    ReadWrite = Read | Write,
+}
+
+export declare const enum Switch {
+    On = "ON",
+    Off = "OFF",
+}
+
+export declare enum Mode {
+    Read_Only = "ro",
+    "Read-Only" = "RO",
+    "2fa" = "2FA",
+}
+
+export declare function code(direction: Direction): number;
+
+export declare function turn(direction: Direction): Direction;
+
+export declare function bits(access: Access): number;
+
+export declare function toggle(s: Switch): Switch;
+
+export declare function isOn(s: Switch): number;
+
+export declare function label(mode: Mode): string;
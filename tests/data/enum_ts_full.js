@@ -0,0 +1,35 @@
+function code(direction) {
+    return direction;
+}
+
+function turn(direction) {
+    return direction + 1;
+}
+
+function bits(access) {
+    return access;
+}
+
+function toggle(s) {
+    return s === "ON" ? "OFF" : "ON";
+}
+
+function isOn(s) {
+    return s === "ON" ? 1 : 0;
+}
+
+function label(mode) {
+    return mode;
+}
+
+module.exports = {
+    "Direction": { "Up": 0, "Down": 1, "Left": 10, "Right": 11 },
+    "Access": { "Read": 1, "Write": 2, "ReadWrite": 3 },
+    "Mode": { "Read_Only": "ro", "Read-Only": "RO", "2fa": "2FA" },
+    "code": code,
+    "turn": turn,
+    "bits": bits,
+    "toggle": toggle,
+    "isOn": isOn,
+    "label": label,
+};
@@ -0,0 +1,8 @@
+declare function scale(x: number): number;
+
+declare namespace scale {
+    function offset(x: number): number;
+    const factor: number;
+}
+
+export = scale;
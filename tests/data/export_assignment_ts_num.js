@@ -0,0 +1,11 @@
+function scale(x) {
+    return x * scale.factor;
+}
+
+scale.offset = function offset(x) {
+    return x + 100;
+};
+
+scale.factor = 4;
+
+module.exports = scale;
@@ -0,0 +1,3 @@
+export * from "./chain/middle";
+
+export declare function top(): number;
@@ -0,0 +1,17 @@
+module.exports = {
+    "top": function top() {
+        return 1;
+    },
+
+    "middle": function middle() {
+        return 2;
+    },
+
+    "inner": function inner() {
+        return 3;
+    },
+
+    "leaf": function leaf() {
+        return 4;
+    },
+};
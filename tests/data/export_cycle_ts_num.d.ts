@@ -0,0 +1,3 @@
+export { a as b, b as a };
+
+export declare const c: typeof a;
@@ -0,0 +1,3 @@
+export type Pair = [number, string];
+
+export declare function half(x: Pair[1.5]): number;
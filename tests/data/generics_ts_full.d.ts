@@ -0,0 +1,19 @@
+export interface Pair<A, B> {
+    first: A;
+    second: B;
+}
+
+export declare class Box<T> {
+    constructor(value: T);
+    get(): T;
+}
+
+export declare function identity<T>(x: T): T;
+
+export declare function makePair<A, B>(first: A, second: B): Pair<A, B>;
+
+export declare function swap<A, B>(p: Pair<A, B>): Pair<B, A>;
+
+export declare function first<A, B>(p: Pair<A, B>): A;
+
+export declare function unbox(b: Box<number>): number;
@@ -0,0 +1,38 @@
+class Box {
+    constructor(value) {
+        this.value = value;
+    }
+
+    get() {
+        return this.value;
+    }
+}
+
+function identity(x) {
+    return x;
+}
+
+function makePair(first, second) {
+    return { first: first, second: second };
+}
+
+function swap(p) {
+    return { first: p.second, second: p.first };
+}
+
+function first(p) {
+    return p.first;
+}
+
+function unbox(b) {
+    return b.get();
+}
+
+module.exports = {
+    "Box": Box,
+    "identity": identity,
+    "makePair": makePair,
+    "swap": swap,
+    "first": first,
+    "unbox": unbox,
+};
@@ -0,0 +1,4 @@
+interface Point {
+    x: number;
+    y: number;
+}
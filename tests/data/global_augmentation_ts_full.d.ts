@@ -0,0 +1,12 @@
+/// <reference path="./global_augmentation/point.d.ts" />
+
+// Augmentations merge with the script declaration
+declare global {
+    interface Point {
+        z: number;
+    }
+}
+
+export declare function sum(p: Point): number;
+
+export declare function unit(): Point;
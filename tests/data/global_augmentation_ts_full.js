@@ -0,0 +1,9 @@
+module.exports = {
+    "sum": function sum(p) {
+        return p.x + p.y + p.z;
+    },
+
+    "unit": function unit() {
+        return { x: 1, y: 0, z: 0 };
+    },
+};
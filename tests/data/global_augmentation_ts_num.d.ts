@@ -0,0 +1,10 @@
+export declare function unbound(x: number): number;
+
+declare global {
+    namespace Math {
+        function abs(x: number): number;
+        function max(x: number, y: number): number;
+    }
+
+    function parseFloat(x: number): number;
+}
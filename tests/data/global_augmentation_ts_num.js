@@ -0,0 +1,2 @@
+// Globals are bound through `globalThis` (see `--bind-globals`)
+throw new Error("The implementation should not be required");
@@ -0,0 +1,4 @@
+export as namespace Math;
+
+export declare function abs(x: number): number;
+export declare function max(x: number, y: number): number;
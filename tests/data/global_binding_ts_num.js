@@ -0,0 +1,2 @@
+// Bound through `globalThis.Math` (see `--root-binding`)
+throw new Error("The implementation should not be required");
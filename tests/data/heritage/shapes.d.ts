@@ -0,0 +1,7 @@
+export interface Base {
+    name: string;
+}
+
+export interface Sized {
+    size: number;
+}
@@ -0,0 +1,11 @@
+import { Base, Sized } from "./heritage/shapes";
+
+export interface Derived extends Base, Sized {
+    weight: number;
+}
+
+export declare function nameLength(b: Base): number;
+
+export declare function measure(s: Sized): number;
+
+export declare function make(name: string, size: number): Derived;
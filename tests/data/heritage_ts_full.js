@@ -0,0 +1,17 @@
+function nameLength(b) {
+    return b.name.length;
+}
+
+function measure(s) {
+    return s.size;
+}
+
+function make(name, size) {
+    return { name: name, size: size, weight: size * 2 };
+}
+
+module.exports = {
+    "nameLength": nameLength,
+    "measure": measure,
+    "make": make,
+};
@@ -0,0 +1,3 @@
+import scale = require("./import_equals/scale");
+
+export { scale };
@@ -0,0 +1,20 @@
+export type Pair = [number, string];
+
+export type Scores = { [name: string]: number };
+
+export interface Opts {
+    width: number;
+    height: number;
+}
+
+export type Patch<T> = Partial<T>;
+
+export declare function double(x: Pair[0]): number;
+
+export declare function score(x: Scores["alice"]): number;
+
+export declare function patchWidth(patch: Pick<Patch<Opts>, "width">): number;
+
+export declare function update<T>(value: T, patch: Partial<T>): T;
+
+export declare function countKeys<T>(value: T, keys: Array<keyof T>): number;
@@ -0,0 +1,27 @@
+function double(x) {
+    return x * 2;
+}
+
+function score(x) {
+    return x;
+}
+
+function patchWidth(patch) {
+    return patch.width === undefined ? 0 : patch.width;
+}
+
+function update(value, patch) {
+    return value;
+}
+
+function countKeys(value, keys) {
+    return keys.length;
+}
+
+module.exports = {
+    "double": double,
+    "score": score,
+    "patchWidth": patchWidth,
+    "update": update,
+    "countKeys": countKeys,
+};
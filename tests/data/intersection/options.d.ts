@@ -0,0 +1,6 @@
+type Label = string;
+
+export interface Options {
+    width: number;
+    label: Label;
+}
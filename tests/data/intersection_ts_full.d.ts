@@ -0,0 +1,7 @@
+import { Options } from "./intersection/options";
+
+export type Extended = Options & { extra: number; width: number };
+
+export declare function total(opts: Extended): number;
+
+export declare function make(width: number): Options & { extra: number };
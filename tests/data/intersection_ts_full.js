@@ -0,0 +1,12 @@
+function total(opts) {
+    return opts.width + opts.extra + opts.label.length;
+}
+
+function make(width) {
+    return { width: width, label: "made", extra: width * 2 };
+}
+
+module.exports = {
+    "total": total,
+    "make": make,
+};
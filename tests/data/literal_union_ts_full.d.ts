@@ -0,0 +1,15 @@
+export type Align = "left" | "center" | "right";
+
+export type Digit = 1 | 2 | 3;
+
+export type Sep = "a-b" | "a.b" | "" | "1st";
+
+export declare function offset(align: Align): number;
+
+export declare function flip(align: Align): Align;
+
+export declare function value(digit: Digit): number;
+
+export declare function next(digit: Digit): Digit;
+
+export declare function bracket(sep: Sep): string;
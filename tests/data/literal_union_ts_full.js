@@ -0,0 +1,35 @@
+function offset(align) {
+    switch (align) {
+        case "left": return 0;
+        case "center": return 50;
+        case "right": return 100;
+    }
+}
+
+function flip(align) {
+    switch (align) {
+        case "left": return "right";
+        case "right": return "left";
+        default: return align;
+    }
+}
+
+function value(digit) {
+    return digit * 10;
+}
+
+function next(digit) {
+    return digit === 3 ? 1 : digit + 1;
+}
+
+function bracket(sep) {
+    return "[" + sep + "]";
+}
+
+module.exports = {
+    "offset": offset,
+    "flip": flip,
+    "value": value,
+    "next": next,
+    "bracket": bracket,
+};
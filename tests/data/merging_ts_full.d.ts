@@ -0,0 +1,32 @@
+export interface Size {
+    width: number;
+}
+
+export interface Size {
+    height: number;
+}
+
+export declare class Label {
+    constructor(text: string);
+    text: string;
+}
+
+export interface Label {
+    length: number;
+}
+
+declare global {
+    interface Scaling {
+        scale: number;
+    }
+}
+
+declare global {
+    interface Scaling {
+        offset: number;
+    }
+}
+
+export declare function area(size: Size): number;
+
+export declare function scaled(scaling: Scaling, x: number): number;
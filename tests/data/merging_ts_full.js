@@ -0,0 +1,23 @@
+class Label {
+    constructor(text) {
+        this.text = text;
+    }
+
+    get length() {
+        return this.text.length;
+    }
+}
+
+function area(size) {
+    return size.width * size.height;
+}
+
+function scaled(scaling, x) {
+    return x * scaling.scale + scaling.offset;
+}
+
+module.exports = {
+    "Label": Label,
+    "area": area,
+    "scaled": scaled,
+};
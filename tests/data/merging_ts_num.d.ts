@@ -0,0 +1,8 @@
+export declare function counter(start: number): number;
+
+export declare namespace counter {
+    const step: number;
+    function reset(): number;
+}
+
+export declare function counter(start: number, step: number): number;
@@ -0,0 +1,10 @@
+function counter(start, step) {
+    return start + (step === undefined ? counter.step : step);
+}
+
+counter.step = 10;
+counter.reset = function reset() { return 0; };
+
+module.exports = {
+    "counter": counter,
+};
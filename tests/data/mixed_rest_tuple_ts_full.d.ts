@@ -0,0 +1 @@
+export declare function label(entry: [string, ...number[]]): string;
@@ -0,0 +1,2 @@
+export declare function add(x: number, y: number): number;
+export declare function neg(x: number): number;
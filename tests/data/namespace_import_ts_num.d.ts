@@ -0,0 +1,4 @@
+export * as Arith from "./namespace_import/arith";
+
+import * as A from "./namespace_import/arith";
+export { A };
@@ -0,0 +1,14 @@
+const arith = {
+    "add": function add(x, y) {
+        return x + y;
+    },
+
+    "neg": function neg(x) {
+        return -x;
+    },
+};
+
+module.exports = {
+    "Arith": arith,
+    "A": arith,
+};
@@ -0,0 +1,15 @@
+export declare namespace Arith {
+    function add(x: number, y: number): number;
+
+    namespace Scaled {
+        function double(x: number): number;
+    }
+}
+
+export declare namespace Arith {
+    function neg(x: number): number;
+}
+
+export declare namespace Geometry.Circle {
+    const tau: number;
+}
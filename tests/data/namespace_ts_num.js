@@ -0,0 +1,23 @@
+module.exports = {
+    "Arith": {
+        "add": function add(x, y) {
+            return x + y;
+        },
+
+        "neg": function neg(x) {
+            return -x;
+        },
+
+        "Scaled": {
+            "double": function double(x) {
+                return x * 2;
+            },
+        },
+    },
+
+    "Geometry": {
+        "Circle": {
+            "tau": 6,
+        },
+    },
+};
@@ -0,0 +1,3 @@
+export type Pair = [number, string];
+
+export declare function last(x: Pair[-1]): number;
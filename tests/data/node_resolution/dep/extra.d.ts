@@ -0,0 +1 @@
+export function bar(x: number, y: number): number;
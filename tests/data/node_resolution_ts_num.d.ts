@@ -0,0 +1,2 @@
+export { foo } from "dep";
+export { bar } from "dep/extra";
@@ -0,0 +1,9 @@
+module.exports = {
+    "foo": function foo(x) {
+        return x * 2;
+    },
+
+    "bar": function bar(x, y) {
+        return x + y;
+    },
+};
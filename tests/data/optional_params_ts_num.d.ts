@@ -0,0 +1,2 @@
+export declare function add(x: number, y?: number, z?: number): number;
+export declare function sum(base: number, ...xs: number[]): number;
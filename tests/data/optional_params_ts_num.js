@@ -0,0 +1,9 @@
+module.exports = {
+    "add": function add(x, y, z) {
+        return x + (y === undefined ? 100 : y) + (z === undefined ? 1000 : z);
+    },
+
+    "sum": function sum(base, ...xs) {
+        return xs.reduce(function(acc, x) { return acc + x; }, base);
+    },
+};
@@ -0,0 +1,17 @@
+export interface Opts {
+    width: number;
+    height?: number;
+}
+
+export declare function area(opts: Opts): number;
+
+export declare function fill(opts: Partial<Opts>): number;
+
+export declare function defaults(): Partial<Opts>;
+
+export interface Style {
+    "fill-color": string;
+    "line-width"?: number;
+}
+
+export declare function describe(style: Style): string;
@@ -0,0 +1,22 @@
+function area(opts) {
+    return opts.width * (opts.height === undefined ? 1 : opts.height);
+}
+
+function fill(opts) {
+    return (opts.width === undefined ? 0 : opts.width) + (opts.height === undefined ? 0 : opts.height);
+}
+
+function defaults() {
+    return { width: 2 };
+}
+
+function describe(style) {
+    return style["fill-color"] + ":" + (style["line-width"] === undefined ? 1 : style["line-width"]);
+}
+
+module.exports = {
+    "area": area,
+    "fill": fill,
+    "defaults": defaults,
+    "describe": describe,
+};
@@ -0,0 +1,4 @@
+export declare function scale(x: number): number;
+export declare function scale(x: number, factor: number): number;
+export declare function clamp(x: number, lo?: number): number;
+export declare function clamp(x: number, lo: number, hi: number, ...rest: number[]): number;
@@ -0,0 +1,11 @@
+module.exports = {
+    "scale": function scale(x, factor) {
+        return x * (factor === undefined ? 10 : factor);
+    },
+
+    "clamp": function clamp(x, lo, hi, ...rest) {
+        if (lo === undefined) { return x; }
+        if (hi === undefined) { return Math.max(x, lo); }
+        return Math.min(Math.max(x, lo), hi) + rest.length;
+    },
+};
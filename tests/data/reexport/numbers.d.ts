@@ -0,0 +1,3 @@
+export declare function one(): number;
+
+export declare function two(): number;
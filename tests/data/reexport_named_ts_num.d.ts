@@ -0,0 +1,3 @@
+export { one } from "./reexport/numbers";
+
+export declare function local(x: number): number;
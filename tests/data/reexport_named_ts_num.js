@@ -0,0 +1,9 @@
+module.exports = {
+    "one": function one() {
+        return 1;
+    },
+
+    "local": function local(x) {
+        return x * 10;
+    },
+};
@@ -0,0 +1 @@
+type Seconds = number;
@@ -0,0 +1,3 @@
+type Meters = number;
+
+declare function toMeters(feet: number): Meters;
@@ -0,0 +1,9 @@
+// Directives may follow comments
+/* but not declarations */
+/// <reference path="./reference/units.d.ts" />
+/// <reference types="timing" />
+
+/* Directives following a declaration are ignored */ export declare function double(x: Meters): Meters;
+/// <reference path="./reference/missing.d.ts" />
+
+export declare function speed(distance: Meters, time: Seconds): number;
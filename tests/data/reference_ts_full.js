@@ -0,0 +1,9 @@
+module.exports = {
+    "double": function double(x) {
+        return x * 2;
+    },
+
+    "speed": function speed(distance, time) {
+        return distance / time;
+    },
+};
@@ -0,0 +1 @@
+export declare function add(x: number, y: number): number;
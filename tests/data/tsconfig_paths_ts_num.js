@@ -0,0 +1,5 @@
+module.exports = {
+    "add": function add(x, y) {
+        return x + y;
+    },
+};
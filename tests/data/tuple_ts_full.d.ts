@@ -0,0 +1,11 @@
+export type Point = [number, number];
+
+export declare function norm(p: Point): number;
+
+export declare function swap(p: [number, number]): [number, number];
+
+export declare function origin(): Point;
+
+export declare function total(p: [number, number?]): number;
+
+export declare function sum(xs: [number, ...number[]]): number;
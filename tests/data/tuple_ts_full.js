@@ -0,0 +1,27 @@
+function norm(p) {
+    return Math.abs(p[0]) + Math.abs(p[1]);
+}
+
+function swap(p) {
+    return [p[1], p[0]];
+}
+
+function origin() {
+    return [0, 0];
+}
+
+function total(p) {
+    return p[1] === undefined ? p[0] : p[0] + p[1];
+}
+
+function sum(xs) {
+    return xs.reduce((acc, x) => acc + x, 0);
+}
+
+module.exports = {
+    "norm": norm,
+    "swap": swap,
+    "origin": origin,
+    "total": total,
+    "sum": sum,
+};
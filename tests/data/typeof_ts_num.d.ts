@@ -0,0 +1,13 @@
+declare function triple(x: number): number;
+
+declare const internal: { offset: number };
+
+declare namespace util {
+    function half(x: number): number;
+}
+
+export declare const scale: typeof triple;
+
+export declare const halve: typeof util.half;
+
+export declare function shift(x: number, by: typeof internal.offset): number;
@@ -0,0 +1,17 @@
+function scale(x) {
+    return x * 3;
+}
+
+function halve(x) {
+    return x / 2;
+}
+
+function shift(x, by) {
+    return x + by;
+}
+
+module.exports = {
+    "scale": scale,
+    "halve": halve,
+    "shift": shift,
+};
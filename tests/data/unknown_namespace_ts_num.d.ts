@@ -0,0 +1 @@
+export declare function delay(ms: number): NodeJS.Timeout;
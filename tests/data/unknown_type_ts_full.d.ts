@@ -0,0 +1 @@
+export declare function later(x: number): Promise<number>;
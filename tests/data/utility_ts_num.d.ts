@@ -0,0 +1,15 @@
+interface Options {
+    width: number;
+    height?: number;
+    scale(x: number): number;
+}
+
+type Size = Required<Pick<Options, "width" | "height">>;
+
+type Scaled = { [K in keyof Options]: Options[K] };
+
+export declare function area(width: Size["width"], height: Size["height"]): number;
+
+export declare const scale: Scaled["scale"];
+
+export declare function double(x: Options extends { width: number } ? number : string): number;
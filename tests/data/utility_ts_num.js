@@ -0,0 +1,17 @@
+function area(width, height) {
+    return width * height;
+}
+
+function scale(x) {
+    return x * 3;
+}
+
+function double(x) {
+    return x * 2;
+}
+
+module.exports = {
+    "area": area,
+    "scale": scale,
+    "double": double,
+};
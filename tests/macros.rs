@@ -40,9 +40,75 @@ macro_rules! make_test {
             => expects: $expected);
     };
 
+    (TS_FULL($test_name: ident) expects: $expected: expr) => {
+        make_test!(TS_FULL($test_name)
+            jetsam-compile: |_, c| c;
+            pyret-compile: |_, c| c;
+            => expects: $expected);
+    };
+
+    (TS_FULL($test_name: ident)
+    jetsam-compile: $jetsam_compile_override: expr;
+    pyret-compile: $pyret_compile_override: expr;
+    => expects: $expected: expr) => {
+        make_test!(FLAVOR("ts-full") FULL(test => $test_name, data => $test_name)
+            jetsam-compile: $jetsam_compile_override;
+            pyret-compile: $pyret_compile_override;
+            => expects: $expected);
+    };
+
     (FULL(test => $test_name: ident, data => $test_data_name: ident)
     jetsam-compile: $jetsam_compile_override: expr;
     pyret-compile: $pyret_compile_override: expr;
+    => expects: $expected: expr) => {
+        make_test!(FLAVOR("ts-num") FULL(test => $test_name, data => $test_data_name)
+            jetsam-compile: $jetsam_compile_override;
+            pyret-compile: $pyret_compile_override;
+            => expects: $expected);
+    };
+
+    // Only runs jetsam (the data has no JS implementation or runner)
+    (FLAVOR($flavor: expr) ERROR($test_name: ident) expects: $expected_error: expr) => {
+
+        #[test]
+        fn $test_name() {
+
+            use common::BINDING_DIR;
+
+            let test_env = common::TestEnv::new(stringify!($test_name));
+
+            test_env.create_tmp_dir(BINDING_DIR);
+            test_env.create_tmp_file(
+                binding_file!(concat!(stringify!($test_name), ".d.ts")),
+                include_str!(concat!("./data/", stringify!($test_name), ".d.ts"))
+            );
+
+            let mut jetsam_build_cmd = test_env
+                .jetsam_build_cmd(binding_file!(concat!(stringify!($test_name), ".d.ts")), BINDING_DIR);
+            let jetsam_output = jetsam_build_cmd
+                .arg("--ts-flavor")
+                .arg($flavor)
+                .output()
+                .expect(&format!("jetsam failed (`{:#?}`)", jetsam_build_cmd));
+
+            if jetsam_output.status.success() {
+                dbg!(test_env);
+                panic!("Command `{:?}` succeeded (expected an error)", jetsam_build_cmd);
+            }
+
+            let stderr: String = String::from_utf8(jetsam_output.stderr)
+                .expect("Jetsam build did NOT emit utf8 in stderr");
+
+            if !stderr.contains($expected_error) {
+                eprintln!("\n=======cmd stderr=======\n\n{}\n\n=======end cmd stderr=======\n", stderr);
+                panic!("Expected error containing `{}`", $expected_error);
+            }
+        }
+    };
+
+    (FLAVOR($flavor: expr) FULL(test => $test_name: ident, data => $test_data_name: ident)
+    jetsam-compile: $jetsam_compile_override: expr;
+    pyret-compile: $pyret_compile_override: expr;
     => expects: $expected: expr) => {
 
         #[test]
@@ -76,7 +142,7 @@ macro_rules! make_test {
             };
             let jetsam_output = jetsam_build_cmd
                 .arg("--ts-flavor")
-                .arg("ts-num")
+                .arg($flavor)
                 .output()
                 .expect(&format!("jetsam failed (`{:#?}`)", jetsam_build_cmd));
 
@@ -129,8 +195,6 @@ macro_rules! make_test {
             let expected = $expected;
 
             if expected != run_stdout {
-                use std::env;
-
                 if std::env::var_os("DBG_EPRINT").is_some() {
                     eprintln!("Expected:\n{:#?}", expected);
                     eprintln!("Result:\n{:#?}", run_stdout);
@@ -0,0 +1,184 @@
+#[macro_use]
+mod macros;
+mod common;
+
+// Can get debug result/expected prints by defining env var "DBG_EPRINT"
+
+make_test!(TS_FULL(literal_union_ts_full) expects:
+    common::line_separated_expected(&["50", "100", "20", "10", "[a.b]", "[]", "[1st]", "Done"])
+);
+
+make_test!(TS_FULL(data_union_ts_full) expects:
+    common::line_separated_expected(&["9", "12", "5", "-7", "Done"])
+);
+
+make_test!(TS_FULL(tuple_ts_full) expects:
+    common::line_separated_expected(&["7", "2", "0", "7", "5", "6", "Done"])
+);
+
+make_test!(TS_FULL(enum_ts_full) expects:
+    common::line_separated_expected(&["0", "1", "11", "3", "1", "RO", "2FA", "Done"])
+);
+
+make_test!(TS_FULL(dict_ts_full) expects:
+    common::line_separated_expected(&["3", "2", "9", "1", "Done"])
+);
+
+make_test!(FLAVOR("ts-full") FULL(test => dict_lazy_ts_full, data => dict_ts_full)
+    jetsam-compile: |_, mut c: std::process::Command| {
+        c
+            .arg("--lazy-dicts")
+            .arg("true");
+
+        c
+    };
+    pyret-compile: |_, c| c;
+    => expects: common::line_separated_expected(&["3", "2", "9", "1", "Done"])
+);
+
+make_test!(TS_FULL(optional_props_ts_full)
+    jetsam-compile: |_, mut c: std::process::Command| {
+        c
+            .arg("--opaque-interfaces")
+            .arg("false");
+
+        c
+    };
+    pyret-compile: |_, c| c;
+    => expects: common::line_separated_expected(&["12", "3", "5", "2", "-1", "red:2", "blue:1", "Done"])
+);
+
+make_test!(TS_FULL(heritage_ts_full)
+    jetsam-compile: |env: &common::TestEnv, mut c: std::process::Command| {
+        env.create_tmp_dir("src/bindings/heritage");
+        env.create_tmp_file(
+            "src/bindings/heritage/shapes.d.ts",
+            include_str!("./data/heritage/shapes.d.ts")
+        );
+
+        c
+            .arg("--opaque-interfaces")
+            .arg("false");
+
+        c
+    };
+    pyret-compile: |_, c| c;
+    => expects: common::line_separated_expected(&["3", "3", "6", "4", "Done"])
+);
+
+make_test!(TS_FULL(class_ts_full) expects:
+    common::line_separated_expected(&["5", "5", "9", "100", "0", "100", "Done"])
+);
+
+make_test!(TS_FULL(constructible_ts_full)
+    jetsam-compile: |_, mut c: std::process::Command| {
+        c
+            .arg("--opaque-interfaces")
+            .arg("false");
+
+        c
+    };
+    pyret-compile: |_, c| c;
+    => expects: common::line_separated_expected(&["4", "5", "2", "7", "20", "Done"])
+);
+
+make_test!(TS_FULL(generics_ts_full) expects:
+    common::line_separated_expected(&["7", "41", "1", "2", "Done"])
+);
+
+make_test!(FLAVOR("ts-full") ERROR(unknown_type_ts_full) expects: "UnknownType(\"Promise\")");
+
+make_test!(FLAVOR("ts-full") ERROR(computed_key_ts_full) expects: "ComputedKey");
+
+make_test!(TS_FULL(merging_ts_full)
+    jetsam-compile: |_, mut c: std::process::Command| {
+        c
+            .arg("--opaque-interfaces")
+            .arg("false");
+
+        c
+    };
+    pyret-compile: |_, c| c;
+    => expects: common::line_separated_expected(&["12", "11", "5", "6", "Done"])
+);
+
+make_test!(FLAVOR("ts-full") ERROR(conflicting_declaration_ts_full) expects: "ConflictingDeclaration(\"Size\")");
+
+make_test!(TS_FULL(indexed_ts_full) expects:
+    common::line_separated_expected(&["8", "5", "3", "0", "7", "Done"])
+);
+
+make_test!(TS_FULL(deferred_ts_full)
+    jetsam-compile: |_, mut c: std::process::Command| {
+        c
+            .arg("--opaque-interfaces")
+            .arg("false");
+
+        c
+    };
+    pyret-compile: |_, c| c;
+    => expects: common::line_separated_expected(&["4", "15", "14", "42", "Done"])
+);
+
+make_test!(FLAVOR("ts-full") ERROR(negative_index_ts_full) expects: "UnknownField(\"-1\")");
+
+make_test!(FLAVOR("ts-full") ERROR(fractional_index_ts_full) expects: "UnknownField(\"1.5\")");
+
+make_test!(FLAVOR("ts-full") ERROR(mixed_rest_tuple_ts_full) expects: "UnevaluableType");
+
+make_test!(TS_FULL(intersection_ts_full)
+    jetsam-compile: |env: &common::TestEnv, mut c: std::process::Command| {
+        env.create_tmp_dir("src/bindings/intersection");
+        env.create_tmp_file(
+            "src/bindings/intersection/options.d.ts",
+            include_str!("./data/intersection/options.d.ts")
+        );
+
+        c
+            .arg("--opaque-interfaces")
+            .arg("false");
+
+        c
+    };
+    pyret-compile: |_, c| c;
+    => expects: common::line_separated_expected(&["9", "5", "10", "true", "Done"])
+);
+
+make_test!(FLAVOR("ts-full") ERROR(conflicting_intersection_ts_full) expects: "ConflictingIntersectionField(\"size\")");
+
+make_test!(TS_FULL(reference_ts_full)
+    jetsam-compile: |env: &common::TestEnv, c: std::process::Command| {
+        env.create_tmp_dir("src/bindings/reference");
+        env.create_tmp_file(
+            "src/bindings/reference/units.d.ts",
+            include_str!("./data/reference/units.d.ts")
+        );
+        env.create_tmp_dir("src/bindings/node_modules/@types/timing");
+        env.create_tmp_file(
+            "src/bindings/node_modules/@types/timing/index.d.ts",
+            include_str!("./data/reference/timing/index.d.ts")
+        );
+
+        c
+    };
+    pyret-compile: |_, c| c;
+    => expects: common::line_separated_expected(&["42", "25", "Done"])
+);
+
+make_test!(TS_FULL(global_augmentation_ts_full)
+    jetsam-compile: |env: &common::TestEnv, mut c: std::process::Command| {
+        env.create_tmp_dir("src/bindings/global_augmentation");
+        env.create_tmp_file(
+            "src/bindings/global_augmentation/point.d.ts",
+            include_str!("./data/global_augmentation/point.d.ts")
+        );
+
+        c
+            .arg("--opaque-interfaces")
+            .arg("false");
+
+        c
+    };
+    pyret-compile: |_, c| c;
+    => expects: common::line_separated_expected(&["6", "1", "Done"])
+);
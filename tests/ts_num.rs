@@ -37,3 +37,230 @@ make_test!(FULL(test => config_unwrapped_ts_num, data => unwrapped_ts_num)
     pyret-compile: |_, c| c;
     => expects: common::line_separated_expected(&["40", "-20", "-55", "99", "9000", "Done"])
 );
+
+make_test!(BASIC(node_resolution_ts_num)
+    jetsam-compile: |env: &common::TestEnv, c: std::process::Command| {
+        env.create_tmp_dir("src/bindings/node_modules/dep/types");
+        env.create_tmp_file(
+            "src/bindings/node_modules/dep/package.json",
+            include_str!("./data/node_resolution/dep/package.json")
+        );
+        env.create_tmp_file(
+            "src/bindings/node_modules/dep/types/index.d.ts",
+            include_str!("./data/node_resolution/dep/types/index.d.ts")
+        );
+        env.create_tmp_file(
+            "src/bindings/node_modules/dep/extra.d.ts",
+            include_str!("./data/node_resolution/dep/extra.d.ts")
+        );
+
+        c
+    };
+    pyret-compile: |_, c| c;
+    => expects: common::line_separated_expected(&["40", "-5", "Done"])
+);
+
+make_test!(BASIC(namespace_ts_num) expects:
+    common::line_separated_expected(&["42", "-5", "42", "6", "Done"])
+);
+
+make_test!(BASIC(ambient_module_ts_num)
+    jetsam-compile: |env: &common::TestEnv, c: std::process::Command| {
+        env.create_tmp_dir("src/bindings/ambient_module");
+        env.create_tmp_file(
+            "src/bindings/ambient_module/arith.d.ts",
+            include_str!("./data/ambient_module/arith.d.ts")
+        );
+
+        c
+    };
+    pyret-compile: |_, c| c;
+    => expects: common::line_separated_expected(&["42", "-5", "Done"])
+);
+
+make_test!(BASIC(default_export_ts_num) expects:
+    common::line_separated_expected(&["15", "6", "Done"])
+);
+
+make_test!(BASIC(namespace_import_ts_num)
+    jetsam-compile: |env: &common::TestEnv, c: std::process::Command| {
+        env.create_tmp_dir("src/bindings/namespace_import");
+        env.create_tmp_file(
+            "src/bindings/namespace_import/arith.d.ts",
+            include_str!("./data/namespace_import/arith.d.ts")
+        );
+
+        c
+    };
+    pyret-compile: |_, c| c;
+    => expects: common::line_separated_expected(&["42", "-5", "Done"])
+);
+
+make_test!(BASIC(export_assignment_ts_num) expects:
+    common::line_separated_expected(&["20", "105", "4", "Done"])
+);
+
+make_test!(BASIC(global_binding_ts_num)
+    jetsam-compile: |_, mut c: std::process::Command| {
+        c
+            .arg("--root-binding")
+            .arg("global");
+
+        c
+    };
+    pyret-compile: |_, c| c;
+    => expects: common::line_separated_expected(&["5", "9", "Done"])
+);
+
+make_test!(BASIC(global_augmentation_ts_num)
+    jetsam-compile: |_, mut c: std::process::Command| {
+        c
+            .arg("--bind-globals")
+            .arg("Math");
+
+        c
+    };
+    pyret-compile: |_, c| c;
+    => expects: common::line_separated_expected(&["5", "9", "Done"])
+);
+
+make_test!(BASIC(optional_params_ts_num) expects:
+    common::line_separated_expected(&["1101", "1003", "6", "10", "Done"])
+);
+
+make_test!(BASIC(overloads_ts_num) expects:
+    common::line_separated_expected(&["20", "6", "5", "7", "5", "Done"])
+);
+
+make_test!(BASIC(overloads_dispatch_ts_num)
+    jetsam-compile: |_, mut c: std::process::Command| {
+        c
+            .arg("--overloads")
+            .arg("dispatch");
+
+        c
+    };
+    pyret-compile: |_, c| c;
+    => expects: common::line_separated_expected(&["20", "6", "5", "7", "5", "Done"])
+);
+
+make_test!(BASIC(merging_ts_num) expects:
+    common::line_separated_expected(&["15", "7", "10", "0", "Done"])
+);
+
+make_test!(BASIC(callable_ts_num) expects:
+    common::line_separated_expected(&["15", "10", "8", "6", "10", "3", "Done"])
+);
+
+make_test!(BASIC(utility_ts_num) expects:
+    common::line_separated_expected(&["12", "15", "42", "Done"])
+);
+
+make_test!(BASIC(typeof_ts_num) expects:
+    common::line_separated_expected(&["15", "4", "7", "Done"])
+);
+
+make_test!(FLAVOR("ts-num") ERROR(unknown_namespace_ts_num) expects: "UnknownNamespace(\"NodeJS\")");
+
+make_test!(FLAVOR("ts-num") ERROR(export_cycle_ts_num) expects: "UnknownValue(\"a\")");
+
+make_test!(BASIC(reexport_named_ts_num)
+    jetsam-compile: |env: &common::TestEnv, c: std::process::Command| {
+        env.create_tmp_dir("src/bindings/reexport");
+        env.create_tmp_file(
+            "src/bindings/reexport/numbers.d.ts",
+            include_str!("./data/reexport/numbers.d.ts")
+        );
+
+        c
+    };
+    pyret-compile: |_, c| c;
+    => expects: common::line_separated_expected(&["1", "30", "Done"])
+);
+
+make_test!(BASIC(export_chain_ts_num)
+    jetsam-compile: |env: &common::TestEnv, c: std::process::Command| {
+        env.create_tmp_dir("src/bindings/chain");
+        env.create_tmp_file(
+            "src/bindings/chain/middle.d.ts",
+            include_str!("./data/chain/middle.d.ts")
+        );
+        env.create_tmp_file(
+            "src/bindings/chain/inner.d.ts",
+            include_str!("./data/chain/inner.d.ts")
+        );
+        env.create_tmp_file(
+            "src/bindings/chain/leaf.d.ts",
+            include_str!("./data/chain/leaf.d.ts")
+        );
+
+        c
+    };
+    pyret-compile: |_, c| c;
+    => expects: common::line_separated_expected(&["1", "2", "3", "4", "Done"])
+);
+
+make_test!(BASIC(tsconfig_paths_ts_num)
+    jetsam-compile: |env: &common::TestEnv, c: std::process::Command| {
+        env.create_tmp_dir("src/bindings/tsconfig_paths/lib");
+        env.create_tmp_file(
+            "src/bindings/tsconfig.json",
+            include_str!("./data/tsconfig_paths/tsconfig.json")
+        );
+        env.create_tmp_file(
+            "src/bindings/tsconfig_paths/lib/arith.d.ts",
+            include_str!("./data/tsconfig_paths/lib/arith.d.ts")
+        );
+
+        c
+    };
+    pyret-compile: |_, c| c;
+    => expects: common::line_separated_expected(&["42", "Done"])
+);
+
+make_test!(FULL(test => tsconfig_extends_package_ts_num, data => tsconfig_paths_ts_num)
+    jetsam-compile: |env: &common::TestEnv, c: std::process::Command| {
+        env.create_tmp_dir("src/bindings/tsconfig_paths/lib");
+        env.create_tmp_dir("src/bindings/node_modules/@shared/tsconfig-paths");
+        env.create_tmp_file(
+            "src/bindings/tsconfig.json",
+            include_str!("./data/tsconfig_extends/tsconfig.json")
+        );
+        env.create_tmp_file(
+            "src/bindings/node_modules/@shared/tsconfig-paths/tsconfig.json",
+            include_str!("./data/tsconfig_extends/package_tsconfig.json")
+        );
+        env.create_tmp_file(
+            "src/bindings/tsconfig_paths/lib/arith.d.ts",
+            include_str!("./data/tsconfig_paths/lib/arith.d.ts")
+        );
+
+        c
+    };
+    pyret-compile: |_, c| c;
+    => expects: common::line_separated_expected(&["42", "Done"])
+);
+
+make_test!(FULL(test => malformed_tsconfig_ts_num, data => basic_ts_num)
+    jetsam-compile: |env: &common::TestEnv, c: std::process::Command| {
+        env.create_tmp_file("src/bindings/tsconfig.json", "{ \"compilerOptions\": ");
+
+        c
+    };
+    pyret-compile: |_, c| c;
+    => expects: common::line_separated_expected(&["40", "-20", "-55", "99", "9000", "Done"])
+);
+
+make_test!(BASIC(import_equals_export_ts_num)
+    jetsam-compile: |env: &common::TestEnv, c: std::process::Command| {
+        env.create_tmp_dir("src/bindings/import_equals");
+        env.create_tmp_file(
+            "src/bindings/import_equals/scale.d.ts",
+            include_str!("./data/import_equals/scale.d.ts")
+        );
+
+        c
+    };
+    pyret-compile: |_, c| c;
+    => expects: common::line_separated_expected(&["20", "105", "4", "Done"])
+);